@@ -0,0 +1,28 @@
+//! Bridge out, have the gateway revert, and check the NFT comes back.
+//!
+//! initialize → setup_gateway → register_chain → mint_nft → cross_chain_transfer
+//! → revert_cross_chain_transfer (mock) → verify
+
+use anchor_client::solana_sdk::signature::{Keypair, Signer};
+use anyhow::Result;
+
+use universal_nft_examples::Scenario;
+use zetachain_universal_nft::{constants::ZETA_CHAIN_ID_BSC, state::TransferStatus};
+
+fn main() -> Result<()> {
+    let scenario = Scenario::connect()?;
+    scenario.bootstrap()?;
+    
+    let owner = Keypair::new();
+    scenario.airdrop(&owner.pubkey(), 2_000_000_000)?;
+    
+    let mint = scenario.mint(&owner)?;
+    scenario.bridge_out(&owner, &mint, ZETA_CHAIN_ID_BSC)?;
+    scenario.verify_escrowed(&mint, TransferStatus::InProgress)?;
+    
+    scenario.revert(&mint)?;
+    scenario.verify_held_by(&mint, &owner.pubkey())?;
+    
+    println!("revert scenario completed");
+    Ok(())
+}
@@ -0,0 +1,33 @@
+//! Mint on Solana, bridge to Ethereum and back.
+//!
+//! initialize → setup_gateway → register_chain → mint_nft → cross_chain_transfer
+//! → confirm_outbound_transfer (mock) → process_returning_nft (mock) → verify
+
+use anchor_client::solana_sdk::signature::{Keypair, Signer};
+use anyhow::Result;
+
+use universal_nft_examples::Scenario;
+use zetachain_universal_nft::{constants::ZETA_CHAIN_ID_ETHEREUM, state::TransferStatus};
+
+fn main() -> Result<()> {
+    let scenario = Scenario::connect()?;
+    scenario.bootstrap()?;
+    
+    let owner = Keypair::new();
+    scenario.airdrop(&owner.pubkey(), 2_000_000_000)?;
+    
+    let mint = scenario.mint(&owner)?;
+    scenario.verify_held_by(&mint, &owner.pubkey())?;
+    
+    scenario.bridge_out(&owner, &mint, ZETA_CHAIN_ID_ETHEREUM)?;
+    scenario.verify_escrowed(&mint, TransferStatus::InProgress)?;
+    
+    scenario.confirm_outbound(&mint)?;
+    scenario.verify_escrowed(&mint, TransferStatus::Completed)?;
+    
+    scenario.mock_return(&mint, ZETA_CHAIN_ID_ETHEREUM, &owner.pubkey())?;
+    scenario.verify_held_by(&mint, &owner.pubkey())?;
+    
+    println!("round trip completed");
+    Ok(())
+}
@@ -0,0 +1,502 @@
+//! Shared steps for the Universal NFT example scenarios.
+//!
+//! Every scenario runs against a local validator with the program deployed
+//! (`anchor localnet` or `./localnet.sh start`). The wallet at `ANCHOR_WALLET`
+//! (default `~/.config/solana/id.json`) acts as program authority; a fresh
+//! keypair stands in for the ZetaChain gateway and a fresh secp256k1 key for its
+//! single observer, so inbound messages can be mocked.
+
+use std::rc::Rc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anchor_client::{
+    solana_sdk::{
+        commitment_config::CommitmentConfig,
+        instruction::Instruction,
+        pubkey::Pubkey,
+        secp256k1_instruction::{construct_eth_pubkey, new_secp256k1_instruction},
+        signature::{read_keypair_file, Keypair, Signer},
+        system_program,
+        sysvar,
+    },
+    Client, Cluster, Program,
+};
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::token::{self, TokenAccount};
+use anyhow::{ensure, Context, Result};
+
+use zetachain_universal_nft::{
+    accounts,
+    codec::derive_token_id,
+    constants::*,
+    instruction,
+    instructions::process_incoming_nft::incoming_message_digest,
+    payload::{CrossChainPayload, PayloadV2},
+    state::{
+        AddressFormat, CrossChainTransferState, MessageLane, NFTMetadata, Outbox, ProgramState,
+        TransferStatus, ZetaChainGatewayState,
+    },
+    seeds::*,
+    ID,
+};
+
+/// EVM recipient used for outbound transfers
+pub const EVM_RECIPIENT: [u8; 20] = [0x42; 20];
+
+/// Connected chains registered by every scenario: (chain ID, chain index, name, address format)
+pub const CHAINS: [(u64, u8, &str, AddressFormat); 3] = [
+    (ZETA_CHAIN_ID_SOLANA, 0, "Solana", AddressFormat::Solana),
+    (ZETA_CHAIN_ID_ETHEREUM, 1, "Ethereum", AddressFormat::Evm),
+    (ZETA_CHAIN_ID_BSC, 2, "BSC", AddressFormat::Evm),
+];
+
+/// Localnet session with the program authority, a mock gateway signer and its observer
+pub struct Scenario {
+    pub program: Program<Rc<Keypair>>,
+    pub authority: Rc<Keypair>,
+    pub gateway: Keypair,
+    pub observer: libsecp256k1::SecretKey,
+}
+
+impl Scenario {
+    /// Connect to the cluster named by `CLUSTER` (default localnet)
+    pub fn connect() -> Result<Self> {
+        let wallet = std::env::var("ANCHOR_WALLET").unwrap_or_else(|_| {
+            let home = std::env::var("HOME").unwrap_or_default();
+            format!("{}/.config/solana/id.json", home)
+        });
+        let authority = Rc::new(
+            read_keypair_file(&wallet).map_err(|e| anyhow::anyhow!("reading {}: {}", wallet, e))?,
+        );
+        let cluster: Cluster = std::env::var("CLUSTER")
+            .unwrap_or_else(|_| "localnet".to_string())
+            .parse()?;
+        
+        let client = Client::new_with_options(cluster, authority.clone(), CommitmentConfig::confirmed());
+        let program = client.program(ID)?;
+        
+        // Any 32 random bytes make a valid secp256k1 key with overwhelming probability
+        let observer = libsecp256k1::SecretKey::parse_slice(Keypair::new().secret().as_bytes())
+            .map_err(|e| anyhow::anyhow!("observer key: {:?}", e))?;
+        
+        Ok(Self {
+            program,
+            authority,
+            gateway: Keypair::new(),
+            observer,
+        })
+    }
+    
+    fn program_state(&self) -> Result<ProgramState> {
+        Ok(self.program.account::<ProgramState>(find_program_state().0)?)
+    }
+    
+    /// Initialize the program, set the mock gateway and its observer and register the connected chains
+    pub fn bootstrap(&self) -> Result<()> {
+        let (program_state, _) = find_program_state();
+        let (gateway_state, _) = find_gateway_state();
+        
+        if self.program.rpc().get_account(&program_state).is_err() {
+            println!("==> initialize");
+            self.program
+                .request()
+                .accounts(accounts::Initialize {
+                    program_state,
+                    gateway_state,
+                    outbox: find_outbox().0,
+                    collection_config: find_collection_config().0,
+                    pause_config: find_pause_config().0,
+                    rate_limiter: find_rate_limiter().0,
+                    denylist: find_denylist().0,
+                    authority: self.authority.pubkey(),
+                    system_program: system_program::ID,
+                    rent: sysvar::rent::ID,
+                })
+                .args(instruction::Initialize {
+                    metadata_uri: "https://example.com/collection.json".to_string(),
+                    max_supply: 1_000,
+                })
+                .send()?;
+        }
+        
+        // setup_gateway enforces a minimum interval between gateway updates
+        let gateway = self.program.account::<ZetaChainGatewayState>(gateway_state)?;
+        let elapsed = unix_now() - gateway.updated_at;
+        if elapsed < MINIMUM_GATEWAY_UPDATE_INTERVAL {
+            let wait = (MINIMUM_GATEWAY_UPDATE_INTERVAL - elapsed + 1) as u64;
+            println!("    waiting {}s for the gateway update interval", wait);
+            thread::sleep(Duration::from_secs(wait));
+        }
+        
+        println!("==> setup_gateway (mock gateway {})", self.gateway.pubkey());
+        self.program
+            .request()
+            .accounts(accounts::SetupGateway {
+                program_state,
+                gateway_state,
+                authority: self.authority.pubkey(),
+                instructions: sysvar::instructions::ID,
+                system_program: system_program::ID,
+            })
+            .args(instruction::SetupGateway {
+                gateway_address: [0x11; 20],
+                gateway_authority: self.gateway.pubkey(),
+                version: GATEWAY_VERSION,
+            })
+            .send()?;
+        
+        println!("==> set_observers (1 of 1)");
+        let observer_address = construct_eth_pubkey(&libsecp256k1::PublicKey::from_secret_key(&self.observer));
+        self.program
+            .request()
+            .accounts(accounts::SetObservers {
+                program_state,
+                gateway_state,
+                authority: self.authority.pubkey(),
+                instructions: sysvar::instructions::ID,
+            })
+            .args(instruction::SetObservers {
+                observers: vec![observer_address],
+                threshold: 1,
+            })
+            .send()?;
+        
+        // The mock gateway pays rent for the accounts it creates
+        self.airdrop(&self.gateway.pubkey(), 2_000_000_000)?;
+        
+        for (chain_id, chain_index, name, address_format) in CHAINS {
+            let (chain_config, _) = find_chain_config(chain_id);
+            if self.program.rpc().get_account(&chain_config).is_ok() {
+                continue;
+            }
+            println!("==> register_chain {} ({})", name, chain_id);
+            self.program
+                .request()
+                .accounts(accounts::RegisterChain {
+                    program_state,
+                    gateway_state,
+                    chain_config,
+                    authority: self.authority.pubkey(),
+                    instructions: sysvar::instructions::ID,
+                    system_program: system_program::ID,
+                })
+                .args(instruction::RegisterChain {
+                    chain_id,
+                    chain_index,
+                    name: name.to_string(),
+                    address_format,
+                    required_confirmations: 12,
+                    default_gas_limit: 500_000,
+                    max_gas_limit: 5_000_000,
+                })
+                .send()?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Fund `wallet` from the faucet and wait for the airdrop to land
+    pub fn airdrop(&self, wallet: &Pubkey, lamports: u64) -> Result<()> {
+        let rpc = self.program.rpc();
+        let signature = rpc.request_airdrop(wallet, lamports)?;
+        while !rpc.confirm_transaction(&signature)? {
+            thread::sleep(Duration::from_millis(500));
+        }
+        Ok(())
+    }
+    
+    /// Mint a new NFT held by `owner`, returning its mint address
+    pub fn mint(&self, owner: &Keypair) -> Result<Pubkey> {
+        let mint = Keypair::new();
+        let program_state = self.program_state()?;
+        let token_id_slot = self.program.rpc().get_slot()?;
+        let token_id = derive_token_id(&mint.pubkey(), token_id_slot, program_state.next_token_id);
+        
+        println!("==> mint_nft {}", mint.pubkey());
+        self.program
+            .request()
+            .accounts(accounts::MintNFT {
+                program_state: find_program_state().0,
+                pause_config: find_pause_config().0,
+                gateway_state: find_gateway_state().0,
+                chain_config: find_chain_config(ZETA_CHAIN_ID_SOLANA).0,
+                collection_config: find_collection_config().0,
+                mint: mint.pubkey(),
+                mint_ata: get_associated_token_address(&owner.pubkey(), &mint.pubkey()),
+                nft_metadata: find_nft_metadata(&mint.pubkey()).0,
+                nft_origin: find_nft_origin(&token_id).0,
+                metadata: find_metaplex_metadata(&mint.pubkey()).0,
+                master_edition: find_master_edition(&mint.pubkey()).0,
+                mint_authority: find_mint_authority().0,
+                payer: owner.pubkey(),
+                minter: owner.pubkey(),
+                allowlist_entry: None,
+                allowlist_mint_record: None,
+                verified_collection: None,
+                treasury: None,
+                payment_mint: None,
+                payer_payment_account: None,
+                treasury_payment_account: None,
+                payment_token_program: None,
+                collection_state: None,
+                token_balance: None,
+                token_record: None,
+                authorization_rules: None,
+                authorization_rules_program: None,
+                sysvar_instructions: None,
+                system_program: system_program::ID,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                token_metadata_program: anchor_spl::metadata::mpl_token_metadata::ID,
+                rent: sysvar::rent::ID,
+            })
+            .args(instruction::MintNft {
+                metadata_uri: "https://example.com/nft/1.json".to_string(),
+                zeta_chain_id: ZETA_CHAIN_ID_SOLANA,
+                payload: CrossChainPayload::V2(PayloadV2 {
+                    token_id,
+                    uri: "https://example.com/nft/1.json".to_string(),
+                    recipient: owner.pubkey().to_bytes().to_vec(),
+                    sender: owner.pubkey().to_bytes().to_vec(),
+                    royalty_basis_points: 0,
+                    data: Vec::new(),
+                    timestamp: unix_now(),
+                }),
+                token_id_slot,
+                name: "Universal NFT #1".to_string(),
+                symbol: DEFAULT_METADATA_SYMBOL.to_string(),
+                seller_fee_basis_points: 0,
+                creators: Vec::new(),
+                allowlist_proof: None,
+                coupon: None,
+            })
+            .signer(owner)
+            .signer(&mint)
+            .send()?;
+        
+        Ok(mint.pubkey())
+    }
+    
+    /// Bridge the NFT out to an EVM chain, moving it into escrow
+    pub fn bridge_out(&self, owner: &Keypair, mint: &Pubkey, target_chain_id: u64) -> Result<()> {
+        let metadata = self.program.account::<NFTMetadata>(find_nft_metadata(mint).0)?;
+        let outbox = self.program.account::<Outbox>(find_outbox().0)?;
+        let (escrow_authority, _) = find_escrow_authority();
+        
+        println!("==> cross_chain_transfer to chain {}", target_chain_id);
+        self.program
+            .request()
+            .accounts(accounts::CrossChainTransfer {
+                program_state: find_program_state().0,
+                pause_config: find_pause_config().0,
+                denylist: find_denylist().0,
+                rate_limiter: find_rate_limiter().0,
+                gateway_state: find_gateway_state().0,
+                chain_config: find_chain_config(target_chain_id).0,
+                nft_metadata: find_nft_metadata(mint).0,
+                nft_origin: find_nft_origin(&metadata.token_id).0,
+                nft_mint: *mint,
+                owner_token_account: get_associated_token_address(&owner.pubkey(), mint),
+                frozen_asset: find_frozen_asset(mint).0,
+                escrow_authority,
+                escrow_token_account: get_associated_token_address(&escrow_authority, mint),
+                transfer_state: find_cross_chain_transfer(mint).0,
+                outbox: find_outbox().0,
+                outbox_message: find_outbox_message(outbox.next_sequence).0,
+                owner_velocity: find_owner_velocity(&owner.pubkey()).0,
+                transfer_history: find_transfer_history(mint).0,
+                collection_state: None,
+                payer: owner.pubkey(),
+                owner: owner.pubkey(),
+                mint_authority: None,
+                system_program: system_program::ID,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                rent: sysvar::rent::ID,
+            })
+            .args(instruction::CrossChainTransfer {
+                target_chain_id,
+                recipient: EVM_RECIPIENT.to_vec(),
+                lane: MessageLane::Standard,
+                gas_amount: 0,
+                gas_limit: 0, // Chain default
+                memo: None,
+                amount: 1,
+            })
+            .signer(owner)
+            .send()?;
+        
+        Ok(())
+    }
+    
+    /// Mock the gateway confirming delivery on the target chain
+    pub fn confirm_outbound(&self, mint: &Pubkey) -> Result<()> {
+        let transfer = self.program.account::<CrossChainTransferState>(find_cross_chain_transfer(mint).0)?;
+        
+        println!("==> confirm_outbound_transfer (mock gateway)");
+        self.program
+            .request()
+            .accounts(accounts::ConfirmOutboundTransfer {
+                gateway_state: find_gateway_state().0,
+                transfer_state: find_cross_chain_transfer(mint).0,
+                nft_mint: *mint,
+                gateway_authority: self.gateway.pubkey(),
+                solana_balance: None,
+                target_balance: None,
+                provenance: find_provenance(&transfer.token_id).0,
+                system_program: system_program::ID,
+            })
+            .args(instruction::ConfirmOutboundTransfer {
+                zeta_tx_hash: [0x01; 32],
+            })
+            .signer(&self.gateway)
+            .send()?;
+        
+        Ok(())
+    }
+    
+    /// Secp256k1 precompile instruction carrying the observer's signature over an inbound message
+    pub fn observer_signature(&self, source_chain_id: u64, zeta_tx_hash: &[u8; 32], payload: &CrossChainPayload) -> Result<Instruction> {
+        let digest = incoming_message_digest(source_chain_id, zeta_tx_hash, &payload.hash()?);
+        Ok(new_secp256k1_instruction(&self.observer, &digest))
+    }
+    
+    /// Mock the gateway delivering the NFT back from `source_chain_id` to `recipient`
+    pub fn mock_return(&self, mint: &Pubkey, source_chain_id: u64, recipient: &Pubkey) -> Result<()> {
+        let metadata = self.program.account::<NFTMetadata>(find_nft_metadata(mint).0)?;
+        let transfer = self.program.account::<CrossChainTransferState>(find_cross_chain_transfer(mint).0)?;
+        let (escrow_authority, _) = find_escrow_authority();
+        
+        let zeta_tx_hash = [0x02; 32];
+        let payload = CrossChainPayload::V2(PayloadV2 {
+            token_id: metadata.token_id,
+            uri: metadata.metadata_uri.clone(),
+            recipient: recipient.to_bytes().to_vec(),
+            sender: EVM_RECIPIENT.to_vec(),
+            royalty_basis_points: 0,
+            data: Vec::new(),
+            timestamp: unix_now(),
+        });
+        
+        println!("==> process_returning_nft from chain {} (mock gateway)", source_chain_id);
+        self.program
+            .request()
+            .instruction(self.observer_signature(source_chain_id, &zeta_tx_hash, &payload)?)
+            .accounts(accounts::ProcessReturningNFT {
+                program_state: find_program_state().0,
+                pause_config: find_pause_config().0,
+                denylist: find_denylist().0,
+                rate_limiter: find_rate_limiter().0,
+                gateway_state: find_gateway_state().0,
+                chain_config: find_chain_config(source_chain_id).0,
+                nft_origin: find_nft_origin(&metadata.token_id).0,
+                provenance: find_provenance(&metadata.token_id).0,
+                transfer_history: find_transfer_history(mint).0,
+                nft_metadata: find_nft_metadata(mint).0,
+                nft_mint: *mint,
+                transfer_state: find_cross_chain_transfer(mint).0,
+                frozen_asset: find_frozen_asset(mint).0,
+                original_sender: transfer.sender,
+                escrow_authority,
+                escrow_token_account: get_associated_token_address(&escrow_authority, mint),
+                recipient_token_account: get_associated_token_address(recipient, mint),
+                recipient: *recipient,
+                gateway_authority: self.gateway.pubkey(),
+                mint_authority: None,
+                source_balance: None,
+                solana_balance: None,
+                rewards_config: None,
+                points_ledger: None,
+                instructions: sysvar::instructions::ID,
+                system_program: system_program::ID,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                rent: sysvar::rent::ID,
+            })
+            .args(instruction::ProcessReturningNft {
+                source_chain_id,
+                payload,
+                zeta_tx_hash,
+                observer_signatures: Vec::new(),
+            })
+            .signer(&self.gateway)
+            .send()?;
+        
+        Ok(())
+    }
+    
+    /// Mock the gateway reverting a failed outbound transfer
+    pub fn revert(&self, mint: &Pubkey) -> Result<()> {
+        let transfer = self.program.account::<CrossChainTransferState>(find_cross_chain_transfer(mint).0)?;
+        let (escrow_authority, _) = find_escrow_authority();
+        
+        println!("==> revert_cross_chain_transfer (mock gateway)");
+        self.program
+            .request()
+            .accounts(accounts::RevertCrossChainTransfer {
+                program_state: find_program_state().0,
+                gateway_state: find_gateway_state().0,
+                transfer_state: find_cross_chain_transfer(mint).0,
+                nft_metadata: find_nft_metadata(mint).0,
+                nft_mint: *mint,
+                escrow_authority,
+                escrow_token_account: get_associated_token_address(&escrow_authority, mint),
+                owner_token_account: get_associated_token_address(&transfer.sender, mint),
+                original_owner: transfer.sender,
+                transfer_history: find_transfer_history(mint).0,
+                gateway_authority: self.gateway.pubkey(),
+                mint_authority: None,
+                system_program: system_program::ID,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                rent: sysvar::rent::ID,
+            })
+            .args(instruction::RevertCrossChainTransfer {
+                revert_tx_hash: [0x03; 32],
+            })
+            .signer(&self.gateway)
+            .send()?;
+        
+        Ok(())
+    }
+    
+    /// Check that `owner` holds the NFT and is recorded as its owner
+    pub fn verify_held_by(&self, mint: &Pubkey, owner: &Pubkey) -> Result<()> {
+        let metadata = self.program.account::<NFTMetadata>(find_nft_metadata(mint).0)?;
+        ensure!(metadata.owner == *owner, "metadata owner is {}, expected {}", metadata.owner, owner);
+        
+        let token_account = self
+            .program
+            .account::<TokenAccount>(get_associated_token_address(owner, mint))
+            .context("owner token account")?;
+        ensure!(token_account.amount == 1, "owner holds {} tokens", token_account.amount);
+        
+        println!("    verified: {} holds {}", owner, mint);
+        Ok(())
+    }
+    
+    /// Check that the NFT sits in escrow with a transfer in `status`
+    pub fn verify_escrowed(&self, mint: &Pubkey, status: TransferStatus) -> Result<()> {
+        let (escrow_authority, _) = find_escrow_authority();
+        let escrow = self
+            .program
+            .account::<TokenAccount>(get_associated_token_address(&escrow_authority, mint))
+            .context("escrow token account")?;
+        ensure!(escrow.amount == 1, "escrow holds {} tokens", escrow.amount);
+        
+        let transfer = self.program.account::<CrossChainTransferState>(find_cross_chain_transfer(mint).0)?;
+        ensure!(transfer.status == status, "unexpected transfer status");
+        
+        println!("    verified: {} escrowed", mint);
+        Ok(())
+    }
+}
+
+/// Wall-clock time used to timestamp mock inbound payloads
+pub fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or_default()
+}
@@ -0,0 +1,94 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Commitment, PublicKey } from "@solana/web3.js";
+import { ZetachainUniversalNft } from "../target/types/zetachain_universal_nft";
+
+// Real-time account subscriptions for Universal NFT frontends
+
+export type CrossChainTransferState = anchor.IdlAccounts<ZetachainUniversalNft>["crossChainTransferState"];
+export type NftMetadata = anchor.IdlAccounts<ZetachainUniversalNft>["nftMetadata"];
+
+export type TransferStatusName = "pending" | "inProgress" | "completed" | "failed";
+
+export interface BridgeProgress {
+  status: TransferStatusName;
+  step: number;
+  totalSteps: number;
+  label: string;
+  done: boolean;
+}
+
+export interface Subscription {
+  unsubscribe: () => Promise<void>;
+}
+
+const BRIDGE_STEPS = 3;
+
+// Anchor decodes Rust enums as `{ variantName: {} }`
+export function transferStatusName(status: object): TransferStatusName {
+  return Object.keys(status)[0] as TransferStatusName;
+}
+
+export function bridgeProgress(state: CrossChainTransferState): BridgeProgress {
+  const status = transferStatusName(state.status);
+  const relayed = state.zetaTxHash.some((byte: number) => byte !== 0);
+
+  switch (status) {
+    case "completed":
+      return { status, step: 3, totalSteps: BRIDGE_STEPS, label: "Delivered on destination chain", done: true };
+    case "failed":
+      return { status, step: 3, totalSteps: BRIDGE_STEPS, label: "Reverted and returned to owner", done: true };
+    case "inProgress":
+      return relayed
+        ? { status, step: 2, totalSteps: BRIDGE_STEPS, label: "Relayed through ZetaChain", done: false }
+        : { status, step: 1, totalSteps: BRIDGE_STEPS, label: "Escrowed on Solana", done: false };
+    default:
+      return { status, step: 0, totalSteps: BRIDGE_STEPS, label: "Pending", done: false };
+  }
+}
+
+export function findTransferStatePda(program: Program<ZetachainUniversalNft>, mint: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("cross_chain_transfer"), mint.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function findNftMetadataPda(program: Program<ZetachainUniversalNft>, mint: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("nft_metadata"), mint.toBuffer()],
+    program.programId
+  )[0];
+}
+
+// Stream typed CrossChainTransferState updates with bridge progress for a mint
+export function subscribeToTransfer(
+  program: Program<ZetachainUniversalNft>,
+  mint: PublicKey,
+  onUpdate: (state: CrossChainTransferState, progress: BridgeProgress) => void,
+  commitment: Commitment = "confirmed"
+): Subscription {
+  const address = findTransferStatePda(program, mint);
+  const emitter = program.account.crossChainTransferState.subscribe(address, commitment);
+  emitter.on("change", (state: CrossChainTransferState) => onUpdate(state, bridgeProgress(state)));
+
+  return {
+    unsubscribe: () => program.account.crossChainTransferState.unsubscribe(address),
+  };
+}
+
+// Stream typed NFTMetadata updates (owner changes, metadata updates) for a mint
+export function subscribeToNftMetadata(
+  program: Program<ZetachainUniversalNft>,
+  mint: PublicKey,
+  onUpdate: (metadata: NftMetadata) => void,
+  commitment: Commitment = "confirmed"
+): Subscription {
+  const address = findNftMetadataPda(program, mint);
+  const emitter = program.account.nftMetadata.subscribe(address, commitment);
+  emitter.on("change", (metadata: NftMetadata) => onUpdate(metadata));
+
+  return {
+    unsubscribe: () => program.account.nftMetadata.unsubscribe(address),
+  };
+}
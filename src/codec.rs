@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::UniversalNFTError,
+    constants::*,
+};
+
+// Universal NFT message codec
+//
+// Messages use the Solidity `abi.encode(uint256 tokenId, string uri, bytes receiver, bytes sender,
+// uint256 gasLimit, uint256 amount)` layout expected by the ZetaChain EVM Universal NFT contracts.
+// Addresses are encoded as `bytes` so both 20-byte EVM addresses and 32-byte Solana public keys fit
+// the same message. `amount` is always present and is one for NFTs, so the tuple decodes with a
+// single `abi.decode` signature.
+
+const WORD: usize = 32;
+const HEAD_WORDS: usize = 6;
+
+/// Decoded cross-chain NFT message
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NFTMessage {
+    pub token_id: [u8; 32],
+    pub uri: String,
+    pub receiver: Vec<u8>,
+    pub sender: Vec<u8>,
+    pub gas_limit: u64, // Execution gas limit on the destination chain
+    pub amount: u64, // Units moved; one for NFTs
+}
+
+/// Encode a message in the EVM abi.encode layout
+pub fn encode_nft_message(message: &NFTMessage) -> Vec<u8> {
+    let uri_offset = HEAD_WORDS * WORD;
+    let receiver_offset = uri_offset + encoded_bytes_len(message.uri.len());
+    let sender_offset = receiver_offset + encoded_bytes_len(message.receiver.len());
+    
+    let mut out = Vec::with_capacity(sender_offset + encoded_bytes_len(message.sender.len()));
+    out.extend_from_slice(&message.token_id);
+    out.extend_from_slice(&encode_u64_word(uri_offset as u64));
+    out.extend_from_slice(&encode_u64_word(receiver_offset as u64));
+    out.extend_from_slice(&encode_u64_word(sender_offset as u64));
+    out.extend_from_slice(&encode_u64_word(message.gas_limit));
+    out.extend_from_slice(&encode_u64_word(message.amount));
+    encode_bytes(&mut out, message.uri.as_bytes());
+    encode_bytes(&mut out, &message.receiver);
+    encode_bytes(&mut out, &message.sender);
+    out
+}
+
+/// Decode a message from the EVM abi.encode layout
+pub fn decode_nft_message(data: &[u8]) -> Result<NFTMessage> {
+    if data.len() < HEAD_WORDS * WORD || data.len() > MAX_CROSS_CHAIN_DATA_LENGTH {
+        return err!(UniversalNFTError::InvalidCrossChainData);
+    }
+    
+    let mut token_id = [0u8; 32];
+    token_id.copy_from_slice(read_word(data, 0)?);
+    let uri = decode_bytes(data, read_word(data, WORD)?)?;
+    let receiver = decode_bytes(data, read_word(data, 2 * WORD)?)?;
+    let sender = decode_bytes(data, read_word(data, 3 * WORD)?)?;
+    let gas_limit = decode_u64_word(read_word(data, 4 * WORD)?)?;
+    let amount = decode_u64_word(read_word(data, 5 * WORD)?)?;
+    
+    let uri = String::from_utf8(uri).map_err(|_| error!(UniversalNFTError::InvalidCrossChainData))?;
+    if uri.len() > MAX_METADATA_URI_LENGTH_LIMIT {
+        return err!(UniversalNFTError::InvalidMetadataURILength);
+    }
+    if receiver.is_empty() || receiver.len() > MAX_RECIPIENT_ADDRESS_LENGTH {
+        return err!(UniversalNFTError::InvalidRecipientAddress);
+    }
+    if amount == 0 {
+        return err!(UniversalNFTError::InvalidAmount);
+    }
+    
+    Ok(NFTMessage {
+        token_id,
+        uri,
+        receiver,
+        sender,
+        gas_limit,
+        amount,
+    })
+}
+
+/// Convert a sequential Solana token counter into a uint256 token ID
+pub fn token_id_from_u64(value: u64) -> [u8; 32] {
+    encode_u64_word(value)
+}
+
+/// Derive a Solana-minted token ID: keccak(mint || uint256(slot) || uint256(counter))
+pub fn derive_token_id(mint: &Pubkey, slot: u64, counter: u64) -> [u8; 32] {
+    anchor_lang::solana_program::keccak::hashv(&[
+        mint.as_ref(),
+        &encode_u64_word(slot),
+        &encode_u64_word(counter),
+    ])
+    .to_bytes()
+}
+
+fn encoded_bytes_len(len: usize) -> usize {
+    WORD + len.div_ceil(WORD) * WORD
+}
+
+fn encode_u64_word(value: u64) -> [u8; WORD] {
+    let mut word = [0u8; WORD];
+    word[WORD - 8..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+fn encode_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&encode_u64_word(bytes.len() as u64));
+    out.extend_from_slice(bytes);
+    let padding = (WORD - bytes.len() % WORD) % WORD;
+    out.resize(out.len() + padding, 0);
+}
+
+fn read_word(data: &[u8], offset: usize) -> Result<&[u8]> {
+    let end = offset.checked_add(WORD).ok_or(error!(UniversalNFTError::InvalidCrossChainData))?;
+    data.get(offset..end).ok_or(error!(UniversalNFTError::InvalidCrossChainData))
+}
+
+fn decode_u64_word(word: &[u8]) -> Result<u64> {
+    // Offsets and lengths must fit in 64 bits
+    if word[..WORD - 8].iter().any(|b| *b != 0) {
+        return err!(UniversalNFTError::InvalidCrossChainData);
+    }
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&word[WORD - 8..]);
+    Ok(u64::from_be_bytes(bytes))
+}
+
+fn decode_bytes(data: &[u8], offset_word: &[u8]) -> Result<Vec<u8>> {
+    let offset = decode_u64_word(offset_word).map_err(|_| error!(UniversalNFTError::InvalidCrossChainData))? as usize;
+    let len = decode_u64_word(read_word(data, offset)?).map_err(|_| error!(UniversalNFTError::InvalidCrossChainData))? as usize;
+    let start = offset + WORD;
+    let end = start.checked_add(len).ok_or(error!(UniversalNFTError::InvalidCrossChainData))?;
+    data.get(start..end)
+        .map(|bytes| bytes.to_vec())
+        .ok_or(error!(UniversalNFTError::InvalidCrossChainData))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    // abi.encode(uint256(1), "ipfs://x", bytes(20 x 0x11), bytes(32 x 0x22), uint256(200000), uint256(1))
+    const ENCODED: &str = concat!(
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "00000000000000000000000000000000000000000000000000000000000000c0",
+        "0000000000000000000000000000000000000000000000000000000000000100",
+        "0000000000000000000000000000000000000000000000000000000000000140",
+        "0000000000000000000000000000000000000000000000000000000000030d40",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000008",
+        "697066733a2f2f78000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000014",
+        "1111111111111111111111111111111111111111000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000020",
+        "2222222222222222222222222222222222222222222222222222222222222222",
+    );
+    
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+    
+    fn message() -> NFTMessage {
+        NFTMessage {
+            token_id: token_id_from_u64(1),
+            uri: "ipfs://x".to_string(),
+            receiver: vec![0x11; 20],
+            sender: vec![0x22; 32],
+            gas_limit: 200_000,
+            amount: 1,
+        }
+    }
+    
+    #[test]
+    fn encodes_the_abi_layout() {
+        assert_eq!(encode_nft_message(&message()), from_hex(ENCODED));
+    }
+    
+    #[test]
+    fn decodes_the_abi_layout() {
+        assert_eq!(decode_nft_message(&from_hex(ENCODED)).unwrap(), message());
+    }
+    
+    #[test]
+    fn round_trips_semi_fungible_amounts() {
+        let message = NFTMessage { amount: 25, ..message() };
+        assert_eq!(decode_nft_message(&encode_nft_message(&message)).unwrap(), message);
+    }
+    
+    #[test]
+    fn rejects_a_zero_amount() {
+        let encoded = encode_nft_message(&NFTMessage { amount: 0, ..message() });
+        assert!(decode_nft_message(&encoded).is_err());
+    }
+    
+    #[test]
+    fn rejects_a_truncated_message() {
+        let encoded = from_hex(ENCODED);
+        assert!(decode_nft_message(&encoded[..encoded.len() - WORD]).is_err());
+    }
+}
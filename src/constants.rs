@@ -3,10 +3,24 @@ use anchor_lang::prelude::*;
 // Universal NFT Program Constants
 
 // Maximum lengths
-pub const MAX_METADATA_URI_LENGTH: usize = 200;
+pub const MAX_METADATA_URI_LENGTH: usize = 200; // Default per-NFT URI limit, and the limit for collection URIs
+pub const MAX_METADATA_URI_LENGTH_LIMIT: usize = 512; // Highest URI limit the authority may configure
 pub const MAX_CROSS_CHAIN_DATA_LENGTH: usize = 1000;
 pub const MAX_RECIPIENT_ADDRESS_LENGTH: usize = 100;
-pub const MAX_SUPPORTED_CHAINS: usize = 13;
+pub const MAX_MEMO_LENGTH: usize = 64; // Transfer memo/reference, e.g. an order ID
+pub const MAX_SUPPORTED_CHAINS: usize = 128; // Bits in the gateway's chain masks
+pub const MAX_OBSERVERS: usize = 10; // Observer/TSS signers in the gateway's signer set
+pub const MAX_MERKLE_PROOF_DEPTH: usize = 32;
+pub const MAX_DENYLIST_ENTRIES: usize = 100;
+pub const MAX_BATCH_MINT_SIZE: usize = 5; // NFTs per batch_mint call, bounded by transaction size and compute
+pub const MAX_INCOMING_BATCH_SIZE: usize = 50; // NFTs per incoming batch message, delivered over several calls
+pub const MAX_PROVENANCE_HOPS: usize = 32; // Chain hops stored per token ID; later hops are counted and emitted only
+pub const TRANSFER_HISTORY_LEN: usize = 16; // Latest ownership changes kept per NFT for activity feeds
+pub const MAX_ROYALTY_BASIS_POINTS: u16 = 10_000;
+pub const MAX_CHAIN_NAME_LENGTH: usize = 32;
+pub const MAX_NAME_LENGTH: usize = 32; // Metaplex metadata name
+pub const MAX_SYMBOL_LENGTH: usize = 10; // Metaplex metadata symbol
+pub const MAX_CREATORS: usize = 5; // Metaplex allows up to 5 creators
 
 // ZetaChain Network IDs
 pub const ZETA_CHAIN_ID_SOLANA: u64 = 1;
@@ -28,30 +42,119 @@ pub const SOLANA_DECIMALS: u8 = 0;
 pub const SOLANA_RENT_EXEMPTION: u64 = 2_039_280; // Minimum rent exemption for accounts
 
 // Security constants
-pub const REPLAY_PROTECTION_WINDOW: i64 = 300; // 5 minutes in seconds
+pub const REPLAY_PROTECTION_WINDOW: i64 = 300; // 5 minutes in seconds, default message expiry window
+pub const MIN_MESSAGE_EXPIRY_WINDOW: i64 = 60; // 1 minute in seconds
+pub const MAX_MESSAGE_EXPIRY_WINDOW: i64 = 86_400; // 1 day in seconds
 pub const TSS_TIMEOUT: i64 = 3600; // 1 hour in seconds
 pub const MINIMUM_GATEWAY_UPDATE_INTERVAL: i64 = 60; // 1 minute in seconds
+pub const GATEWAY_FRESHNESS_WINDOW: i64 = 7_776_000; // 90 days in seconds before an untouched gateway is treated as stale
+pub const MIN_TSS_GRACE_PERIOD: i64 = 600; // 10 minutes in seconds
+pub const MAX_TSS_GRACE_PERIOD: i64 = 604_800; // 7 days in seconds
+pub const MIN_TRANSFER_TIMEOUT: i64 = 600; // 10 minutes in seconds
+pub const MAX_TRANSFER_TIMEOUT: i64 = 604_800; // 7 days in seconds
+pub const MAX_CHALLENGE_PERIOD: i64 = 604_800; // 7 days in seconds
+pub const MIN_STUCK_TRANSFER_AGE: i64 = 259_200; // 3 days in seconds before the authority can force-fail
+pub const CLAIM_EXPIRY_PERIOD: i64 = 2_592_000; // 30 days in seconds before an unclaimed NFT can be swept
+pub const RATE_LIMIT_WINDOW: i64 = 3600; // 1 hour in seconds, default rate-limit window
+pub const MIN_RATE_LIMIT_WINDOW: i64 = 60; // 1 minute in seconds
+pub const MAX_RATE_LIMIT_WINDOW: i64 = 86_400; // 1 day in seconds
+pub const DEFAULT_MAX_TRANSFERS_PER_WINDOW: u64 = 100;
+pub const DEFAULT_MAX_VALUE_PER_WINDOW: u64 = 100_000_000_000; // 100 SOL in lamports
+pub const SECONDS_PER_DAY: i64 = 86_400;
+pub const DEFAULT_MAX_OWNER_TRANSFERS_PER_DAY: u64 = 20;
+
+// Feature flags (ProgramState.feature_flags)
+pub const FEATURE_MARKETPLACE: u64 = 1 << 0; // sell_nft, transfer_from and permit_transfer
+// 1 << 1 is reserved; it was a staking flag that never gated anything
+pub const FEATURE_PUBLIC_MINT: u64 = 1 << 2; // mint_nft open to any wallet, not just the authority
+pub const FEATURE_COMPRESSED_MODE: u64 = 1 << 3; // process_incoming_compressed_nft
+pub const FEATURE_ALLOWLIST_MINT: u64 = 1 << 4; // mint_nft restricted to AllowlistEntry holders
+pub const FEATURE_ALL: u64 = FEATURE_MARKETPLACE | FEATURE_PUBLIC_MINT | FEATURE_COMPRESSED_MODE | FEATURE_ALLOWLIST_MINT;
+pub const DEFAULT_FEATURE_FLAGS: u64 = 0; // Minimal bridge configuration
+
+// Pausable operations (PauseConfig.paused_operations)
+pub const PAUSE_MINT: u64 = 1 << 0;
+pub const PAUSE_TRANSFER: u64 = 1 << 1;
+pub const PAUSE_BURN: u64 = 1 << 2;
+pub const PAUSE_OUTBOUND: u64 = 1 << 3;
+pub const PAUSE_INBOUND: u64 = 1 << 4;
+pub const PAUSE_ALL: u64 = PAUSE_MINT | PAUSE_TRANSFER | PAUSE_BURN | PAUSE_OUTBOUND | PAUSE_INBOUND;
+
+// Metadata URI schemes (CollectionConfig.allowed_uri_schemes)
+pub const URI_SCHEME_IPFS: u8 = 1 << 0;
+pub const URI_SCHEME_ARWEAVE: u8 = 1 << 1;
+pub const URI_SCHEME_HTTPS: u8 = 1 << 2;
+pub const URI_SCHEME_ALL: u8 = URI_SCHEME_IPFS | URI_SCHEME_ARWEAVE | URI_SCHEME_HTTPS;
+pub const URI_SCHEME_PREFIXES: [(u8, &str); 3] = [
+    (URI_SCHEME_IPFS, "ipfs://"),
+    (URI_SCHEME_ARWEAVE, "ar://"),
+    (URI_SCHEME_HTTPS, "https://"),
+];
+
+// Identity binding
+pub const IDENTITY_BINDING_DOMAIN: &[u8] = b"universal-nft:identity-binding:v1";
+pub const EVM_ADDRESS_LENGTH: usize = 20;
+
+// Observer signatures
+pub const INCOMING_MESSAGE_DOMAIN: &[u8] = b"universal-nft:incoming-message:v1";
+pub const MINT_COUPON_DOMAIN: &[u8] = b"universal-nft:mint-coupon:v1";
+pub const PERMIT_DOMAIN: &[u8] = b"universal-nft:permit:v1";
+
+// Rewards constants
+pub const DEFAULT_POINTS_PER_ROUND_TRIP: u64 = 0; // Rewards disabled until configured
+pub const MAX_POINTS_PER_ROUND_TRIP: u64 = 1_000_000;
 
 // Fee constants
 pub const CROSS_CHAIN_TRANSFER_FEE: u64 = 0; // No fee for now
 pub const MINT_FEE: u64 = 0; // No fee for now
+pub const PRIORITY_LANE_FEE: u64 = 5_000_000; // 0.005 SOL surcharge for the priority outbox lane
 
 // Default metadata values
-pub const DEFAULT_METADATA_NAME: &str = "Universal NFT";
-pub const DEFAULT_METADATA_SYMBOL: &str = "UNFT";
+pub const DEFAULT_METADATA_NAME: &str = "Universal NFT"; // Initial ProgramState.default_name
+pub const DEFAULT_METADATA_SYMBOL: &str = "UNFT"; // Initial ProgramState.default_symbol
 pub const DEFAULT_METADATA_DESCRIPTION: &str = "Cross-chain Universal NFT";
 
 // Gateway configuration
 pub const GATEWAY_VERSION: u8 = 1;
 pub const DEFAULT_GATEWAY_ADDRESS: [u8; 20] = [0u8; 20];
 
+// Account layouts
+/// Current layout of every program account, upgraded in place by `migrate_account`:
+/// - 0: predates versioning
+/// - 1: account_version on every account
+/// - 2: NFTMetadata.collection
+/// - 3: CollectionConfig.rule_set
+/// - 4: CollectionState.asset_backend
+/// - 5: CollectionState.merkle_tree
+/// - 6: NFTMetadata.metadata_immutable
+/// - 7: CollectionConfig.allowed_uri_schemes
+/// - 8: NFTMetadata.metadata_hash
+/// - 9: CollectionConfig.max_uri_length
+/// - 10: ProgramState.default_name and default_symbol
+/// - 11: CollectionConfig.mint_price
+/// - 12: CollectionState.price_mint and price_amount
+/// - 13: CollectionConfig.allowlist_root
+/// - 14: CollectionConfig.mint_phases
+/// - 15: CrossChainTransferState.memo
+/// - 16: NFTMetadata.royalty_basis_points and royalty_recipient
+/// - 17: CrossChainTransferState.amount
+pub const ACCOUNT_VERSION: u16 = 17;
+
+// Devnet faucet constants
+pub const DEVNET_GENESIS_HASH: [u8; 32] = [
+    206, 89, 219, 80, 128, 252, 44, 109, 59, 207, 124, 169, 7, 18, 211, 194,
+    229, 230, 194, 143, 39, 240, 223, 187, 153, 83, 189, 176, 137, 76, 3, 171,
+]; // EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG
+pub const FAUCET_METADATA_URI: &str = "https://example.com/universal-nft/faucet.json";
+
 // Token ID generation constants
-pub const TOKEN_ID_SEED: &[u8] = b"nft_origin";
 pub const TOKEN_ID_OFFSET: u64 = 1000000; // Offset to ensure uniqueness
+pub const MAX_TOKEN_ID_SLOT_AGE: u64 = 150; // Slots a caller-supplied mint slot may lag behind
 
 // Metaplex constants
 pub const METADATA_PROGRAM_ID: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
 pub const MASTER_EDITION_PROGRAM_ID: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
+pub const TOKEN_AUTH_RULES_PROGRAM_ID: Pubkey = solana_program::pubkey!("auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg");
 
 // Error message constants
 pub const ERROR_INVALID_CHAIN_ID: &str = "Invalid ZetaChain ID";
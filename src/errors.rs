@@ -70,4 +70,334 @@ pub enum UniversalNFTError {
     
     #[msg("TSS verification failed")]
     TSSVerificationFailed,
+    
+    #[msg("Transfer timeout has not elapsed")]
+    TransferTimeoutNotReached,
+    
+    #[msg("Invalid transfer timeout")]
+    InvalidTransferTimeout,
+    
+    #[msg("Feature is disabled")]
+    FeatureDisabled,
+    
+    #[msg("Invalid feature flags")]
+    InvalidFeatureFlags,
+    
+    #[msg("Invalid notification commitment")]
+    InvalidNotificationCommitment,
+    
+    #[msg("Invalid token ID")]
+    InvalidTokenId,
+    
+    #[msg("Identity verification failed")]
+    IdentityVerificationFailed,
+    
+    #[msg("Invalid royalty basis points")]
+    InvalidRoyaltyBasisPoints,
+    
+    #[msg("Account already migrated")]
+    AccountAlreadyMigrated,
+    
+    #[msg("Invalid legacy account data")]
+    InvalidLegacyAccount,
+    
+    #[msg("Insufficient points balance")]
+    InsufficientPoints,
+    
+    #[msg("Invalid redemption hook")]
+    InvalidRedemptionHook,
+    
+    #[msg("Token ID slot is in the future or too old")]
+    InvalidTokenIdSlot,
+    
+    #[msg("Invalid gateway validity window")]
+    InvalidGatewayWindow,
+    
+    #[msg("Next gateway is not yet active")]
+    NextGatewayNotActive,
+    
+    #[msg("Invalid chain name")]
+    InvalidChainName,
+    
+    #[msg("Token account is not a wrapped SOL account")]
+    InvalidWrappedSolAccount,
+    
+    #[msg("NFT mint does not match the recorded mint")]
+    MintMismatch,
+    
+    #[msg("Account does not match the recorded transfer sender")]
+    SenderMismatch,
+    
+    #[msg("Recipient does not match the message recipient")]
+    RecipientMismatch,
+    
+    #[msg("Token account does not match the expected address")]
+    InvalidTokenAccount,
+    
+    #[msg("Account is not owned by the expected program")]
+    InvalidAccountOwner,
+    
+    #[msg("Signer is not the NFT owner")]
+    NotNFTOwner,
+    
+    #[msg("Gas limit exceeds the chain maximum")]
+    GasLimitExceeded,
+    
+    #[msg("Invalid gas limit configuration")]
+    InvalidGasLimit,
+    
+    #[msg("Royalties are enforced; use a royalty-enforcing transfer")]
+    RoyaltyEnforced,
+    
+    #[msg("Cross-chain message is outside the accepted time window")]
+    MessageExpired,
+    
+    #[msg("Invalid message expiry window")]
+    InvalidMessageExpiryWindow,
+    
+    #[msg("Emission schedule limit reached for this period")]
+    EmissionLimitReached,
+    
+    #[msg("Invalid emission schedule")]
+    InvalidEmissionSchedule,
+    
+    #[msg("Faucet is only available on devnet")]
+    FaucetUnavailable,
+    
+    #[msg("Invalid pending authority")]
+    InvalidPendingAuthority,
+    
+    #[msg("Invalid chain index")]
+    InvalidChainIndex,
+    
+    #[msg("Chain index is already assigned")]
+    ChainIndexInUse,
+    
+    #[msg("Invalid authority program")]
+    InvalidAuthorityProgram,
+    
+    #[msg("Owner token account does not hold the NFT")]
+    TokenNotHeld,
+    
+    #[msg("Program is paused")]
+    ProgramPaused,
+    
+    #[msg("Operation is paused")]
+    OperationPaused,
+    
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+    
+    #[msg("Chain is disabled")]
+    ChainDisabled,
+    
+    #[msg("Bridge rate limit exceeded")]
+    RateLimitExceeded,
+    
+    #[msg("Invalid rate limit")]
+    InvalidRateLimit,
+    
+    #[msg("Daily transfer limit reached for this wallet")]
+    VelocityLimitExceeded,
+    
+    #[msg("Minted supply counter overflow")]
+    SupplyOverflow,
+    
+    #[msg("Minted supply counter underflow")]
+    SupplyUnderflow,
+    
+    #[msg("Token ID counter overflow")]
+    TokenIdOverflow,
+    
+    #[msg("Invalid TSS grace period")]
+    InvalidGracePeriod,
+    
+    #[msg("A gateway rotation is already pending")]
+    GatewayRotationPending,
+    
+    #[msg("Invalid observer set")]
+    InvalidObserverSet,
+    
+    #[msg("Observer set is not configured")]
+    ObserverSetNotConfigured,
+    
+    #[msg("Invalid observer signature")]
+    InvalidObserverSignature,
+    
+    #[msg("Not enough observer signatures")]
+    InsufficientObserverSignatures,
+    
+    #[msg("Invalid signature precompile instruction")]
+    InvalidPrecompileInstruction,
+    
+    #[msg("Invalid Merkle proof")]
+    InvalidMerkleProof,
+    
+    #[msg("Cross-chain ownership has not been verified")]
+    OwnershipNotVerified,
+    
+    #[msg("Asset is frozen pending review")]
+    AssetFrozen,
+    
+    #[msg("Recipient is on the denylist")]
+    RecipientDenied,
+    
+    #[msg("Denylist is full")]
+    DenylistFull,
+    
+    #[msg("Minter is not on the allowlist")]
+    NotAllowlisted,
+    
+    #[msg("Invalid challenge period")]
+    InvalidChallengePeriod,
+    
+    #[msg("Challenge period is active; deliveries go through escrow")]
+    ChallengePeriodActive,
+    
+    #[msg("Challenge period has not ended")]
+    ChallengePeriodNotEnded,
+    
+    #[msg("Challenge period has ended")]
+    ChallengePeriodEnded,
+    
+    #[msg("Delivery is disputed")]
+    DeliveryDisputed,
+    
+    #[msg("Fraud proof does not show a conflict with the pending delivery")]
+    InvalidFraudProof,
+    
+    #[msg("NFT is locked by a pending cross-chain operation")]
+    NFTLocked,
+    
+    #[msg("NFT is not locked")]
+    NFTNotLocked,
+    
+    #[msg("Invalid metadata name")]
+    InvalidMetadataName,
+    
+    #[msg("Invalid metadata symbol")]
+    InvalidMetadataSymbol,
+    
+    #[msg("Invalid creators")]
+    InvalidCreators,
+    
+    #[msg("Collection does not match the NFT")]
+    CollectionMismatch,
+    
+    #[msg("Chain is not allowed for this collection")]
+    ChainNotAllowedForCollection,
+    
+    #[msg("Invalid or missing programmable NFT rule set accounts")]
+    InvalidRuleSet,
+    
+    #[msg("Collection uses a different asset backend")]
+    WrongAssetBackend,
+    
+    #[msg("Transfer hook authority PDA is required to move this NFT")]
+    MissingHookAuthority,
+    
+    #[msg("Transfer hook called outside a Token-2022 transfer")]
+    NotTransferring,
+    
+    #[msg("Merkle tree is not configured for this collection or does not match the asset ID")]
+    InvalidMerkleTree,
+    
+    #[msg("Metaplex metadata is missing or not updatable by the program")]
+    InvalidMetaplexMetadata,
+    
+    #[msg("NFT metadata is locked and can no longer change")]
+    MetadataImmutable,
+    
+    #[msg("Metadata URI scheme is not allowed")]
+    DisallowedUriScheme,
+    
+    #[msg("At least one known URI scheme must be allowed")]
+    InvalidUriSchemes,
+    
+    #[msg("Treasury account is required for paid mints")]
+    MissingTreasury,
+    
+    #[msg("Treasury balance is too low for this withdrawal")]
+    InsufficientTreasuryBalance,
+    
+    #[msg("Payment accounts are required for collections priced in an SPL token")]
+    MissingPaymentAccounts,
+    
+    #[msg("Payment mint or treasury token account does not match the collection price")]
+    InvalidPaymentAccount,
+    
+    #[msg("Wallet has used all of its allowlist mints")]
+    AllowlistMintLimitReached,
+    
+    #[msg("Mint coupon is expired or not signed by the program authority")]
+    InvalidMintCoupon,
+    
+    #[msg("Minting is outside the launch schedule")]
+    MintPhaseNotActive,
+    
+    #[msg("The current mint phase has no mints left")]
+    MintPhaseSoldOut,
+    
+    #[msg("Mint phases must start with the allowlist phase and end after the public phase starts")]
+    InvalidMintPhases,
+    
+    #[msg("Batch is empty, too large, or its remaining accounts don't match the items")]
+    InvalidBatchAccounts,
+    
+    #[msg("Every leaf of this airdrop has been claimed")]
+    AirdropFullyClaimed,
+    
+    #[msg("Airdrop needs a non-zero root and at least one leaf")]
+    InvalidAirdrop,
+    
+    #[msg("Operator cannot be the default address or the owner")]
+    InvalidOperator,
+    
+    #[msg("Signer is not approved to move this NFT")]
+    NotApproved,
+    
+    #[msg("Permit is expired, already used, or not signed by the NFT owner")]
+    InvalidPermit,
+    
+    #[msg("Memo exceeds the maximum length")]
+    InvalidMemo,
+    
+    #[msg("Royalty recipient is missing or does not match the NFT's creator")]
+    InvalidRoyaltyRecipient,
+    
+    #[msg("NFT still has supply; only records of burned NFTs can be closed")]
+    NFTNotBurned,
+    
+    #[msg("External NFT must be a one-of-one mint with Metaplex metadata")]
+    InvalidExternalNFT,
+    
+    #[msg("NFT originates on Solana; release it with process_returning_nft or unwrap_external_nft")]
+    ReturnToEscrowedMint,
+    
+    #[msg("Amount must be at least one and no more than the balance held")]
+    InvalidAmount,
+    
+    #[msg("Token balance account is required for amounts above one")]
+    MissingTokenBalance,
+    
+    #[msg("Every item of this incoming batch has been delivered")]
+    BatchAlreadyProcessed,
+    
+    #[msg("Incoming NFT can still be claimed by its recipient")]
+    ClaimNotExpired,
+    
+    #[msg("Mint authority is no longer held by the program")]
+    MintAuthorityRevoked,
+    
+    #[msg("Points balance overflow")]
+    PointsOverflow,
+    
+    #[msg("Owner's token account is not delegated to the program; call set_program_delegation first")]
+    NotDelegatedToProgram,
+    
+    #[msg("A sale attached to a transfer must be paid by the signing recipient")]
+    InvalidBuyer,
+    
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
 }
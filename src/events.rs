@@ -0,0 +1,255 @@
+use anchor_lang::prelude::*;
+
+// Universal NFT Program Events
+
+/// Emitted when the gateway confirms an outbound transfer on ZetaChain
+#[event]
+pub struct OutboundTransferConfirmed {
+    pub nft_mint: Pubkey,
+    pub token_id: [u8; 32],
+    pub sender: Pubkey,
+    pub target_chain_id: u64,
+    pub recipient: Vec<u8>,
+    pub zeta_tx_hash: [u8; 32],
+    pub memo: String,
+    pub confirmed_at: i64,
+}
+
+/// Emitted when an incoming NFT is delivered to a wallet with a notification registration
+#[event]
+pub struct IncomingNFTNotification {
+    pub recipient: Pubkey,
+    pub registration: Pubkey,
+    pub commitment: [u8; 32],
+    pub nft_mint: Pubkey,
+    pub token_id: [u8; 32],
+    pub source_chain_id: u64,
+    pub delivered_at: i64,
+}
+
+/// Emitted when a soulbound attestation is imported from another chain
+#[event]
+pub struct AttestationImported {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub issuer: Vec<u8>,
+    pub token_id: [u8; 32],
+    pub source_chain_id: u64,
+    pub zeta_tx_hash: [u8; 32],
+}
+
+/// Emitted when a wallet earns points for a completed bridge round-trip
+#[event]
+pub struct PointsCredited {
+    pub wallet: Pubkey,
+    pub token_id: [u8; 32],
+    pub points: u64,
+    pub balance: u64,
+}
+
+/// Emitted when a wallet redeems points
+#[event]
+pub struct PointsRedeemed {
+    pub wallet: Pubkey,
+    pub points: u64,
+    pub balance: u64,
+    pub redemption_hook: Pubkey,
+}
+
+/// Emitted when a chain is added to the registry
+#[event]
+pub struct ChainAdded {
+    pub chain_id: u64,
+    pub name: String,
+    pub added_at: i64,
+}
+
+/// Emitted when a chain is removed from the registry
+#[event]
+pub struct ChainRemoved {
+    pub chain_id: u64,
+    pub removed_at: i64,
+}
+
+/// Emitted when traffic to a chain is halted or resumed
+#[event]
+pub struct ChainStatusChanged {
+    pub chain_id: u64,
+    pub enabled: bool,
+    pub changed_at: i64,
+}
+
+/// Emitted when the gateway's TSS signer rotation is scheduled
+#[event]
+pub struct TssAddressRotated {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub activation_time: i64,
+    pub previous_valid_until: i64,
+}
+
+/// Emitted when a watcher freezes an asset or the authority clears it
+#[event]
+pub struct AssetFreezeChanged {
+    pub mint: Pubkey,
+    pub frozen: bool,
+    pub changed_by: Pubkey,
+    pub changed_at: i64,
+}
+
+/// Emitted when the watcher disputes a pending incoming delivery or the authority clears it
+#[event]
+pub struct DeliveryDisputeChanged {
+    pub mint: Pubkey,
+    pub disputed: bool,
+    pub changed_by: Pubkey,
+    pub changed_at: i64,
+}
+
+/// Emitted when a fraud proof cancels a pending incoming delivery
+#[event]
+pub struct FraudProofAccepted {
+    pub mint: Pubkey,
+    pub relayer: Pubkey,
+    pub challenger: Pubkey,
+    pub zeta_tx_hash: [u8; 32],
+    pub fraud_count: u32,
+}
+
+/// Emitted when an NFT is locked by its owner or unlocked by the owner or authority
+#[event]
+pub struct NFTLockChanged {
+    pub mint: Pubkey,
+    pub locked: bool,
+    pub changed_by: Pubkey,
+    pub changed_at: i64,
+}
+
+/// Emitted when an owner makes an NFT's metadata permanently immutable
+#[event]
+pub struct MetadataLocked {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub locked_at: i64,
+}
+
+/// Emitted when a collection is registered
+#[event]
+pub struct CollectionRegistered {
+    pub collection: Pubkey,
+    pub collection_id: u64,
+    pub authority: Pubkey,
+    pub max_supply: u64,
+    pub registered_at: i64,
+}
+
+/// Emitted when an owner approves an address to move one NFT, or revokes it
+#[event]
+pub struct Approval {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub approved: Pubkey,
+}
+
+/// Emitted when an owner grants or revokes an operator for all of their NFTs
+#[event]
+pub struct ApprovalForAll {
+    pub owner: Pubkey,
+    pub operator: Pubkey,
+    pub approved: bool,
+}
+
+/// Emitted when an owner delegates an NFT's token account to the program, or revokes the delegation
+#[event]
+pub struct ProgramDelegation {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub enabled: bool,
+}
+
+/// Emitted when an NFT is transferred locally, carrying the sender's memo
+#[event]
+pub struct NFTTransferred {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub memo: String,
+    pub transferred_at: i64,
+}
+
+/// Emitted when an outbound cross-chain transfer is initiated
+#[event]
+pub struct OutboundTransferInitiated {
+    pub nft_mint: Pubkey,
+    pub token_id: [u8; 32],
+    pub sender: Pubkey,
+    pub target_chain_id: u64,
+    pub recipient: Vec<u8>,
+    pub memo: String,
+    pub initiated_at: i64,
+}
+
+/// Emitted when an NFT is sold through `sell_nft`
+#[event]
+pub struct NFTSold {
+    pub mint: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub royalty: u64,
+    pub royalty_recipient: Pubkey,
+    pub sold_at: i64,
+}
+
+/// Emitted when the holder on another chain retires an NFT's Solana token
+#[event]
+pub struct NFTBurnedRemotely {
+    pub mint: Pubkey,
+    pub token_id: [u8; 32],
+    pub source_chain_id: u64,
+    pub owner: Vec<u8>,
+    pub zeta_tx_hash: [u8; 32],
+    pub burned_at: i64,
+}
+
+/// Emitted after each `process_incoming_batch` call
+#[event]
+pub struct IncomingBatchProcessed {
+    pub batch: Pubkey,
+    pub source_chain_id: u64,
+    pub zeta_tx_hash: [u8; 32],
+    pub processed_items: u16,
+    pub total_items: u16,
+    pub processed_at: i64,
+}
+
+/// Emitted when a recipient claims an incoming NFT held for them in escrow
+#[event]
+pub struct IncomingNFTClaimed {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub token_id: [u8; 32],
+    pub source_chain_id: u64,
+    pub claimed_at: i64,
+}
+
+/// Emitted when the authority sweeps an expired, unclaimed incoming NFT to a recovery address
+#[event]
+pub struct UnclaimedNFTSwept {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub recovery: Pubkey,
+    pub token_id: [u8; 32],
+    pub swept_at: i64,
+}
+
+/// Emitted for every hop appended to a token ID's provenance, including hops past the stored ones
+#[event]
+pub struct ProvenanceRecorded {
+    pub token_id: [u8; 32],
+    pub chain_id: u64,
+    pub outbound: bool,
+    pub tx_hash: [u8; 32],
+    pub total_hops: u32,
+    pub recorded_at: i64,
+}
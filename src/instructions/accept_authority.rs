@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::ProgramState,
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        constraint = program_state.pending_authority != Pubkey::default() @ UniversalNFTError::InvalidPendingAuthority,
+        constraint = program_state.pending_authority == new_authority.key() @ UniversalNFTError::InvalidPendingAuthority
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub new_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    let previous_authority = program_state.authority;
+    program_state.authority = ctx.accounts.new_authority.key();
+    program_state.pending_authority = Pubkey::default();
+    program_state.authority_program = Pubkey::default(); // A multisig authority re-registers its program
+    
+    msg!("Authority rotation accepted");
+    msg!("Previous authority: {}", previous_authority);
+    msg!("New authority: {}", program_state.authority);
+    
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, AllowlistEntry},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddToAllowlist<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = AllowlistEntry::LEN,
+        seeds = [ALLOWLIST_SEED, wallet.as_ref()],
+        bump
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<AddToAllowlist>,
+    wallet: Pubkey,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let clock = Clock::get()?;
+    
+    let allowlist_entry = &mut ctx.accounts.allowlist_entry;
+    allowlist_entry.wallet = wallet;
+    allowlist_entry.added_at = clock.unix_timestamp;
+    allowlist_entry.bump = ctx.bumps.allowlist_entry;
+    allowlist_entry.account_version = ACCOUNT_VERSION;
+    
+    msg!("Wallet added to mint allowlist");
+    msg!("Wallet: {}", wallet);
+    
+    Ok(())
+}
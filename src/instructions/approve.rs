@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Approve, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    state::{NFTMetadata, TokenApproval},
+    errors::UniversalNFTError,
+    events::Approval,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct ApproveNFT<'info> {
+    #[account(
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump,
+        has_one = owner @ UniversalNFTError::NotNFTOwner
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        constraint = nft_mint.key() == nft_metadata.mint @ UniversalNFTError::MintMismatch
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+        constraint = owner_token_account.amount == 1 @ UniversalNFTError::TokenNotHeld,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TokenApproval::LEN,
+        seeds = [TOKEN_APPROVAL_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub token_approval: Account<'info, TokenApproval>,
+    
+    /// CHECK: PDA the owner's token account is delegated to, so the approved address can move the NFT through the program
+    #[account(
+        seeds = [TRANSFER_AUTHORITY_SEED],
+        bump
+    )]
+    pub transfer_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub owner: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ApproveNFT>,
+    approved: Pubkey,
+) -> Result<()> {
+    ctx.accounts.nft_metadata.require_unlocked()?;
+    
+    // SPL delegation lets the program move the token for the approved address; it lapses when the token leaves the account.
+    // Clearing the approval leaves the delegation in place for operators; set_program_delegation revokes it
+    if approved != Pubkey::default() {
+        token_interface::approve(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Approve {
+                    to: ctx.accounts.owner_token_account.to_account_info(),
+                    delegate: ctx.accounts.transfer_authority.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+    }
+    
+    let token_approval = &mut ctx.accounts.token_approval;
+    token_approval.mint = ctx.accounts.nft_mint.key();
+    token_approval.owner = ctx.accounts.owner.key();
+    token_approval.approved = approved;
+    token_approval.bump = ctx.bumps.token_approval;
+    token_approval.account_version = ACCOUNT_VERSION;
+    
+    emit!(Approval {
+        mint: token_approval.mint,
+        owner: token_approval.owner,
+        approved,
+    });
+    
+    msg!("NFT approval set");
+    msg!("NFT: {}", token_approval.mint);
+    msg!("Owner: {}", token_approval.owner);
+    msg!("Approved: {}", approved);
+    
+    Ok(())
+}
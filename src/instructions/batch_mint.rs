@@ -0,0 +1,323 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken, Create},
+    token::{self, spl_token, InitializeMint2, MintTo, Token},
+    metadata::{
+        create_master_edition_v3,
+        create_metadata_accounts_v3,
+        CreateMasterEditionV3,
+        CreateMetadataAccountsV3,
+        Metadata,
+    },
+};
+
+use crate::{
+    state::{ProgramState, PauseConfig, CollectionConfig, NFTMetadata, NFTOrigin, BatchMintItem},
+    errors::UniversalNFTError,
+    metaplex::{validate_display_data, build_data_v2},
+    codec::derive_token_id,
+    constants::*,
+    seeds::*,
+};
+
+/// Remaining accounts per item: mint, authority token account, NFT metadata, NFT origin,
+/// Metaplex metadata, master edition
+const ACCOUNTS_PER_ITEM: usize = 6;
+
+#[derive(Accounts)]
+pub struct BatchMint<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    /// CHECK: PDA set as the Metaplex update authority, so the program can keep on-chain metadata in sync
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub update_authority: UncheckedAccount<'info>,
+    
+    /// Pays for every account and is mint authority and initial holder of every NFT
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, BatchMint<'info>>,
+    items: Vec<BatchMintItem>,
+    token_id_slot: u64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_MINT)?;
+    
+    if items.is_empty() || items.len() > MAX_BATCH_MINT_SIZE || ctx.remaining_accounts.len() != items.len() * ACCOUNTS_PER_ITEM {
+        return err!(UniversalNFTError::InvalidBatchAccounts);
+    }
+    
+    // The whole batch must fit the global supply
+    let program_state = &ctx.accounts.program_state;
+    let minted_after = program_state.total_minted.checked_add(items.len() as u64).ok_or(error!(UniversalNFTError::SupplyOverflow))?;
+    if minted_after > program_state.max_supply {
+        return err!(UniversalNFTError::MaxSupplyExceeded);
+    }
+    
+    let clock = Clock::get()?;
+    
+    // Slow-drip drops release supply on a schedule
+    if let Some(cap) = ctx.accounts.collection_config.emission_cap(clock.unix_timestamp) {
+        if minted_after > cap {
+            return err!(UniversalNFTError::EmissionLimitReached);
+        }
+    }
+    
+    // The slot must be recent so token IDs track the block the NFTs were minted in
+    if token_id_slot > clock.slot || clock.slot - token_id_slot > MAX_TOKEN_ID_SLOT_AGE {
+        return err!(UniversalNFTError::InvalidTokenIdSlot);
+    }
+    
+    let update_authority_bump = ctx.bumps.update_authority;
+    let update_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[update_authority_bump]];
+    let update_authority_signer = &[update_authority_seeds];
+    
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let token_metadata_program = ctx.accounts.token_metadata_program.to_account_info();
+    let update_authority = ctx.accounts.update_authority.to_account_info();
+    let rent = ctx.accounts.rent.to_account_info();
+    let max_uri_length = ctx.accounts.collection_config.max_uri_length();
+    let mut next_token_id = ctx.accounts.program_state.next_token_id;
+    
+    for (item, accounts) in items.iter().zip(ctx.remaining_accounts.chunks(ACCOUNTS_PER_ITEM)) {
+        let [mint, token_account, nft_metadata_info, nft_origin_info, metadata, master_edition] = accounts else {
+            return err!(UniversalNFTError::InvalidBatchAccounts);
+        };
+        
+        // Validate the item's metadata
+        ctx.accounts.collection_config.require_uri_length(&item.metadata_uri)?;
+        ctx.accounts.collection_config.require_allowed_uri(&item.metadata_uri)?;
+        validate_display_data(&item.name, "", 0, &[])?;
+        
+        // Each mint is a fresh keypair signing the transaction
+        if !mint.is_signer {
+            return err!(UniversalNFTError::InvalidBatchAccounts);
+        }
+        let token_id = derive_token_id(&mint.key(), token_id_slot, next_token_id);
+        let (nft_metadata_key, nft_metadata_bump) = find_nft_metadata(&mint.key());
+        let (nft_origin_key, nft_origin_bump) = find_nft_origin(&token_id);
+        if nft_metadata_info.key() != nft_metadata_key || nft_origin_info.key() != nft_origin_key {
+            return err!(UniversalNFTError::InvalidBatchAccounts);
+        }
+        
+        // Create the mint and the authority's token account, then mint the single token
+        system_program::create_account(
+            CpiContext::new(
+                system_program.clone(),
+                CreateAccount {
+                    from: authority.clone(),
+                    to: mint.clone(),
+                },
+            ),
+            Rent::get()?.minimum_balance(spl_token::state::Mint::LEN),
+            spl_token::state::Mint::LEN as u64,
+            &token_program.key(),
+        )?;
+        token::initialize_mint2(
+            CpiContext::new(token_program.clone(), InitializeMint2 { mint: mint.clone() }),
+            SOLANA_DECIMALS,
+            &authority.key(),
+            Some(&authority.key()),
+        )?;
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: authority.clone(),
+                associated_token: token_account.clone(),
+                authority: authority.clone(),
+                mint: mint.clone(),
+                system_program: system_program.clone(),
+                token_program: token_program.clone(),
+            },
+        ))?;
+        token::mint_to(
+            CpiContext::new(
+                token_program.clone(),
+                MintTo {
+                    mint: mint.clone(),
+                    to: token_account.clone(),
+                    authority: authority.clone(),
+                },
+            ),
+            1,
+        )?;
+        
+        // Create the Metaplex metadata and master edition so wallets recognize the token as an NFT
+        let program_state = &ctx.accounts.program_state;
+        let data_v2 = build_data_v2(
+            program_state.name_or_default(&item.name),
+            program_state.symbol_or_default(""),
+            item.metadata_uri.clone(),
+            0,
+            &[],
+            &update_authority.key(),
+            None,
+        );
+        create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                token_metadata_program.clone(),
+                CreateMetadataAccountsV3 {
+                    metadata: metadata.clone(),
+                    mint: mint.clone(),
+                    mint_authority: authority.clone(),
+                    payer: authority.clone(),
+                    update_authority: update_authority.clone(),
+                    system_program: system_program.clone(),
+                    rent: rent.clone(),
+                },
+                update_authority_signer,
+            ),
+            data_v2,
+            true, // is_mutable
+            true, // update_authority_is_signer
+            None,
+        )?;
+        
+        // Max supply 0 makes this a one-of-one; mint and freeze authority move to the edition PDA
+        create_master_edition_v3(
+            CpiContext::new_with_signer(
+                token_metadata_program.clone(),
+                CreateMasterEditionV3 {
+                    edition: master_edition.clone(),
+                    mint: mint.clone(),
+                    update_authority: update_authority.clone(),
+                    mint_authority: authority.clone(),
+                    payer: authority.clone(),
+                    metadata: metadata.clone(),
+                    token_program: token_program.clone(),
+                    system_program: system_program.clone(),
+                    rent: rent.clone(),
+                },
+                update_authority_signer,
+            ),
+            Some(0),
+        )?;
+        
+        // Create the program's NFT metadata and origin records
+        let nft_metadata = NFTMetadata {
+            mint: mint.key(),
+            owner: authority.key(),
+            metadata_uri: item.metadata_uri.clone(),
+            zeta_chain_id: ZETA_CHAIN_ID_SOLANA,
+            cross_chain_data_hash: [0u8; 32], // No payload is attached to batch mints
+            token_id,
+            locked: false,
+            owner_locked: false,
+            created_at: clock.unix_timestamp,
+            updated_at: clock.unix_timestamp,
+            bump: nft_metadata_bump,
+            account_version: ACCOUNT_VERSION,
+            collection: Pubkey::default(),
+            metadata_immutable: false,
+            metadata_hash: [0u8; 32],
+            royalty_basis_points: 0,
+            royalty_recipient: Pubkey::default(),
+        };
+        create_record(
+            nft_metadata_info,
+            &authority,
+            &system_program,
+            NFTMetadata::space(max_uri_length),
+            &[NFT_METADATA_SEED, mint.key().as_ref(), &[nft_metadata_bump]],
+            &nft_metadata,
+        )?;
+        
+        let nft_origin = NFTOrigin {
+            token_id,
+            original_mint: mint.key(),
+            original_metadata_uri: item.metadata_uri.clone(),
+            source_chain_id: ZETA_CHAIN_ID_SOLANA,
+            created_at: clock.unix_timestamp,
+            bump: nft_origin_bump,
+            account_version: ACCOUNT_VERSION,
+        };
+        create_record(
+            nft_origin_info,
+            &authority,
+            &system_program,
+            NFTOrigin::space(max_uri_length),
+            &[TOKEN_ID_SEED, token_id.as_ref(), &[nft_origin_bump]],
+            &nft_origin,
+        )?;
+        
+        next_token_id = next_token_id.checked_add(1).ok_or(error!(UniversalNFTError::TokenIdOverflow))?;
+        msg!("Minted {} (token ID {:?})", mint.key(), token_id);
+    }
+    
+    // Update program state
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_minted = minted_after;
+    program_state.next_token_id = next_token_id;
+    
+    msg!("Batch minted {} NFTs", items.len());
+    msg!("Total minted: {}", program_state.total_minted);
+    msg!("Next token ID: {}", program_state.next_token_id);
+    
+    Ok(())
+}
+
+/// Create a program-owned PDA and write `value` into it, as `init` would
+pub fn create_record<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+    value: &T,
+) -> Result<()> {
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: account.clone(),
+            },
+            &[seeds],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    )?;
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data[..];
+    value.try_serialize(&mut writer)?;
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{keccak, secp256k1_recover::secp256k1_recover};
+
+use crate::{
+    state::{ZetaChainGatewayState, ChainConfig, IdentityBinding},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64, remote_address: [u8; 20])]
+pub struct BindIdentity<'info> {
+    #[account(
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    #[account(
+        seeds = [CHAIN_CONFIG_SEED, &chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = IdentityBinding::LEN,
+        seeds = [IDENTITY_SEED, remote_address.as_ref()],
+        bump
+    )]
+    pub identity_binding: Account<'info, IdentityBinding>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub wallet: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<BindIdentity>,
+    chain_id: u64,
+    remote_address: [u8; 20],
+    signature: [u8; 64],
+    recovery_id: u8,
+) -> Result<()> {
+    // Validate chain ID
+    if !ctx.accounts.gateway_state.is_chain_supported(ctx.accounts.chain_config.chain_index) {
+        return err!(UniversalNFTError::ChainDisabled);
+    }
+    if chain_id == ZETA_CHAIN_ID_SOLANA {
+        return err!(UniversalNFTError::InvalidZetaChainID);
+    }
+    
+    // The remote key must have signed over the wallet and chain it is binding to
+    let digest = identity_binding_digest(&ctx.accounts.wallet.key(), chain_id);
+    let recovered = secp256k1_recover(&digest, recovery_id, &signature)
+        .map_err(|_| error!(UniversalNFTError::IdentityVerificationFailed))?;
+    let recovered_hash = keccak::hash(&recovered.to_bytes()).to_bytes();
+    if recovered_hash[32 - EVM_ADDRESS_LENGTH..] != remote_address {
+        return err!(UniversalNFTError::IdentityVerificationFailed);
+    }
+    
+    let clock = Clock::get()?;
+    
+    let identity_binding = &mut ctx.accounts.identity_binding;
+    identity_binding.wallet = ctx.accounts.wallet.key();
+    identity_binding.remote_address = remote_address;
+    identity_binding.chain_id = chain_id;
+    identity_binding.bound_at = clock.unix_timestamp;
+    identity_binding.bump = ctx.bumps.identity_binding;
+    identity_binding.account_version = ACCOUNT_VERSION;
+    
+    msg!("Identity bound successfully");
+    msg!("Wallet: {}", ctx.accounts.wallet.key());
+    msg!("Remote address: {:?}", remote_address);
+    msg!("Chain ID: {}", chain_id);
+    
+    Ok(())
+}
+
+/// Digest signed by the remote key: keccak(domain || wallet || chain_id)
+pub fn identity_binding_digest(wallet: &Pubkey, chain_id: u64) -> [u8; 32] {
+    keccak::hashv(&[
+        IDENTITY_BINDING_DOMAIN,
+        wallet.as_ref(),
+        &chain_id.to_le_bytes(),
+    ])
+    .to_bytes()
+}
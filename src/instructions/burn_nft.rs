@@ -1,54 +1,96 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::{Mint, Token, TokenAccount, Burn},
+    token_interface::{Mint, TokenAccount, TokenInterface, Burn},
 };
 
 use crate::{
-    state::{NFTMetadata, ProgramState},
+    state::{ProgramState, PauseConfig, NFTMetadata, NFTOrigin, TokenBalance},
     errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
 };
 
 #[derive(Accounts)]
 pub struct BurnNFT<'info> {
     #[account(
         mut,
-        seeds = [b"program_state"],
+        seeds = [PROGRAM_STATE_SEED],
         bump = program_state.bump
     )]
     pub program_state: Account<'info, ProgramState>,
     
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    /// Closed to the owner once the last unit of the mint is burned
     #[account(
         mut,
-        seeds = [b"nft_metadata", nft_mint.key().as_ref()],
-        bump = nft_metadata.bump,
-        has_one = owner
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump
     )]
     pub nft_metadata: Account<'info, NFTMetadata>,
     
+    /// Closed to the owner once the last unit of the mint is burned
+    #[account(
+        mut,
+        seeds = [TOKEN_ID_SEED, nft_metadata.token_id.as_ref()],
+        bump = nft_origin.bump
+    )]
+    pub nft_origin: Account<'info, NFTOrigin>,
+    
     #[account(
         mut,
-        constraint = nft_mint.key() == nft_metadata.mint
+        constraint = nft_mint.key() == nft_metadata.mint @ UniversalNFTError::MintMismatch
     )]
-    pub nft_mint: Account<'info, Mint>,
+    pub nft_mint: InterfaceAccount<'info, Mint>,
     
     #[account(
         mut,
         associated_token::mint = nft_mint,
         associated_token::authority = owner,
+        associated_token::token_program = token_program,
+        constraint = owner_token_account.amount > 0 @ UniversalNFTError::TokenNotHeld,
     )]
-    pub owner_token_account: Account<'info, TokenAccount>,
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Solana balance of a semi-fungible token, debited with the burned units; required for supplies above one
+    #[account(
+        mut,
+        seeds = [TOKEN_BALANCE_SEED, nft_metadata.token_id.as_ref(), &ZETA_CHAIN_ID_SOLANA.to_le_bytes()],
+        bump = token_balance.bump
+    )]
+    pub token_balance: Option<Account<'info, TokenBalance>>,
     
     #[account(mut)]
     pub owner: Signer<'info>,
     
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn handler(
     ctx: Context<BurnNFT>,
 ) -> Result<()> {
-    // Burn the NFT
+    ctx.accounts.pause_config.require_active(PAUSE_BURN)?;
+    ctx.accounts.nft_metadata.require_unlocked()?;
+    
+    // One-of-ones are burned by their owner of record; semi-fungible units by whoever holds them
+    let supply = ctx.accounts.nft_mint.supply;
+    if supply == 1 && ctx.accounts.nft_metadata.owner != ctx.accounts.owner.key() {
+        return err!(UniversalNFTError::NotNFTOwner);
+    }
+    
+    // Burn every unit the owner holds
+    let amount = ctx.accounts.owner_token_account.amount;
+    match ctx.accounts.token_balance.as_mut() {
+        Some(token_balance) => token_balance.debit(amount)?,
+        None if supply > 1 => return err!(UniversalNFTError::MissingTokenBalance),
+        None => {}
+    }
+    
     let cpi_accounts = Burn {
         mint: ctx.accounts.nft_mint.to_account_info(),
         from: ctx.accounts.owner_token_account.to_account_info(),
@@ -58,16 +100,26 @@ pub fn handler(
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
     
-    anchor_spl::token::burn(cpi_ctx, 1)?;
+    anchor_spl::token_interface::burn(cpi_ctx, amount)?;
     
-    // Update program state
-    let program_state = &mut ctx.accounts.program_state;
-    program_state.total_minted -= 1;
+    // The token ID leaves the supply, and its records are closed to the owner, with its last unit;
+    // units still held elsewhere keep them open
+    let remaining_supply = supply.checked_sub(amount).ok_or(error!(UniversalNFTError::SupplyUnderflow))?;
+    if remaining_supply == 0 {
+        let program_state = &mut ctx.accounts.program_state;
+        program_state.total_minted = program_state.total_minted.checked_sub(1).ok_or(error!(UniversalNFTError::SupplyUnderflow))?;
+        
+        let owner = ctx.accounts.owner.to_account_info();
+        ctx.accounts.nft_metadata.close(owner.clone())?;
+        ctx.accounts.nft_origin.close(owner)?;
+    }
     
     msg!("NFT burned successfully");
     msg!("NFT: {}", ctx.accounts.nft_mint.key());
     msg!("Owner: {}", ctx.accounts.owner.key());
-    msg!("Total minted: {}", program_state.total_minted);
+    msg!("Amount: {}", amount);
+    msg!("Remaining supply: {}", remaining_supply);
+    msg!("Total minted: {}", ctx.accounts.program_state.total_minted);
     
     Ok(())
 }
@@ -0,0 +1,304 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount, MintTo},
+    metadata::{
+        create_master_edition_v3,
+        create_metadata_accounts_v3,
+        CreateMasterEditionV3,
+        CreateMetadataAccountsV3,
+        Metadata,
+    },
+};
+
+use crate::{
+    state::{ProgramState, PauseConfig, CollectionConfig, NFTMetadata, NFTOrigin, Airdrop, AirdropClaim},
+    errors::UniversalNFTError,
+    metaplex::build_data_v2,
+    codec::derive_token_id,
+    merkle,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(index: u32, metadata_uri: String, proof: Vec<[u8; 32]>, token_id_slot: u64)]
+pub struct ClaimAirdrop<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    #[account(
+        mut,
+        seeds = [AIRDROP_SEED, airdrop.merkle_root.as_ref()],
+        bump = airdrop.bump
+    )]
+    pub airdrop: Account<'info, Airdrop>,
+    
+    /// Created on claim, so each leaf can only be claimed once
+    #[account(
+        init,
+        payer = payer,
+        space = AirdropClaim::LEN,
+        seeds = [AIRDROP_CLAIM_SEED, airdrop.key().as_ref(), &index.to_le_bytes()],
+        bump
+    )]
+    pub airdrop_claim: Account<'info, AirdropClaim>,
+    
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = SOLANA_DECIMALS,
+        mint::authority = claimer,
+        mint::freeze_authority = claimer,
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = claimer,
+    )]
+    pub claimer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = NFTMetadata::space(collection_config.max_uri_length()),
+        seeds = [NFT_METADATA_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = NFTOrigin::space(collection_config.max_uri_length()),
+        seeds = [TOKEN_ID_SEED, derive_token_id(&mint.key(), token_id_slot, program_state.next_token_id).as_ref()],
+        bump
+    )]
+    pub nft_origin: Account<'info, NFTOrigin>,
+    
+    /// CHECK: Metaplex metadata PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Metaplex master edition PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), mint.key().as_ref(), METAPLEX_EDITION_SEED],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA set as the Metaplex update authority, so the program can keep on-chain metadata in sync
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub update_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Wallet named in the leaf; mint authority of the new mint and receives the NFT
+    pub claimer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<ClaimAirdrop>,
+    index: u32,
+    metadata_uri: String,
+    proof: Vec<[u8; 32]>,
+    token_id_slot: u64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_MINT)?;
+    
+    // The leaf binds the index, the claimer and the URI they receive
+    if proof.len() > MAX_MERKLE_PROOF_DEPTH {
+        return err!(UniversalNFTError::InvalidProofData);
+    }
+    let leaf = airdrop_leaf(index, &ctx.accounts.claimer.key(), &metadata_uri);
+    if !merkle::verify(&proof, &ctx.accounts.airdrop.merkle_root, &leaf) {
+        return err!(UniversalNFTError::InvalidMerkleProof);
+    }
+    ctx.accounts.airdrop.record_claim()?;
+    
+    ctx.accounts.collection_config.require_uri_length(&metadata_uri)?;
+    ctx.accounts.collection_config.require_allowed_uri(&metadata_uri)?;
+    
+    // Claims mint from the global supply
+    if ctx.accounts.program_state.total_minted >= ctx.accounts.program_state.max_supply {
+        return err!(UniversalNFTError::MaxSupplyExceeded);
+    }
+    
+    let clock = Clock::get()?;
+    
+    // Slow-drip drops release supply on a schedule
+    if let Some(cap) = ctx.accounts.collection_config.emission_cap(clock.unix_timestamp) {
+        if ctx.accounts.program_state.total_minted >= cap {
+            return err!(UniversalNFTError::EmissionLimitReached);
+        }
+    }
+    
+    // The slot must be recent so token IDs track the block the NFT was minted in
+    if token_id_slot > clock.slot || clock.slot - token_id_slot > MAX_TOKEN_ID_SLOT_AGE {
+        return err!(UniversalNFTError::InvalidTokenIdSlot);
+    }
+    
+    // Mint the NFT to the claimer
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.claimer_token_account.to_account_info(),
+        authority: ctx.accounts.claimer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    anchor_spl::token::mint_to(cpi_ctx, 1)?;
+    
+    let update_authority_bump = ctx.bumps.update_authority;
+    let update_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[update_authority_bump]];
+    let update_authority_signer = &[update_authority_seeds];
+    
+    let program_state = &ctx.accounts.program_state;
+    let data_v2 = build_data_v2(
+        program_state.name_or_default(""),
+        program_state.symbol_or_default(""),
+        metadata_uri.clone(),
+        0,
+        &[],
+        &ctx.accounts.update_authority.key(),
+        None,
+    );
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                mint_authority: ctx.accounts.claimer.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                update_authority: ctx.accounts.update_authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            update_authority_signer,
+        ),
+        data_v2,
+        true, // is_mutable
+        true, // update_authority_is_signer
+        None,
+    )?;
+    
+    // Max supply 0 makes this a one-of-one; mint and freeze authority move to the edition PDA
+    create_master_edition_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMasterEditionV3 {
+                edition: ctx.accounts.master_edition.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                update_authority: ctx.accounts.update_authority.to_account_info(),
+                mint_authority: ctx.accounts.claimer.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            update_authority_signer,
+        ),
+        Some(0),
+    )?;
+    
+    let token_id = derive_token_id(&ctx.accounts.mint.key(), token_id_slot, program_state.next_token_id);
+    
+    // Initialize NFT metadata
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.mint = ctx.accounts.mint.key();
+    nft_metadata.owner = ctx.accounts.claimer.key();
+    nft_metadata.metadata_uri = metadata_uri.clone();
+    nft_metadata.zeta_chain_id = ZETA_CHAIN_ID_SOLANA;
+    nft_metadata.cross_chain_data_hash = [0u8; 32];
+    nft_metadata.token_id = token_id;
+    nft_metadata.locked = false;
+    nft_metadata.owner_locked = false;
+    nft_metadata.created_at = clock.unix_timestamp;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    nft_metadata.bump = ctx.bumps.nft_metadata;
+    nft_metadata.account_version = ACCOUNT_VERSION;
+    nft_metadata.collection = Pubkey::default();
+    nft_metadata.metadata_immutable = false;
+    nft_metadata.metadata_hash = [0u8; 32];
+    nft_metadata.royalty_basis_points = 0;
+    nft_metadata.royalty_recipient = Pubkey::default();
+    
+    // Initialize NFT origin tracking
+    let nft_origin = &mut ctx.accounts.nft_origin;
+    nft_origin.token_id = token_id;
+    nft_origin.original_mint = ctx.accounts.mint.key();
+    nft_origin.original_metadata_uri = metadata_uri;
+    nft_origin.source_chain_id = ZETA_CHAIN_ID_SOLANA;
+    nft_origin.created_at = clock.unix_timestamp;
+    nft_origin.bump = ctx.bumps.nft_origin;
+    nft_origin.account_version = ACCOUNT_VERSION;
+    
+    // Record the claim
+    let airdrop_claim = &mut ctx.accounts.airdrop_claim;
+    airdrop_claim.airdrop = ctx.accounts.airdrop.key();
+    airdrop_claim.index = index;
+    airdrop_claim.claimer = ctx.accounts.claimer.key();
+    airdrop_claim.mint = ctx.accounts.mint.key();
+    airdrop_claim.claimed_at = clock.unix_timestamp;
+    airdrop_claim.bump = ctx.bumps.airdrop_claim;
+    airdrop_claim.account_version = ACCOUNT_VERSION;
+    
+    // Update program state
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_minted = program_state.total_minted.checked_add(1).ok_or(error!(UniversalNFTError::SupplyOverflow))?;
+    program_state.next_token_id = program_state.next_token_id.checked_add(1).ok_or(error!(UniversalNFTError::TokenIdOverflow))?;
+    
+    msg!("Airdrop claimed");
+    msg!("Airdrop: {}", ctx.accounts.airdrop.key());
+    msg!("Leaf index: {}", index);
+    msg!("Claimer: {}", ctx.accounts.claimer.key());
+    msg!("Mint address: {}", ctx.accounts.mint.key());
+    msg!("Token ID: {:?}", token_id);
+    
+    Ok(())
+}
+
+/// Airdrop tree leaf: keccak(keccak(index as u32 LE || wallet || metadata_uri)), double-hashed so a
+/// leaf can never be read as an inner node
+pub fn airdrop_leaf(index: u32, wallet: &Pubkey, metadata_uri: &str) -> [u8; 32] {
+    let inner = keccak::hashv(&[&index.to_le_bytes(), wallet.as_ref(), metadata_uri.as_bytes()]).to_bytes();
+    keccak::hash(&inner).to_bytes()
+}
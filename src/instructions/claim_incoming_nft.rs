@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    state::{ProgramState, PauseConfig, FrozenAsset, ClaimableNFT, NFTMetadata, TransferHistory, TransferRecord, TransferKind},
+    errors::UniversalNFTError,
+    events::IncomingNFTClaimed,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct ClaimIncomingNFT<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        mut,
+        seeds = [CLAIMABLE_NFT_SEED, nft_mint.key().as_ref()],
+        bump = claimable_nft.bump,
+        has_one = recipient @ UniversalNFTError::RecipientMismatch,
+        close = relayer
+    )]
+    pub claimable_nft: Account<'info, ClaimableNFT>,
+    
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    /// CHECK: FrozenAsset PDA for the mint; claims are rejected while it exists
+    #[account(
+        seeds = [FROZEN_ASSET_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub frozen_asset: UncheckedAccount<'info>,
+    
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: PDA that holds incoming NFTs until they are claimed
+    #[account(
+        seeds = [ESCROW_AUTHORITY_SEED],
+        bump
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = escrow_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Latest ownership changes of the NFT
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TransferHistory::LEN,
+        seeds = [TRANSFER_HISTORY_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_history: Account<'info, TransferHistory>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// The recipient recorded at delivery must sign the claim
+    pub recipient: Signer<'info>,
+    
+    /// CHECK: Receives the claim record rent; validated against the recorded relayer
+    #[account(
+        mut,
+        constraint = relayer.key() == claimable_nft.relayer @ UniversalNFTError::Unauthorized
+    )]
+    pub relayer: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<ClaimIncomingNFT>) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_INBOUND)?;
+    FrozenAsset::require_not_frozen(&ctx.accounts.frozen_asset)?;
+    
+    let clock = Clock::get()?;
+    
+    // Release the NFT from escrow to the recipient
+    let escrow_bump = ctx.bumps.escrow_authority;
+    let escrow_seeds: &[&[u8]] = &[ESCROW_AUTHORITY_SEED, &[escrow_bump]];
+    let escrow_signer = &[escrow_seeds];
+    
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.escrow_token_account.to_account_info(),
+        mint: ctx.accounts.nft_mint.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: ctx.accounts.escrow_authority.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, escrow_signer);
+    
+    anchor_spl::token_interface::transfer_checked(cpi_ctx, 1, SOLANA_DECIMALS)?;
+    
+    // Record the ownership change for activity feeds
+    let transfer_history = &mut ctx.accounts.transfer_history;
+    transfer_history.mint = ctx.accounts.nft_mint.key();
+    transfer_history.bump = ctx.bumps.transfer_history;
+    transfer_history.account_version = ACCOUNT_VERSION;
+    transfer_history.push(TransferRecord {
+        owner: ctx.accounts.recipient.key(),
+        timestamp: clock.unix_timestamp,
+        kind: TransferKind::CrossChain,
+    });
+    
+    // Hand ownership to the recipient
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.owner = ctx.accounts.recipient.key();
+    nft_metadata.locked = false;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    
+    let claimable_nft = &ctx.accounts.claimable_nft;
+    emit!(IncomingNFTClaimed {
+        mint: claimable_nft.mint,
+        recipient: claimable_nft.recipient,
+        token_id: claimable_nft.token_id,
+        source_chain_id: claimable_nft.source_chain_id,
+        claimed_at: clock.unix_timestamp,
+    });
+    
+    msg!("Incoming NFT claimed");
+    msg!("NFT: {}", claimable_nft.mint);
+    msg!("Recipient: {}", claimable_nft.recipient);
+    msg!("Token ID: {:?}", claimable_nft.token_id);
+    msg!("Source chain: {}", claimable_nft.source_chain_id);
+    
+    Ok(())
+}
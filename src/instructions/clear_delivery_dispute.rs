@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, PendingDelivery},
+    errors::UniversalNFTError,
+    events::DeliveryDisputeChanged,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct ClearDeliveryDispute<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [PENDING_DELIVERY_SEED, pending_delivery.mint.as_ref()],
+        bump = pending_delivery.bump,
+        constraint = pending_delivery.disputed @ UniversalNFTError::InvalidTransferStatus
+    )]
+    pub pending_delivery: Account<'info, PendingDelivery>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ClearDeliveryDispute>) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let clock = Clock::get()?;
+    
+    // The authority reviewed the message and found it valid; release follows the normal window
+    let pending_delivery = &mut ctx.accounts.pending_delivery;
+    pending_delivery.disputed = false;
+    
+    emit!(DeliveryDisputeChanged {
+        mint: pending_delivery.mint,
+        disputed: false,
+        changed_by: ctx.accounts.authority.key(),
+        changed_at: clock.unix_timestamp,
+    });
+    
+    msg!("Delivery dispute cleared");
+    msg!("Mint: {}", pending_delivery.mint);
+    
+    Ok(())
+}
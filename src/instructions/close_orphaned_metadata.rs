@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    state::{NFTMetadata, NFTOrigin},
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+/// Closes the records of an NFT burned before `burn_nft` closed them. Anyone may call it; the
+/// rent goes to the owner recorded at burn time.
+#[derive(Accounts)]
+pub struct CloseOrphanedMetadata<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump,
+        has_one = owner @ UniversalNFTError::NotNFTOwner
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        mut,
+        close = owner,
+        seeds = [TOKEN_ID_SEED, nft_metadata.token_id.as_ref()],
+        bump = nft_origin.bump
+    )]
+    pub nft_origin: Account<'info, NFTOrigin>,
+    
+    #[account(
+        constraint = nft_mint.key() == nft_metadata.mint @ UniversalNFTError::MintMismatch,
+        constraint = nft_mint.supply == 0 @ UniversalNFTError::NFTNotBurned
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Owner recorded in the NFT metadata; receives the rent
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseOrphanedMetadata>) -> Result<()> {
+    // Records still held by a cross-chain operation are needed to settle it
+    ctx.accounts.nft_metadata.require_unlocked()?;
+    
+    msg!("Orphaned NFT records closed");
+    msg!("NFT: {}", ctx.accounts.nft_mint.key());
+    msg!("Rent refunded to: {}", ctx.accounts.owner.key());
+    
+    Ok(())
+}
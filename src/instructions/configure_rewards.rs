@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, RewardsConfig},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct ConfigureRewards<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RewardsConfig::LEN,
+        seeds = [REWARDS_CONFIG_SEED],
+        bump
+    )]
+    pub rewards_config: Account<'info, RewardsConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureRewards>,
+    points_per_round_trip: u64,
+    redemption_hook: Pubkey,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Validate schedule
+    if points_per_round_trip > MAX_POINTS_PER_ROUND_TRIP {
+        return err!(UniversalNFTError::InvalidCrossChainData);
+    }
+    
+    // The hook cannot be this program
+    if redemption_hook == crate::ID {
+        return err!(UniversalNFTError::InvalidRedemptionHook);
+    }
+    
+    let clock = Clock::get()?;
+    
+    let rewards_config = &mut ctx.accounts.rewards_config;
+    rewards_config.points_per_round_trip = points_per_round_trip;
+    rewards_config.redemption_hook = redemption_hook;
+    rewards_config.updated_at = clock.unix_timestamp;
+    rewards_config.bump = ctx.bumps.rewards_config;
+    rewards_config.account_version = ACCOUNT_VERSION;
+    
+    msg!("Rewards configuration updated");
+    msg!("Points per round-trip: {}", points_per_round_trip);
+    msg!("Redemption hook: {}", redemption_hook);
+    
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    state::{CrossChainTransferState, ZetaChainGatewayState, TransferStatus, TokenBalance, Provenance, ProvenanceHop},
+    errors::UniversalNFTError,
+    events::OutboundTransferConfirmed,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct ConfirmOutboundTransfer<'info> {
+    #[account(
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    #[account(
+        mut,
+        seeds = [CROSS_CHAIN_TRANSFER_SEED, nft_mint.key().as_ref()],
+        bump = transfer_state.bump,
+        constraint = transfer_state.status == TransferStatus::InProgress @ UniversalNFTError::InvalidTransferStatus
+    )]
+    pub transfer_state: Account<'info, CrossChainTransferState>,
+    
+    #[account(
+        constraint = nft_mint.key() == transfer_state.nft_mint @ UniversalNFTError::MintMismatch
+    )]
+    pub nft_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = gateway_state.is_gateway_authority(&gateway_authority.key(), Clock::get()?.unix_timestamp) @ UniversalNFTError::Unauthorized
+    )]
+    pub gateway_authority: Signer<'info>,
+    
+    /// Solana balance of a semi-fungible token; required when more than one unit was sent
+    #[account(
+        mut,
+        seeds = [TOKEN_BALANCE_SEED, transfer_state.token_id.as_ref(), &ZETA_CHAIN_ID_SOLANA.to_le_bytes()],
+        bump = solana_balance.bump
+    )]
+    pub solana_balance: Option<Account<'info, TokenBalance>>,
+    
+    /// Target chain balance of a semi-fungible token, credited with the sent units
+    #[account(
+        init_if_needed,
+        payer = gateway_authority,
+        space = TokenBalance::LEN,
+        seeds = [TOKEN_BALANCE_SEED, transfer_state.token_id.as_ref(), &transfer_state.target_chain_id.to_le_bytes()],
+        bump
+    )]
+    pub target_balance: Option<Account<'info, TokenBalance>>,
+    
+    /// Chain hop history of the token ID
+    #[account(
+        init_if_needed,
+        payer = gateway_authority,
+        space = Provenance::LEN,
+        seeds = [PROVENANCE_SEED, transfer_state.token_id.as_ref()],
+        bump
+    )]
+    pub provenance: Account<'info, Provenance>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ConfirmOutboundTransfer>,
+    zeta_tx_hash: [u8; 32],
+) -> Result<()> {
+    // Gateway must be configured before it can confirm transfers
+    if !ctx.accounts.gateway_state.is_configured() {
+        return err!(UniversalNFTError::GatewayNotConfigured);
+    }
+    
+    // Confirmation must reference the ZetaChain transaction
+    if zeta_tx_hash == [0u8; 32] {
+        return err!(UniversalNFTError::ZetaChainTransactionFailed);
+    }
+    
+    let clock = Clock::get()?;
+    
+    // Release the destination gas deposit to the gateway that paid for execution
+    let gas_amount = ctx.accounts.transfer_state.gas_amount;
+    if gas_amount > 0 {
+        **ctx.accounts.transfer_state.to_account_info().try_borrow_mut_lamports()? -= gas_amount;
+        **ctx.accounts.gateway_authority.to_account_info().try_borrow_mut_lamports()? += gas_amount;
+    }
+    
+    // Semi-fungible units now live on the target chain
+    let amount = ctx.accounts.transfer_state.amount();
+    match (ctx.accounts.solana_balance.as_mut(), ctx.accounts.target_balance.as_mut()) {
+        (Some(solana_balance), Some(target_balance)) => {
+            solana_balance.debit(amount)?;
+            target_balance.token_id = ctx.accounts.transfer_state.token_id;
+            target_balance.chain_id = ctx.accounts.transfer_state.target_chain_id;
+            target_balance.bump = ctx.bumps.target_balance;
+            target_balance.account_version = ACCOUNT_VERSION;
+            target_balance.credit(amount)?;
+        }
+        _ if amount > 1 => return err!(UniversalNFTError::MissingTokenBalance),
+        _ => {}
+    }
+    
+    // Record the ZetaChain transaction and complete the transfer. The NFT stays locked in
+    // escrow while it lives on the target chain so the original mint is released on return.
+    let transfer_state = &mut ctx.accounts.transfer_state;
+    transfer_state.zeta_tx_hash = zeta_tx_hash;
+    transfer_state.status = TransferStatus::Completed;
+    transfer_state.gas_amount = 0;
+    
+    // Append the hop to the token ID's multichain history
+    let provenance_bump = ctx.bumps.provenance;
+    ctx.accounts.provenance.record(transfer_state.token_id, provenance_bump, ProvenanceHop {
+        chain_id: transfer_state.target_chain_id,
+        outbound: true,
+        tx_hash: zeta_tx_hash,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    emit!(OutboundTransferConfirmed {
+        nft_mint: transfer_state.nft_mint,
+        token_id: transfer_state.token_id,
+        sender: transfer_state.sender,
+        target_chain_id: transfer_state.target_chain_id,
+        recipient: transfer_state.recipient.clone(),
+        zeta_tx_hash,
+        memo: transfer_state.memo.clone(),
+        confirmed_at: clock.unix_timestamp,
+    });
+    
+    msg!("Outbound transfer confirmed");
+    msg!("NFT: {}", transfer_state.nft_mint);
+    msg!("Token ID: {:?}", transfer_state.token_id);
+    msg!("ZetaChain TX: {:?}", zeta_tx_hash);
+    msg!("Gas released: {} lamports", gas_amount);
+    msg!("Status: Completed");
+    
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, Airdrop},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(merkle_root: [u8; 32])]
+pub struct CreateAirdrop<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Airdrop::LEN,
+        seeds = [AIRDROP_SEED, merkle_root.as_ref()],
+        bump
+    )]
+    pub airdrop: Account<'info, Airdrop>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateAirdrop>,
+    merkle_root: [u8; 32],
+    total_count: u32,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    if merkle_root == [0u8; 32] || total_count == 0 {
+        return err!(UniversalNFTError::InvalidAirdrop);
+    }
+    
+    let airdrop = &mut ctx.accounts.airdrop;
+    airdrop.authority = ctx.accounts.authority.key();
+    airdrop.merkle_root = merkle_root;
+    airdrop.total_count = total_count;
+    airdrop.claimed_count = 0;
+    airdrop.created_at = Clock::get()?.unix_timestamp;
+    airdrop.bump = ctx.bumps.airdrop;
+    airdrop.account_version = ACCOUNT_VERSION;
+    
+    msg!("Airdrop created");
+    msg!("Airdrop: {}", airdrop.key());
+    msg!("Merkle root: {:?}", merkle_root);
+    msg!("Total count: {}", total_count);
+    
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount, MintTo},
+    metadata::{
+        create_master_edition_v3,
+        create_metadata_accounts_v3,
+        CreateMasterEditionV3,
+        CreateMetadataAccountsV3,
+        Metadata,
+        mpl_token_metadata::types::CollectionDetails,
+    },
+};
+
+use crate::{
+    state::{ProgramState, VerifiedCollection},
+    errors::UniversalNFTError,
+    metaplex::{validate_display_data, build_data_v2},
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct CreateCollection<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = VerifiedCollection::LEN,
+        seeds = [VERIFIED_COLLECTION_SEED],
+        bump
+    )]
+    pub verified_collection: Account<'info, VerifiedCollection>,
+    
+    /// CHECK: PDA used as mint and freeze authority for program-minted assets
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = SOLANA_DECIMALS,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+    )]
+    pub collection_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = collection_mint,
+        associated_token::authority = mint_authority,
+    )]
+    pub collection_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Metaplex metadata PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), collection_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Metaplex master edition PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), collection_mint.key().as_ref(), METAPLEX_EDITION_SEED],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<CreateCollection>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Validate collection display data
+    if uri.len() > MAX_METADATA_URI_LENGTH {
+        return err!(UniversalNFTError::InvalidMetadataURILength);
+    }
+    validate_display_data(&name, &symbol, 0, &[])?;
+    
+    let clock = Clock::get()?;
+    
+    let mint_authority_bump = ctx.bumps.mint_authority;
+    let mint_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[mint_authority_bump]];
+    let mint_authority_signer = &[mint_authority_seeds];
+    
+    // The collection NFT is held by the mint authority PDA so no wallet can move it
+    anchor_spl::token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                to: ctx.accounts.collection_token_account.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            mint_authority_signer,
+        ),
+        1,
+    )?;
+    
+    // Sized collection metadata, with the PDA as update authority so it can verify items
+    let data_v2 = build_data_v2(
+        ctx.accounts.program_state.name_or_default(&name),
+        ctx.accounts.program_state.symbol_or_default(&symbol),
+        uri,
+        0,
+        &[],
+        &ctx.accounts.mint_authority.key(),
+        None,
+    );
+    
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.collection_metadata.to_account_info(),
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                payer: ctx.accounts.authority.to_account_info(),
+                update_authority: ctx.accounts.mint_authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            mint_authority_signer,
+        ),
+        data_v2,
+        true, // is_mutable
+        true, // update_authority_is_signer
+        Some(CollectionDetails::V1 { size: 0 }),
+    )?;
+    
+    create_master_edition_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMasterEditionV3 {
+                edition: ctx.accounts.collection_master_edition.to_account_info(),
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                update_authority: ctx.accounts.mint_authority.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                payer: ctx.accounts.authority.to_account_info(),
+                metadata: ctx.accounts.collection_metadata.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            mint_authority_signer,
+        ),
+        Some(0),
+    )?;
+    
+    let verified_collection = &mut ctx.accounts.verified_collection;
+    verified_collection.mint = ctx.accounts.collection_mint.key();
+    verified_collection.created_at = clock.unix_timestamp;
+    verified_collection.bump = ctx.bumps.verified_collection;
+    verified_collection.account_version = ACCOUNT_VERSION;
+    
+    msg!("Verified collection created");
+    msg!("Collection mint: {}", verified_collection.mint);
+    
+    Ok(())
+}
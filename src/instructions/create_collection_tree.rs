@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use mpl_bubblegum::{
+    accounts::TreeConfig,
+    instructions::CreateTreeConfigCpiBuilder,
+    programs::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID},
+};
+
+use crate::{
+    state::{CollectionState, AssetBackend},
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct CreateCollectionTree<'info> {
+    #[account(
+        mut,
+        seeds = [COLLECTION_STATE_SEED, &collection_state.collection_id.to_le_bytes()],
+        bump = collection_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+    
+    /// CHECK: Empty concurrent merkle tree account, allocated by the client and owned by the account compression program
+    #[account(
+        mut,
+        owner = SPL_ACCOUNT_COMPRESSION_ID @ UniversalNFTError::InvalidAccountOwner
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+    
+    /// CHECK: Bubblegum tree config PDA of the merkle tree, created by Bubblegum
+    #[account(
+        mut,
+        address = TreeConfig::find_pda(&merkle_tree.key()).0
+    )]
+    pub tree_config: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA used as mint and freeze authority for program-minted assets; becomes the tree creator
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Bubblegum program
+    #[account(address = mpl_bubblegum::ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL noop program, used by Bubblegum as its log wrapper
+    #[account(address = SPL_NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL account compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateCollectionTree>,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Result<()> {
+    // Only compressed collections mint into a tree
+    if ctx.accounts.collection_state.asset_backend != AssetBackend::Compressed {
+        return err!(UniversalNFTError::WrongAssetBackend);
+    }
+    
+    let mint_authority_bump = ctx.bumps.mint_authority;
+    let mint_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[mint_authority_bump]];
+    
+    // The mint authority PDA creates the tree, so only this program can mint into it
+    CreateTreeConfigCpiBuilder::new(&ctx.accounts.bubblegum_program.to_account_info())
+        .tree_config(&ctx.accounts.tree_config.to_account_info())
+        .merkle_tree(&ctx.accounts.merkle_tree.to_account_info())
+        .payer(&ctx.accounts.authority.to_account_info())
+        .tree_creator(&ctx.accounts.mint_authority.to_account_info())
+        .log_wrapper(&ctx.accounts.log_wrapper.to_account_info())
+        .compression_program(&ctx.accounts.compression_program.to_account_info())
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .max_depth(max_depth)
+        .max_buffer_size(max_buffer_size)
+        .public(false)
+        .invoke_signed(&[mint_authority_seeds])?;
+    
+    let collection_state = &mut ctx.accounts.collection_state;
+    collection_state.merkle_tree = ctx.accounts.merkle_tree.key();
+    collection_state.updated_at = Clock::get()?.unix_timestamp;
+    
+    msg!("Collection merkle tree created");
+    msg!("Collection: {}", collection_state.collection_id);
+    msg!("Merkle tree: {}", collection_state.merkle_tree);
+    msg!("Capacity: {} NFTs", 1u64 << max_depth);
+    
+    Ok(())
+}
@@ -1,83 +1,206 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::{Mint, Token, TokenAccount, Transfer},
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{
-    state::{ProgramState, NFTMetadata, CrossChainTransferState, ZetaChainGatewayState, TransferStatus, NFTOrigin},
+    state::{ProgramState, PauseConfig, Denylist, FrozenAsset, RateLimiter, OwnerVelocity, NFTMetadata, CrossChainTransferState, ZetaChainGatewayState, ChainConfig, TransferStatus, NFTOrigin, Outbox, OutboxMessage, MessageLane, CollectionState, TransferHistory, TransferRecord, TransferKind},
     errors::UniversalNFTError,
+    events::OutboundTransferInitiated,
+    transfer_hook,
+    programmable::ProgrammableTransfer,
+    codec::{encode_nft_message, NFTMessage},
     constants::*,
+    seeds::*,
 };
 
 #[derive(Accounts)]
+#[instruction(target_chain_id: u64)]
 pub struct CrossChainTransfer<'info> {
     #[account(
         mut,
-        seeds = [b"program_state"],
+        seeds = [PROGRAM_STATE_SEED],
         bump = program_state.bump
     )]
     pub program_state: Account<'info, ProgramState>,
     
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+    
+    #[account(
+        mut,
+        seeds = [RATE_LIMITER_SEED],
+        bump = rate_limiter.bump
+    )]
+    pub rate_limiter: Account<'info, RateLimiter>,
+    
     #[account(
         mut,
-        seeds = [b"gateway_state"],
+        seeds = [GATEWAY_STATE_SEED],
         bump = gateway_state.bump
     )]
     pub gateway_state: Account<'info, ZetaChainGatewayState>,
     
+    #[account(
+        seeds = [CHAIN_CONFIG_SEED, &target_chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
     #[account(
         mut,
-        seeds = [b"nft_metadata", nft_mint.key().as_ref()],
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
         bump = nft_metadata.bump,
-        has_one = owner
+        has_one = owner @ UniversalNFTError::NotNFTOwner
     )]
     pub nft_metadata: Account<'info, NFTMetadata>,
     
     #[account(
-        seeds = [TOKEN_ID_SEED, &nft_metadata.token_id.to_le_bytes()],
+        seeds = [TOKEN_ID_SEED, nft_metadata.token_id.as_ref()],
         bump = nft_origin.bump
     )]
     pub nft_origin: Account<'info, NFTOrigin>,
     
     #[account(
         mut,
-        constraint = nft_mint.key() == nft_metadata.mint
+        constraint = nft_mint.key() == nft_metadata.mint @ UniversalNFTError::MintMismatch
     )]
-    pub nft_mint: Account<'info, Mint>,
+    pub nft_mint: InterfaceAccount<'info, Mint>,
     
     #[account(
         mut,
         associated_token::mint = nft_mint,
         associated_token::authority = owner,
+        associated_token::token_program = token_program,
+        constraint = owner_token_account.amount >= 1 @ UniversalNFTError::TokenNotHeld,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: FrozenAsset PDA for the mint; the transfer is rejected while it exists
+    #[account(
+        seeds = [FROZEN_ASSET_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub frozen_asset: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA that owns escrowed NFTs while a cross-chain transfer is pending
+    #[account(
+        seeds = [ESCROW_AUTHORITY_SEED],
+        bump
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = escrow_authority,
+        associated_token::token_program = token_program,
     )]
-    pub owner_token_account: Account<'info, TokenAccount>,
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
     
     #[account(
         init,
-        payer = owner,
+        payer = payer,
         space = CrossChainTransferState::LEN,
-        seeds = [b"cross_chain_transfer", nft_mint.key().as_ref()],
+        seeds = [CROSS_CHAIN_TRANSFER_SEED, nft_mint.key().as_ref()],
         bump
     )]
     pub transfer_state: Account<'info, CrossChainTransferState>,
     
+    #[account(
+        mut,
+        seeds = [OUTBOX_SEED],
+        bump = outbox.bump
+    )]
+    pub outbox: Account<'info, Outbox>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = OutboxMessage::LEN,
+        seeds = [OUTBOX_MESSAGE_SEED, &outbox.next_sequence.to_le_bytes()],
+        bump
+    )]
+    pub outbox_message: Account<'info, OutboxMessage>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OwnerVelocity::LEN,
+        seeds = [OWNER_VELOCITY_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub owner_velocity: Account<'info, OwnerVelocity>,
+    
+    /// Latest ownership changes of the NFT
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TransferHistory::LEN,
+        seeds = [TRANSFER_HISTORY_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_history: Account<'info, TransferHistory>,
+    
+    /// Required when the NFT belongs to a collection; its chain routes bound the target chain
+    #[account(
+        seeds = [COLLECTION_STATE_SEED, &collection_state.collection_id.to_le_bytes()],
+        bump = collection_state.bump
+    )]
+    pub collection_state: Option<Account<'info, CollectionState>>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
     #[account(mut)]
     pub owner: Signer<'info>,
     
+    /// CHECK: Transfer hook authority PDA, required for Token-2022 NFTs that use this program's hook
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: Option<UncheckedAccount<'info>>,
+    
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(
-    ctx: Context<CrossChainTransfer>,
+#[allow(clippy::too_many_arguments)]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CrossChainTransfer<'info>>,
     target_chain_id: u64,
     recipient: Vec<u8>,
-    zeta_chain_data: Vec<u8>,
+    lane: MessageLane,
+    gas_amount: u64,
+    gas_limit: u64,
+    memo: Option<String>,
+    amount: u64,
 ) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_OUTBOUND)?;
+    FrozenAsset::require_not_frozen(&ctx.accounts.frozen_asset)?;
+    ctx.accounts.nft_metadata.require_unlocked()?;
+    
+    // Gateway must be configured and recently maintained before messages can flow
+    ctx.accounts.gateway_state.require_live(Clock::get()?.unix_timestamp)?;
+    
     // Validate target chain ID
-    if !ctx.accounts.gateway_state.supported_chains.contains(&target_chain_id) {
-        return err!(UniversalNFTError::UnsupportedTargetChain);
+    if !ctx.accounts.gateway_state.is_chain_supported(ctx.accounts.chain_config.chain_index) {
+        return err!(UniversalNFTError::ChainDisabled);
     }
     
     // Cannot transfer to the same chain
@@ -85,80 +208,202 @@ pub fn handler(
         return err!(UniversalNFTError::InvalidZetaChainID);
     }
     
-    // Validate recipient address length
-    if recipient.len() > MAX_RECIPIENT_ADDRESS_LENGTH {
+    // Collection NFTs may only travel along their collection's chain routes
+    CollectionState::require_route(&ctx.accounts.nft_metadata, ctx.accounts.collection_state.as_ref(), ctx.accounts.chain_config.chain_index)?;
+    
+    // Validate recipient address against the target chain's address format
+    if recipient.len() > MAX_RECIPIENT_ADDRESS_LENGTH || !ctx.accounts.chain_config.address_format.is_valid_address(&recipient) {
         return err!(UniversalNFTError::InvalidRecipientAddress);
     }
     
-    // Validate cross-chain data length
-    if zeta_chain_data.len() > MAX_CROSS_CHAIN_DATA_LENGTH {
-        return err!(UniversalNFTError::InvalidCrossChainData);
+    let memo = memo.unwrap_or_default();
+    if memo.len() > MAX_MEMO_LENGTH {
+        return err!(UniversalNFTError::InvalidMemo);
     }
+    ctx.accounts.denylist.require_allowed(&recipient)?;
     
-    // Check if transfer is already in progress
-    if ctx.accounts.transfer_state.status == TransferStatus::InProgress {
-        return err!(UniversalNFTError::TransferInProgress);
+    // The holder's whole balance travels, so one transfer state accounts for every escrowed unit
+    if amount == 0 || amount != ctx.accounts.owner_token_account.amount {
+        return err!(UniversalNFTError::InvalidAmount);
     }
     
-    let clock = Clock::get()?;
-    
-    // Get the token ID from NFT origin for cross-chain message
-    let token_id = ctx.accounts.nft_metadata.token_id;
+    // Zero selects the chain's default gas limit
+    let chain_config = &ctx.accounts.chain_config;
+    let gas_limit = if gas_limit == 0 { chain_config.default_gas_limit } else { gas_limit };
+    if gas_limit > chain_config.max_gas_limit {
+        return err!(UniversalNFTError::GasLimitExceeded);
+    }
     
-    // Transfer NFT from owner to program (burning it on Solana)
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.owner_token_account.to_account_info(),
-        to: ctx.accounts.nft_mint.to_account_info(),
-        authority: ctx.accounts.owner.to_account_info(),
-    };
+    let clock = Clock::get()?;
     
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    // Bound outbound traffic; value is the destination gas deposit
+    ctx.accounts.rate_limiter.record(false, clock.unix_timestamp, gas_amount)?;
     
-    anchor_spl::token::transfer(cpi_ctx, 1)?;
+    // Cap how fast a single wallet can be drained
+    let owner_velocity = &mut ctx.accounts.owner_velocity;
+    owner_velocity.owner = ctx.accounts.owner.key();
+    owner_velocity.bump = ctx.bumps.owner_velocity;
+    owner_velocity.account_version = ACCOUNT_VERSION;
+    owner_velocity.record(clock.unix_timestamp, ctx.accounts.rate_limiter.max_owner_transfers_per_day)?;
     
-    // Burn the NFT by setting supply to 0
-    let cpi_accounts = anchor_spl::token::Burn {
-        mint: ctx.accounts.nft_mint.to_account_info(),
-        from: ctx.accounts.owner_token_account.to_account_info(),
-        authority: ctx.accounts.owner.to_account_info(),
-    };
+    // Get the token ID from NFT origin for cross-chain message
+    let token_id = ctx.accounts.nft_metadata.token_id;
     
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    anchor_spl::token::burn(cpi_ctx, 1)?;
+    // Move the NFT into the program escrow until ZetaChain confirms or reverts the transfer
+    match ProgrammableTransfer::from_remaining(ctx.remaining_accounts, &ctx.accounts.nft_mint.key())? {
+        // pNFT token accounts stay frozen, so they move through token metadata under the rule set
+        Some(pnft) => pnft.transfer(
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.owner_token_account.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.escrow_token_account.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+            &[],
+        )?,
+        None => {
+            transfer_hook::transfer_amount(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.owner_token_account.to_account_info(),
+                &ctx.accounts.nft_mint.to_account_info(),
+                &ctx.accounts.escrow_token_account.to_account_info(),
+                &ctx.accounts.owner.to_account_info(),
+                ctx.accounts.mint_authority.as_ref().map(|a| a.to_account_info()),
+                &[],
+                amount,
+            )?;
+        }
+    }
     
     // Initialize cross-chain transfer state
     let transfer_state = &mut ctx.accounts.transfer_state;
     transfer_state.nft_mint = ctx.accounts.nft_mint.key();
     transfer_state.token_id = token_id; // Set the Universal NFT token ID
+    transfer_state.sender = ctx.accounts.owner.key();
     transfer_state.source_chain_id = ZETA_CHAIN_ID_SOLANA;
     transfer_state.target_chain_id = target_chain_id;
-    transfer_state.recipient = recipient;
+    transfer_state.recipient = recipient.clone();
     transfer_state.status = TransferStatus::InProgress;
     transfer_state.zeta_tx_hash = [0u8; 32]; // Will be updated when ZetaChain confirms
+    transfer_state.gas_amount = gas_amount;
+    transfer_state.gas_limit = gas_limit;
     transfer_state.created_at = clock.unix_timestamp;
-    transfer_state.bump = *ctx.bumps.get("transfer_state").unwrap();
+    transfer_state.bump = ctx.bumps.transfer_state;
+    transfer_state.account_version = ACCOUNT_VERSION;
+    transfer_state.memo = memo.clone();
+    transfer_state.amount = amount;
+    
+    // Hold the destination gas deposit in the transfer state until the gateway confirms or reverts
+    if gas_amount > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.transfer_state.to_account_info(),
+                },
+            ),
+            gas_amount,
+        )?;
+    }
+    
+    // Encode the message for the EVM Universal NFT contracts
+    let payload = encode_nft_message(&NFTMessage {
+        token_id,
+        uri: ctx.accounts.nft_metadata.metadata_uri.clone(),
+        receiver: recipient.clone(),
+        sender: ctx.accounts.owner.key().to_bytes().to_vec(),
+        gas_limit,
+        amount,
+    });
+    
+    // Priority messages pay a surcharge into the outbox
+    let fee_paid = match lane {
+        MessageLane::Standard => 0,
+        MessageLane::Priority => PRIORITY_LANE_FEE,
+    };
+    if fee_paid > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.outbox.to_account_info(),
+                },
+            ),
+            fee_paid,
+        )?;
+    }
+    
+    // Append the message to the outbox for relayers
+    let outbox = &mut ctx.accounts.outbox;
+    let sequence = outbox.next_sequence;
+    let outbox_message = &mut ctx.accounts.outbox_message;
+    outbox_message.sequence = sequence;
+    outbox_message.nft_mint = ctx.accounts.nft_mint.key();
+    outbox_message.token_id = token_id;
+    outbox_message.sender = ctx.accounts.owner.key();
+    outbox_message.target_chain_id = target_chain_id;
+    outbox_message.recipient = recipient.clone();
+    outbox_message.payload = payload;
+    outbox_message.lane = lane;
+    outbox_message.fee_paid = fee_paid;
+    outbox_message.gas_amount = gas_amount;
+    outbox_message.created_at = clock.unix_timestamp;
+    outbox_message.bump = ctx.bumps.outbox_message;
+    outbox_message.account_version = ACCOUNT_VERSION;
+    outbox.next_sequence = outbox.next_sequence.checked_add(1).ok_or(error!(UniversalNFTError::ArithmeticOverflow))?;
+    let lane_stats = outbox.lane_stats_mut(lane);
+    lane_stats.enqueued = lane_stats.enqueued.checked_add(1).ok_or(error!(UniversalNFTError::ArithmeticOverflow))?;
+    lane_stats.fees_collected = lane_stats.fees_collected.checked_add(fee_paid).ok_or(error!(UniversalNFTError::ArithmeticOverflow))?;
+    
+    // Record the ownership change for activity feeds
+    let transfer_history = &mut ctx.accounts.transfer_history;
+    transfer_history.mint = ctx.accounts.nft_mint.key();
+    transfer_history.bump = ctx.bumps.transfer_history;
+    transfer_history.account_version = ACCOUNT_VERSION;
+    transfer_history.push(TransferRecord {
+        owner: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+        kind: TransferKind::CrossChain,
+    });
     
     // Update NFT metadata to reflect transfer
     let nft_metadata = &mut ctx.accounts.nft_metadata;
     nft_metadata.owner = Pubkey::default(); // Clear owner during transfer
+    nft_metadata.locked = true; // Held until the transfer is confirmed, reverted or cancelled
     nft_metadata.updated_at = clock.unix_timestamp;
     
     // Update program state
     let program_state = &mut ctx.accounts.program_state;
-    program_state.total_minted -= 1;
+    program_state.total_minted = program_state.total_minted.checked_sub(1).ok_or(error!(UniversalNFTError::SupplyUnderflow))?;
+    
+    emit!(OutboundTransferInitiated {
+        nft_mint: ctx.accounts.nft_mint.key(),
+        token_id,
+        sender: ctx.accounts.owner.key(),
+        target_chain_id,
+        recipient: recipient.clone(),
+        memo,
+        initiated_at: clock.unix_timestamp,
+    });
     
     msg!("Cross-chain transfer initiated");
     msg!("NFT: {}", ctx.accounts.nft_mint.key());
-    msg!("Token ID: {}", token_id);
+    msg!("Token ID: {:?}", token_id);
     msg!("From: Solana (Chain ID: {})", ZETA_CHAIN_ID_SOLANA);
     msg!("To: Chain ID: {}", target_chain_id);
     msg!("Recipient: {:?}", recipient);
+    msg!("Amount: {}", amount);
+    msg!("Outbox sequence: {}", sequence);
+    msg!("Priority lane: {}", lane == MessageLane::Priority);
+    msg!("Destination gas: {} lamports", gas_amount);
+    msg!("Gas limit: {}", gas_limit);
     msg!("Status: In Progress");
     
-    // TODO: Integrate with ZetaChain gateway contract to initiate actual cross-chain transfer
-    // The token ID should be included in the cross-chain message to identify the NFT on the target chain
-    // This would involve calling the gateway contract with the transfer parameters including the token ID
-    
     Ok(())
 }
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{PauseConfig, PendingDelivery},
+    errors::UniversalNFTError,
+    events::DeliveryDisputeChanged,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct DisputeIncomingNFT<'info> {
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump,
+        constraint = pause_config.watcher != Pubkey::default() && pause_config.watcher == watcher.key() @ UniversalNFTError::Unauthorized
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        mut,
+        seeds = [PENDING_DELIVERY_SEED, pending_delivery.mint.as_ref()],
+        bump = pending_delivery.bump
+    )]
+    pub pending_delivery: Account<'info, PendingDelivery>,
+    
+    pub watcher: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<DisputeIncomingNFT>) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    let pending_delivery = &mut ctx.accounts.pending_delivery;
+    
+    // Disputes are only accepted while the challenge window is open
+    if clock.unix_timestamp >= pending_delivery.available_at {
+        return err!(UniversalNFTError::ChallengePeriodEnded);
+    }
+    if pending_delivery.disputed {
+        return err!(UniversalNFTError::DeliveryDisputed);
+    }
+    
+    pending_delivery.disputed = true;
+    
+    emit!(DeliveryDisputeChanged {
+        mint: pending_delivery.mint,
+        disputed: true,
+        changed_by: ctx.accounts.watcher.key(),
+        changed_at: clock.unix_timestamp,
+    });
+    
+    msg!("Incoming delivery disputed");
+    msg!("Mint: {}", pending_delivery.mint);
+    msg!("Watcher: {}", ctx.accounts.watcher.key());
+    
+    Ok(())
+}
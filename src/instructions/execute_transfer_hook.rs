@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    state::NFTMetadata,
+    errors::UniversalNFTError,
+    transfer_hook::is_transferring,
+    seeds::*,
+};
+
+/// Accounts Token-2022 passes to the transfer hook, followed by the resolved extra accounts
+#[derive(Accounts)]
+pub struct ExecuteTransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Source owner or delegate, already authorized by Token-2022
+    pub owner: UncheckedAccount<'info>,
+    
+    /// CHECK: Extra account metas list for the mint, read by Token-2022 to resolve the accounts below
+    #[account(
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, mint.key().as_ref()],
+        bump = nft_metadata.bump,
+        constraint = nft_metadata.mint == mint.key() @ UniversalNFTError::MintMismatch
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+}
+
+pub fn handler(
+    ctx: Context<ExecuteTransferHook>,
+    _amount: u64,
+) -> Result<()> {
+    // Only Token-2022 may drive the hook, never a direct call
+    if !is_transferring(&ctx.accounts.source_token.to_account_info())? {
+        return err!(UniversalNFTError::NotTransferring);
+    }
+    
+    // Escrowed and owner-locked NFTs cannot leave through a plain transfer
+    ctx.accounts.nft_metadata.require_unlocked()?;
+    
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.owner = ctx.accounts.destination_token.owner;
+    nft_metadata.updated_at = Clock::get()?.unix_timestamp;
+    
+    msg!("NFT owner synchronized by transfer hook");
+    msg!("NFT: {}", ctx.accounts.mint.key());
+    msg!("New owner: {}", nft_metadata.owner);
+    
+    Ok(())
+}
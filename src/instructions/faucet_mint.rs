@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, MintTo, SetAuthority, spl_token::instruction::AuthorityType},
+};
+
+use crate::{
+    state::{ProgramState, PauseConfig, NFTMetadata, NFTOrigin},
+    errors::UniversalNFTError,
+    codec::derive_token_id,
+    constants::*,
+    seeds::*,
+};
+
+// Devnet faucet
+//
+// Only usable in builds with the `devnet-faucet` feature; without it the instruction always fails.
+// Programs cannot read the cluster's genesis hash at runtime, so callers pass the hash reported by
+// their RPC node (`getGenesisHash`) and it must match devnet; this stops clients pointed at the
+// wrong cluster, while keeping the feature out of mainnet builds is what keeps the faucet off mainnet.
+
+#[derive(Accounts)]
+#[instruction(genesis_hash: [u8; 32], token_id_slot: u64)]
+pub struct FaucetMint<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = SOLANA_DECIMALS,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = NFTMetadata::LEN,
+        seeds = [NFT_METADATA_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = NFTOrigin::LEN,
+        seeds = [TOKEN_ID_SEED, derive_token_id(&mint.key(), token_id_slot, program_state.next_token_id).as_ref()],
+        bump
+    )]
+    pub nft_origin: Account<'info, NFTOrigin>,
+    
+    /// CHECK: PDA used as mint and freeze authority for program-minted assets
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub recipient: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<FaucetMint>,
+    genesis_hash: [u8; 32],
+    token_id_slot: u64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_MINT)?;
+    
+    // Refuse to run in builds without the faucet or against any cluster but devnet
+    if !cfg!(feature = "devnet-faucet") {
+        return err!(UniversalNFTError::FaucetUnavailable);
+    }
+    if genesis_hash != DEVNET_GENESIS_HASH {
+        return err!(UniversalNFTError::FaucetUnavailable);
+    }
+    
+    // Check max supply
+    let program_state = &mut ctx.accounts.program_state;
+    if program_state.total_minted >= program_state.max_supply {
+        return err!(UniversalNFTError::MaxSupplyExceeded);
+    }
+    
+    let clock = Clock::get()?;
+    
+    // The slot must be recent so token IDs track the block the NFT was minted in
+    if token_id_slot > clock.slot || clock.slot - token_id_slot > MAX_TOKEN_ID_SLOT_AGE {
+        return err!(UniversalNFTError::InvalidTokenIdSlot);
+    }
+    
+    let token_id = derive_token_id(&ctx.accounts.mint.key(), token_id_slot, program_state.next_token_id);
+    
+    // Mint the sample NFT straight to the caller
+    let mint_authority_bump = ctx.bumps.mint_authority;
+    let mint_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[mint_authority_bump]];
+    let mint_authority_signer = &[mint_authority_seeds];
+    
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: ctx.accounts.mint_authority.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, mint_authority_signer);
+    
+    anchor_spl::token::mint_to(cpi_ctx, 1)?;
+    
+    // Drop the mint authority so no second token can ever be minted
+    token::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.mint_authority.to_account_info(),
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+            },
+            mint_authority_signer,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+    
+    // Initialize NFT metadata
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.mint = ctx.accounts.mint.key();
+    nft_metadata.owner = ctx.accounts.recipient.key();
+    nft_metadata.metadata_uri = FAUCET_METADATA_URI.to_string();
+    nft_metadata.zeta_chain_id = ZETA_CHAIN_ID_SOLANA;
+    nft_metadata.cross_chain_data_hash = [0u8; 32];
+    nft_metadata.token_id = token_id;
+    nft_metadata.locked = false;
+    nft_metadata.owner_locked = false;
+    nft_metadata.created_at = clock.unix_timestamp;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    nft_metadata.bump = ctx.bumps.nft_metadata;
+    nft_metadata.account_version = ACCOUNT_VERSION;
+    nft_metadata.collection = Pubkey::default();
+    nft_metadata.metadata_immutable = false;
+    nft_metadata.metadata_hash = [0u8; 32];
+    nft_metadata.royalty_basis_points = 0;
+    nft_metadata.royalty_recipient = Pubkey::default();
+    
+    // Initialize NFT origin tracking
+    let nft_origin = &mut ctx.accounts.nft_origin;
+    nft_origin.token_id = token_id;
+    nft_origin.original_mint = ctx.accounts.mint.key();
+    nft_origin.original_metadata_uri = FAUCET_METADATA_URI.to_string();
+    nft_origin.source_chain_id = ZETA_CHAIN_ID_SOLANA;
+    nft_origin.created_at = clock.unix_timestamp;
+    nft_origin.bump = ctx.bumps.nft_origin;
+    nft_origin.account_version = ACCOUNT_VERSION;
+    
+    // Update program state
+    program_state.total_minted = program_state.total_minted.checked_add(1).ok_or(error!(UniversalNFTError::SupplyOverflow))?;
+    program_state.next_token_id = program_state.next_token_id.checked_add(1).ok_or(error!(UniversalNFTError::TokenIdOverflow))?;
+    
+    msg!("Faucet NFT minted");
+    msg!("Mint address: {}", ctx.accounts.mint.key());
+    msg!("Owner: {}", ctx.accounts.recipient.key());
+    msg!("Token ID: {:?}", token_id);
+    
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    state::{ProgramState, PauseConfig, FrozenAsset, PendingDelivery, NFTMetadata, CrossChainTransferState, TransferStatus, TransferHistory, TransferRecord, TransferKind},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct FinalizeIncomingNFT<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        mut,
+        seeds = [PENDING_DELIVERY_SEED, nft_mint.key().as_ref()],
+        bump = pending_delivery.bump,
+        close = relayer
+    )]
+    pub pending_delivery: Account<'info, PendingDelivery>,
+    
+    #[account(
+        mut,
+        seeds = [CROSS_CHAIN_TRANSFER_SEED, nft_mint.key().as_ref()],
+        bump = transfer_state.bump,
+        constraint = transfer_state.status == TransferStatus::InProgress @ UniversalNFTError::InvalidTransferStatus
+    )]
+    pub transfer_state: Account<'info, CrossChainTransferState>,
+    
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    /// CHECK: FrozenAsset PDA for the mint; release is rejected while it exists
+    #[account(
+        seeds = [FROZEN_ASSET_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub frozen_asset: UncheckedAccount<'info>,
+    
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: PDA that holds incoming NFTs while the challenge period runs
+    #[account(
+        seeds = [ESCROW_AUTHORITY_SEED],
+        bump
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = escrow_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Validated against the recipient recorded in the pending delivery
+    #[account(
+        constraint = recipient.key() == pending_delivery.recipient @ UniversalNFTError::RecipientMismatch
+    )]
+    pub recipient: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the pending delivery rent; validated against the recorded relayer
+    #[account(
+        mut,
+        constraint = relayer.key() == pending_delivery.relayer @ UniversalNFTError::Unauthorized
+    )]
+    pub relayer: UncheckedAccount<'info>,
+    
+    /// Latest ownership changes of the NFT
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = TransferHistory::LEN,
+        seeds = [TRANSFER_HISTORY_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_history: Account<'info, TransferHistory>,
+    
+    /// Anyone can finalize once the challenge period has passed; pays for the transfer history on first use
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<FinalizeIncomingNFT>) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_INBOUND)?;
+    FrozenAsset::require_not_frozen(&ctx.accounts.frozen_asset)?;
+    
+    let clock = Clock::get()?;
+    
+    // The message must have survived its challenge window undisputed
+    let pending_delivery = &ctx.accounts.pending_delivery;
+    if pending_delivery.disputed {
+        return err!(UniversalNFTError::DeliveryDisputed);
+    }
+    if clock.unix_timestamp < pending_delivery.available_at {
+        return err!(UniversalNFTError::ChallengePeriodNotEnded);
+    }
+    
+    // Release the NFT from escrow to the recipient
+    let escrow_bump = ctx.bumps.escrow_authority;
+    let escrow_seeds: &[&[u8]] = &[ESCROW_AUTHORITY_SEED, &[escrow_bump]];
+    let escrow_signer = &[escrow_seeds];
+    
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.escrow_token_account.to_account_info(),
+        mint: ctx.accounts.nft_mint.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: ctx.accounts.escrow_authority.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, escrow_signer);
+    
+    anchor_spl::token_interface::transfer_checked(cpi_ctx, 1, SOLANA_DECIMALS)?;
+    
+    // Record the ownership change for activity feeds
+    let transfer_history = &mut ctx.accounts.transfer_history;
+    transfer_history.mint = ctx.accounts.nft_mint.key();
+    transfer_history.bump = ctx.bumps.transfer_history;
+    transfer_history.account_version = ACCOUNT_VERSION;
+    transfer_history.push(TransferRecord {
+        owner: ctx.accounts.recipient.key(),
+        timestamp: clock.unix_timestamp,
+        kind: TransferKind::CrossChain,
+    });
+    
+    // Hand ownership to the recipient
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.owner = ctx.accounts.recipient.key();
+    nft_metadata.locked = false;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    
+    let transfer_state = &mut ctx.accounts.transfer_state;
+    transfer_state.status = TransferStatus::Completed;
+    
+    msg!("Incoming NFT finalized");
+    msg!("NFT: {}", ctx.accounts.nft_mint.key());
+    msg!("Recipient: {}", ctx.accounts.recipient.key());
+    msg!("Token ID: {:?}", transfer_state.token_id);
+    msg!("Status: Completed");
+    
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{PauseConfig, FrozenAsset},
+    errors::UniversalNFTError,
+    events::AssetFreezeChanged,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct FreezeAsset<'info> {
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump,
+        constraint = pause_config.watcher != Pubkey::default() && pause_config.watcher == watcher.key() @ UniversalNFTError::Unauthorized
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        init,
+        payer = watcher,
+        space = FrozenAsset::LEN,
+        seeds = [FROZEN_ASSET_SEED, mint.as_ref()],
+        bump
+    )]
+    pub frozen_asset: Account<'info, FrozenAsset>,
+    
+    #[account(mut)]
+    pub watcher: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<FreezeAsset>,
+    mint: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    // The mint may not exist yet when an incoming transfer is flagged before delivery
+    let frozen_asset = &mut ctx.accounts.frozen_asset;
+    frozen_asset.mint = mint;
+    frozen_asset.frozen_by = ctx.accounts.watcher.key();
+    frozen_asset.frozen_at = clock.unix_timestamp;
+    frozen_asset.bump = ctx.bumps.frozen_asset;
+    frozen_asset.account_version = ACCOUNT_VERSION;
+    
+    emit!(AssetFreezeChanged {
+        mint,
+        frozen: true,
+        changed_by: frozen_asset.frozen_by,
+        changed_at: clock.unix_timestamp,
+    });
+    
+    msg!("Asset frozen pending review");
+    msg!("Mint: {}", mint);
+    msg!("Watcher: {}", frozen_asset.frozen_by);
+    
+    Ok(())
+}
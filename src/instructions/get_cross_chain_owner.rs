@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    state::OwnershipVerificationState,
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct GetCrossChainOwner<'info> {
+    #[account(
+        seeds = [OWNERSHIP_VERIFICATION_SEED, nft_mint.key().as_ref()],
+        bump = verification_state.bump
+    )]
+    pub verification_state: Account<'info, OwnershipVerificationState>,
+    
+    pub nft_mint: Account<'info, Mint>,
+}
+
+/// Last verified owner of an NFT on a remote chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CrossChainOwner {
+    pub nft_mint: Pubkey,
+    pub chain_id: u64,
+    pub owner: Vec<u8>, // Address in the remote chain's format
+    pub verified_at: i64,
+}
+
+pub fn handler(ctx: Context<GetCrossChainOwner>) -> Result<CrossChainOwner> {
+    let verification_state = &ctx.accounts.verification_state;
+    if !verification_state.verified {
+        return err!(UniversalNFTError::OwnershipNotVerified);
+    }
+    
+    let owner = CrossChainOwner {
+        nft_mint: verification_state.nft_mint,
+        chain_id: verification_state.owner_chain_id,
+        owner: verification_state.zeta_owner.clone(),
+        verified_at: verification_state.verified_at,
+    };
+    
+    msg!("Cross-chain owner");
+    msg!("NFT: {}", owner.nft_mint);
+    msg!("Chain ID: {}", owner.chain_id);
+    msg!("Owner: {:?}", owner.owner);
+    
+    Ok(owner)
+}
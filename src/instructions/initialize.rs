@@ -1,13 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
-};
 
 use crate::{
-    state::{ProgramState, ZetaChainGatewayState},
+    state::{ProgramState, ZetaChainGatewayState, Outbox, LaneStats, CollectionConfig, RoyaltyPolicy, PauseConfig, RateLimiter, RateWindow, Denylist, MintPhases},
     errors::UniversalNFTError,
     constants::*,
+    seeds::*,
 };
 
 #[derive(Accounts)]
@@ -16,7 +13,7 @@ pub struct Initialize<'info> {
         init,
         payer = authority,
         space = ProgramState::LEN,
-        seeds = [b"program_state"],
+        seeds = [PROGRAM_STATE_SEED],
         bump
     )]
     pub program_state: Account<'info, ProgramState>,
@@ -25,11 +22,56 @@ pub struct Initialize<'info> {
         init,
         payer = authority,
         space = ZetaChainGatewayState::LEN,
-        seeds = [b"gateway_state"],
+        seeds = [GATEWAY_STATE_SEED],
         bump
     )]
     pub gateway_state: Account<'info, ZetaChainGatewayState>,
     
+    #[account(
+        init,
+        payer = authority,
+        space = Outbox::LEN,
+        seeds = [OUTBOX_SEED],
+        bump
+    )]
+    pub outbox: Account<'info, Outbox>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = CollectionConfig::LEN,
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = PauseConfig::LEN,
+        seeds = [PAUSE_CONFIG_SEED],
+        bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = RateLimiter::LEN,
+        seeds = [RATE_LIMITER_SEED],
+        bump
+    )]
+    pub rate_limiter: Account<'info, RateLimiter>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Denylist::LEN,
+        seeds = [DENYLIST_SEED],
+        bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
@@ -57,38 +99,96 @@ pub fn handler(
     // Initialize program state
     let program_state = &mut ctx.accounts.program_state;
     program_state.authority = ctx.accounts.authority.key();
+    program_state.pending_authority = Pubkey::default();
+    program_state.authority_program = Pubkey::default();
     program_state.total_minted = 0;
     program_state.max_supply = max_supply;
     program_state.next_token_id = TOKEN_ID_OFFSET; // Start with offset for uniqueness
-    program_state.bump = *ctx.bumps.get("program_state").unwrap();
+    program_state.feature_flags = DEFAULT_FEATURE_FLAGS;
+    program_state.paused = false;
+    program_state.bump = ctx.bumps.program_state;
+    program_state.account_version = ACCOUNT_VERSION;
     program_state.created_at = clock.unix_timestamp;
+    program_state.default_name = DEFAULT_METADATA_NAME.to_string();
+    program_state.default_symbol = DEFAULT_METADATA_SYMBOL.to_string();
     
     // Initialize gateway state with default ZetaChain configuration
     let gateway_state = &mut ctx.accounts.gateway_state;
     gateway_state.gateway_address = DEFAULT_GATEWAY_ADDRESS; // Will be updated via setup_gateway
-    gateway_state.supported_chains = vec![
-        ZETA_CHAIN_ID_SOLANA,
-        ZETA_CHAIN_ID_ETHEREUM,
-        ZETA_CHAIN_ID_BSC,
-        ZETA_CHAIN_ID_POLYGON,
-        ZETA_CHAIN_ID_AVALANCHE,
-        ZETA_CHAIN_ID_ARBITRUM,
-        ZETA_CHAIN_ID_OPTIMISM,
-        ZETA_CHAIN_ID_BASE,
-        ZETA_CHAIN_ID_LINEA,
-        ZETA_CHAIN_ID_MANTLE,
-        ZETA_CHAIN_ID_SCROLL,
-        ZETA_CHAIN_ID_BERACHAIN,
-        ZETA_CHAIN_ID_BITCOIN,
-    ];
+    gateway_state.gateway_authority = Pubkey::default(); // Will be updated via setup_gateway
+    gateway_state.current_valid_until = i64::MAX;
+    gateway_state.next_gateway_address = DEFAULT_GATEWAY_ADDRESS;
+    gateway_state.next_gateway_authority = Pubkey::default();
+    gateway_state.next_valid_from = i64::MAX;
+    gateway_state.registered_chain_mask = 0;
+    gateway_state.supported_chain_mask = 0;
+    gateway_state.observers = [[0u8; 20]; MAX_OBSERVERS]; // Will be set via set_observers
+    gateway_state.observer_count = 0;
+    gateway_state.observer_threshold = 0;
     gateway_state.version = GATEWAY_VERSION;
+    gateway_state.transfer_timeout = TSS_TIMEOUT;
+    gateway_state.message_expiry_window = REPLAY_PROTECTION_WINDOW;
+    gateway_state.challenge_period = 0; // Incoming NFTs finalize instantly
     gateway_state.updated_at = clock.unix_timestamp;
-    gateway_state.bump = *ctx.bumps.get("gateway_state").unwrap();
+    gateway_state.bump = ctx.bumps.gateway_state;
+    gateway_state.account_version = ACCOUNT_VERSION;
+    
+    // Initialize outbound message queue
+    let outbox = &mut ctx.accounts.outbox;
+    outbox.next_sequence = 0;
+    outbox.standard_lane = LaneStats::default();
+    outbox.priority_lane = LaneStats::default();
+    outbox.bump = ctx.bumps.outbox;
+    outbox.account_version = ACCOUNT_VERSION;
+    
+    // Royalties are not enforced until the authority opts in
+    let collection_config = &mut ctx.accounts.collection_config;
+    collection_config.royalty_policy = RoyaltyPolicy::None;
+    collection_config.emission_start = 0;
+    collection_config.emission_period = 0; // No emission schedule
+    collection_config.emission_per_period = 0;
+    collection_config.updated_at = clock.unix_timestamp;
+    collection_config.bump = ctx.bumps.collection_config;
+    collection_config.account_version = ACCOUNT_VERSION;
+    collection_config.rule_set = Pubkey::default(); // Standard NFTs until set_rule_set
+    collection_config.allowed_uri_schemes = URI_SCHEME_ALL;
+    collection_config.max_uri_length = MAX_METADATA_URI_LENGTH as u16;
+    collection_config.mint_price = 0; // Free until set_mint_price
+    collection_config.allowlist_root = [0u8; 32]; // No Merkle allowlist until set_allowlist_root
+    collection_config.mint_phases = MintPhases::default(); // No launch schedule until set_mint_phases
+    collection_config.allowlist_phase_minted = 0;
+    collection_config.public_phase_minted = 0;
+    
+    // All operations start active
+    let pause_config = &mut ctx.accounts.pause_config;
+    pause_config.paused_operations = 0;
+    pause_config.watcher = Pubkey::default(); // Will be set via set_watcher
+    pause_config.updated_at = clock.unix_timestamp;
+    pause_config.bump = ctx.bumps.pause_config;
+    pause_config.account_version = ACCOUNT_VERSION;
+    
+    // Bound bridge traffic from the start
+    let rate_limiter = &mut ctx.accounts.rate_limiter;
+    rate_limiter.max_transfers = DEFAULT_MAX_TRANSFERS_PER_WINDOW;
+    rate_limiter.max_value = DEFAULT_MAX_VALUE_PER_WINDOW;
+    rate_limiter.window = RATE_LIMIT_WINDOW;
+    rate_limiter.outbound = RateWindow::default();
+    rate_limiter.inbound = RateWindow::default();
+    rate_limiter.max_owner_transfers_per_day = DEFAULT_MAX_OWNER_TRANSFERS_PER_DAY;
+    rate_limiter.updated_at = clock.unix_timestamp;
+    rate_limiter.bump = ctx.bumps.rate_limiter;
+    rate_limiter.account_version = ACCOUNT_VERSION;
+    
+    // No recipients are denied until the authority adds them
+    let denylist = &mut ctx.accounts.denylist;
+    denylist.entries = Vec::new();
+    denylist.updated_at = clock.unix_timestamp;
+    denylist.bump = ctx.bumps.denylist;
+    denylist.account_version = ACCOUNT_VERSION;
     
     msg!("Universal NFT program initialized successfully");
     msg!("Max supply: {}", max_supply);
     msg!("Next token ID: {}", program_state.next_token_id);
-    msg!("Supported chains: {}", gateway_state.supported_chains.len());
     
     Ok(())
 }
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{
+    update_metadata_accounts_v2,
+    Metadata,
+    UpdateMetadataAccountsV2,
+};
+
+use crate::{
+    state::NFTMetadata,
+    errors::UniversalNFTError,
+    events::MetadataLocked,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct LockMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, nft_metadata.mint.as_ref()],
+        bump = nft_metadata.bump,
+        has_one = owner @ UniversalNFTError::NotNFTOwner
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    /// CHECK: Metaplex metadata PDA, updated by the token metadata program
+    #[account(
+        mut,
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), nft_metadata.mint.as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA that is the Metaplex update authority of program-minted NFTs
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub update_authority: UncheckedAccount<'info>,
+    
+    pub owner: Signer<'info>,
+    
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+pub fn handler(ctx: Context<LockMetadata>) -> Result<()> {
+    ctx.accounts.nft_metadata.require_unlocked()?;
+    ctx.accounts.nft_metadata.require_mutable()?;
+    
+    // Metaplex never lets is_mutable be set back, so the lock can't be undone on either side
+    let update_authority_bump = ctx.bumps.update_authority;
+    let update_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[update_authority_bump]];
+    
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                update_authority: ctx.accounts.update_authority.to_account_info(),
+            },
+            &[update_authority_seeds],
+        ),
+        None,
+        None,
+        None,
+        Some(false), // is_mutable
+    )?;
+    
+    let clock = Clock::get()?;
+    
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.metadata_immutable = true;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    
+    emit!(MetadataLocked {
+        mint: nft_metadata.mint,
+        owner: ctx.accounts.owner.key(),
+        locked_at: clock.unix_timestamp,
+    });
+    
+    msg!("NFT metadata locked");
+    msg!("NFT: {}", nft_metadata.mint);
+    msg!("Owner: {}", ctx.accounts.owner.key());
+    
+    Ok(())
+}
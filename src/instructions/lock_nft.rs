@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::NFTMetadata,
+    errors::UniversalNFTError,
+    events::NFTLockChanged,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct LockNFT<'info> {
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, nft_metadata.mint.as_ref()],
+        bump = nft_metadata.bump,
+        has_one = owner @ UniversalNFTError::NotNFTOwner
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<LockNFT>) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    if nft_metadata.owner_locked {
+        return err!(UniversalNFTError::NFTLocked);
+    }
+    
+    // Blocks transfers, burns, metadata updates and outbound transfers until unlocked
+    nft_metadata.owner_locked = true;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    
+    emit!(NFTLockChanged {
+        mint: nft_metadata.mint,
+        locked: true,
+        changed_by: ctx.accounts.owner.key(),
+        changed_at: clock.unix_timestamp,
+    });
+    
+    msg!("NFT locked");
+    msg!("NFT: {}", nft_metadata.mint);
+    msg!("Owner: {}", ctx.accounts.owner.key());
+    
+    Ok(())
+}
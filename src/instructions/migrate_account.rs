@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::{
+    state::*,
+    errors::UniversalNFTError,
+    instructions::migrate_token_id::grow_account,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: Program state, parsed manually because it may itself predate the current layout
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump,
+        owner = crate::ID @ UniversalNFTError::InvalidAccountOwner
+    )]
+    pub program_state: UncheckedAccount<'info>,
+    
+    /// CHECK: Any program account; its type is identified by the discriminator
+    #[account(
+        mut,
+        owner = crate::ID @ UniversalNFTError::InvalidAccountOwner
+    )]
+    pub account: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let program_state_info = ctx.accounts.program_state.to_account_info();
+    
+    // The program state is upgraded first so the authority check can parse it; a failed
+    // check below reverts the upgrade with the rest of the transaction
+    let program_state_upgraded = program_state_info.data_len() < ProgramState::LEN;
+    if program_state_upgraded {
+        upgrade::<ProgramState>(&program_state_info, &authority, &system_program, ProgramState::LEN)?;
+    }
+    let program_state = {
+        let data = program_state_info.try_borrow_data()?;
+        ProgramState::try_deserialize(&mut &data[..])?
+    };
+    if program_state.authority != ctx.accounts.authority.key() {
+        return err!(UniversalNFTError::Unauthorized);
+    }
+    program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let account_info = ctx.accounts.account.to_account_info();
+    if account_info.key() == program_state_info.key() && program_state_upgraded {
+        msg!("Program state migrated to layout version {}", ACCOUNT_VERSION);
+        return Ok(());
+    }
+    
+    let previous_version = upgrade_by_discriminator(&account_info, &authority, &system_program)?;
+    
+    msg!("Account migrated");
+    msg!("Account: {}", account_info.key());
+    msg!("Layout version: {} -> {}", previous_version, ACCOUNT_VERSION);
+    
+    Ok(())
+}
+
+/// Upgrade any program account to the current layout, dispatching on its discriminator
+fn upgrade_by_discriminator<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u16> {
+    let discriminator: [u8; 8] = {
+        let data = account.try_borrow_data()?;
+        if data.len() < 8 {
+            return err!(UniversalNFTError::InvalidLegacyAccount);
+        }
+        data[..8].try_into().unwrap()
+    };
+    
+    macro_rules! dispatch {
+        ($($account:ty),* $(,)?) => {
+            $(
+                if discriminator == <$account>::discriminator() {
+                    return upgrade::<$account>(account, payer, system_program, <$account>::LEN);
+                }
+            )*
+        };
+    }
+    
+    dispatch!(
+        ProgramState, ZetaChainGatewayState, NFTMetadata, NFTOrigin, CrossChainTransferState,
+        OwnershipVerificationState, Outbox, OutboxMessage, NotificationRegistration, AttestationRecord,
+        IdentityBinding, RewardsConfig, PointsLedger, ChainConfig, CollectionConfig, PauseConfig,
+        FrozenAsset, Denylist, AllowlistEntry, PendingDelivery, RelayerRecord, RateLimiter,
+        OwnerVelocity, OwnershipRoot, VerifiedCollection, CollectionState, Treasury,
+        AllowlistMintRecord, Airdrop, AirdropClaim, TokenApproval, OperatorApproval,
+        PermitNonce, WrappedNFT, TokenBalance, IncomingBatch, ClaimableNFT, Provenance, TransferHistory,
+    );
+    
+    err!(UniversalNFTError::InvalidLegacyAccount)
+}
+
+/// Grow the account to its current size where needed and stamp the current layout version.
+/// Versions and later fields are appended at the end, so older layouts parse with them zeroed once grown.
+fn upgrade<'info, T: AccountSerialize + AccountDeserialize + Versioned>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<u16> {
+    if account.data_len() < space {
+        grow_account(account, payer, system_program, space)?;
+    }
+    
+    let mut value = {
+        let data = account.try_borrow_data()?;
+        T::try_deserialize(&mut &data[..]).map_err(|_| error!(UniversalNFTError::InvalidLegacyAccount))?
+    };
+    let previous_version = value.account_version();
+    if previous_version >= ACCOUNT_VERSION {
+        return err!(UniversalNFTError::AccountAlreadyMigrated);
+    }
+    
+    value.set_account_version(ACCOUNT_VERSION);
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data[..];
+    value.try_serialize(&mut writer)?;
+    Ok(previous_version)
+}
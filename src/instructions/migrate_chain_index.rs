@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::{
+    state::{ProgramState, ZetaChainGatewayState, ChainConfig, LegacyZetaChainGatewayState, LegacyChainConfig},
+    errors::UniversalNFTError,
+    instructions::migrate_token_id::{read_legacy, write_migrated},
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct MigrateChainIndex<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// CHECK: Gateway state, parsed manually because it may still predate the chain masks
+    #[account(
+        mut,
+        seeds = [GATEWAY_STATE_SEED],
+        bump,
+        owner = crate::ID @ UniversalNFTError::InvalidAccountOwner
+    )]
+    pub gateway_state: UncheckedAccount<'info>,
+    
+    /// CHECK: Legacy ChainConfig without a chain index, migrated in place
+    #[account(
+        mut,
+        seeds = [CHAIN_CONFIG_SEED, &chain_id.to_le_bytes()],
+        bump,
+        owner = crate::ID @ UniversalNFTError::InvalidAccountOwner
+    )]
+    pub chain_config: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<MigrateChainIndex>,
+    chain_id: u64,
+    chain_index: u8,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let gateway_info = ctx.accounts.gateway_state.to_account_info();
+    
+    // The first migrated chain also upgrades the gateway state with empty masks
+    let mut gateway_state = if gateway_info.data_len() < ZetaChainGatewayState::LEN {
+        let legacy_gateway: LegacyZetaChainGatewayState =
+            read_legacy(&gateway_info, &ZetaChainGatewayState::discriminator(), ZetaChainGatewayState::LEN)?;
+        ZetaChainGatewayState {
+            gateway_address: legacy_gateway.gateway_address,
+            gateway_authority: legacy_gateway.gateway_authority,
+            current_valid_until: legacy_gateway.current_valid_until,
+            next_gateway_address: legacy_gateway.next_gateway_address,
+            next_gateway_authority: legacy_gateway.next_gateway_authority,
+            next_valid_from: legacy_gateway.next_valid_from,
+            registered_chain_mask: 0,
+            supported_chain_mask: 0,
+            observers: [[0u8; 20]; MAX_OBSERVERS],
+            observer_count: 0,
+            observer_threshold: 0,
+            version: legacy_gateway.version,
+            transfer_timeout: legacy_gateway.transfer_timeout,
+            message_expiry_window: legacy_gateway.message_expiry_window,
+            challenge_period: 0,
+            updated_at: legacy_gateway.updated_at,
+            bump: legacy_gateway.bump,
+            account_version: ACCOUNT_VERSION,
+        }
+    } else {
+        let data = gateway_info.try_borrow_data()?;
+        ZetaChainGatewayState::try_deserialize(&mut &data[..])?
+    };
+    
+    // Migrate the chain config in place, carrying its enabled flag into the mask
+    let chain_info = ctx.accounts.chain_config.to_account_info();
+    let legacy_chain: LegacyChainConfig = read_legacy(&chain_info, &ChainConfig::discriminator(), ChainConfig::LEN)?;
+    if legacy_chain.chain_id != chain_id {
+        return err!(UniversalNFTError::InvalidLegacyAccount);
+    }
+    gateway_state.assign_chain_index(chain_index)?;
+    gateway_state.set_chain_supported(chain_index, legacy_chain.enabled);
+    
+    let chain_config = ChainConfig {
+        chain_id: legacy_chain.chain_id,
+        chain_index,
+        name: legacy_chain.name,
+        address_format: legacy_chain.address_format,
+        required_confirmations: legacy_chain.required_confirmations,
+        default_gas_limit: legacy_chain.default_gas_limit,
+        max_gas_limit: legacy_chain.max_gas_limit,
+        enabled: legacy_chain.enabled,
+        created_at: legacy_chain.created_at,
+        updated_at: legacy_chain.updated_at,
+        bump: legacy_chain.bump,
+        account_version: ACCOUNT_VERSION,
+    };
+    write_migrated(&chain_info, &authority, &system_program, ChainConfig::LEN, &chain_config)?;
+    write_migrated(&gateway_info, &authority, &system_program, ZetaChainGatewayState::LEN, &gateway_state)?;
+    
+    msg!("Chain config migrated to indexed lookup");
+    msg!("Chain ID: {}", chain_id);
+    msg!("Chain index: {}", chain_index);
+    msg!("Enabled: {}", chain_config.enabled);
+    
+    Ok(())
+}
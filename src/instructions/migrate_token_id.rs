@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+
+use crate::{
+    state::{
+        ProgramState, NFTMetadata, NFTOrigin, CrossChainTransferState,
+        LegacyNFTMetadata, LegacyNFTOrigin, LegacyCrossChainTransferState,
+    },
+    errors::UniversalNFTError,
+    codec::token_id_from_u64,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(legacy_token_id: u64)]
+pub struct MigrateTokenId<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// CHECK: Legacy NFTMetadata, parsed manually because its layout predates uint256 token IDs
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump,
+        owner = crate::ID @ UniversalNFTError::InvalidAccountOwner
+    )]
+    pub nft_metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Only used to derive the metadata and transfer state PDAs
+    pub nft_mint: UncheckedAccount<'info>,
+    
+    /// CHECK: Legacy NFTOrigin seeded by the u64 token ID, closed after migration
+    #[account(
+        mut,
+        seeds = [TOKEN_ID_SEED, &legacy_token_id.to_le_bytes()],
+        bump,
+        owner = crate::ID @ UniversalNFTError::InvalidAccountOwner
+    )]
+    pub legacy_nft_origin: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = NFTOrigin::LEN,
+        seeds = [TOKEN_ID_SEED, token_id_from_u64(legacy_token_id).as_ref()],
+        bump
+    )]
+    pub nft_origin: Account<'info, NFTOrigin>,
+    
+    /// CHECK: Legacy CrossChainTransferState for the mint, if one exists
+    #[account(
+        mut,
+        seeds = [CROSS_CHAIN_TRANSFER_SEED, nft_mint.key().as_ref()],
+        bump,
+        owner = crate::ID @ UniversalNFTError::InvalidAccountOwner
+    )]
+    pub transfer_state: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<MigrateTokenId>,
+    legacy_token_id: u64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let token_id = token_id_from_u64(legacy_token_id);
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    
+    // Migrate NFT metadata in place
+    let metadata_info = ctx.accounts.nft_metadata.to_account_info();
+    let legacy_metadata: LegacyNFTMetadata = read_legacy(&metadata_info, &NFTMetadata::discriminator(), NFTMetadata::LEN)?;
+    if legacy_metadata.token_id != legacy_token_id || legacy_metadata.mint != ctx.accounts.nft_mint.key() {
+        return err!(UniversalNFTError::InvalidLegacyAccount);
+    }
+    let nft_metadata = NFTMetadata {
+        mint: legacy_metadata.mint,
+        owner: legacy_metadata.owner,
+        metadata_uri: legacy_metadata.metadata_uri,
+        zeta_chain_id: legacy_metadata.zeta_chain_id,
+        cross_chain_data_hash: legacy_metadata.cross_chain_data_hash,
+        token_id,
+        locked: false,
+        owner_locked: false,
+        created_at: legacy_metadata.created_at,
+        updated_at: legacy_metadata.updated_at,
+        bump: legacy_metadata.bump,
+        account_version: ACCOUNT_VERSION,
+        collection: Pubkey::default(),
+        metadata_immutable: false,
+        metadata_hash: [0u8; 32],
+        royalty_basis_points: 0,
+        royalty_recipient: Pubkey::default(),
+    };
+    write_migrated(&metadata_info, &authority, &system_program, NFTMetadata::LEN, &nft_metadata)?;
+    
+    // Move the origin record to its uint256-seeded address
+    let legacy_origin_info = ctx.accounts.legacy_nft_origin.to_account_info();
+    let legacy_origin: LegacyNFTOrigin = read_legacy(&legacy_origin_info, &NFTOrigin::discriminator(), NFTOrigin::LEN)?;
+    if legacy_origin.token_id != legacy_token_id {
+        return err!(UniversalNFTError::InvalidLegacyAccount);
+    }
+    let nft_origin = &mut ctx.accounts.nft_origin;
+    nft_origin.token_id = token_id;
+    nft_origin.original_mint = legacy_origin.original_mint;
+    nft_origin.original_metadata_uri = legacy_origin.original_metadata_uri;
+    nft_origin.source_chain_id = legacy_origin.source_chain_id;
+    nft_origin.created_at = legacy_origin.created_at;
+    nft_origin.bump = ctx.bumps.nft_origin;
+    nft_origin.account_version = ACCOUNT_VERSION;
+    close_legacy(&legacy_origin_info, &authority)?;
+    
+    // Migrate the transfer state in place, if provided
+    if let Some(transfer_state) = &ctx.accounts.transfer_state {
+        let transfer_info = transfer_state.to_account_info();
+        let legacy_transfer: LegacyCrossChainTransferState =
+            read_legacy(&transfer_info, &CrossChainTransferState::discriminator(), CrossChainTransferState::LEN)?;
+        if legacy_transfer.token_id != legacy_token_id {
+            return err!(UniversalNFTError::InvalidLegacyAccount);
+        }
+        let migrated_transfer = CrossChainTransferState {
+            nft_mint: legacy_transfer.nft_mint,
+            token_id,
+            sender: legacy_transfer.sender,
+            source_chain_id: legacy_transfer.source_chain_id,
+            target_chain_id: legacy_transfer.target_chain_id,
+            recipient: legacy_transfer.recipient,
+            status: legacy_transfer.status,
+            zeta_tx_hash: legacy_transfer.zeta_tx_hash,
+            gas_amount: 0,
+            gas_limit: 0,
+            created_at: legacy_transfer.created_at,
+            bump: legacy_transfer.bump,
+            account_version: ACCOUNT_VERSION,
+            memo: String::new(),
+            amount: 1,
+        };
+        write_migrated(&transfer_info, &authority, &system_program, CrossChainTransferState::LEN, &migrated_transfer)?;
+    }
+    
+    msg!("Token ID migrated to uint256");
+    msg!("Mint: {}", ctx.accounts.nft_mint.key());
+    msg!("Legacy token ID: {}", legacy_token_id);
+    msg!("Token ID: {:?}", token_id);
+    
+    Ok(())
+}
+
+/// Deserialize a legacy layout, rejecting accounts that are already at the new size
+pub(crate) fn read_legacy<T: AnchorDeserialize>(account: &AccountInfo, discriminator: &[u8; 8], migrated_len: usize) -> Result<T> {
+    let data = account.try_borrow_data()?;
+    if data.len() >= migrated_len {
+        return err!(UniversalNFTError::AccountAlreadyMigrated);
+    }
+    if data.len() < 8 || &data[..8] != discriminator {
+        return err!(UniversalNFTError::InvalidLegacyAccount);
+    }
+    T::deserialize(&mut &data[8..]).map_err(|_| error!(UniversalNFTError::InvalidLegacyAccount))
+}
+
+/// Grow the account to its new size, topping up rent from the payer, and write the new layout
+pub(crate) fn write_migrated<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    value: &T,
+) -> Result<()> {
+    grow_account(account, payer, system_program, space)?;
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data[..];
+    value.try_serialize(&mut writer)?;
+    Ok(())
+}
+
+/// Resize the account, zero-filling new bytes and topping up rent from the payer
+pub(crate) fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+    if required_lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+    
+    account.realloc(space, true)?;
+    Ok(())
+}
+
+/// Close a legacy account, returning its rent to the destination
+fn close_legacy<'info>(account: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? += lamports;
+    **account.try_borrow_mut_lamports()? = 0;
+    account.assign(&system_program::ID);
+    account.realloc(0, false)?;
+    Ok(())
+}
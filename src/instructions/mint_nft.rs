@@ -1,45 +1,73 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount, MintTo},
+    token::{self, spl_token::instruction::AuthorityType, Mint, Token, TokenAccount, MintTo, SetAuthority},
+    token_interface::{self, Mint as PaymentMint, TokenAccount as PaymentTokenAccount, TokenInterface, TransferChecked},
     metadata::{
+        create_master_edition_v3,
         create_metadata_accounts_v3,
+        CreateMasterEditionV3,
         CreateMetadataAccountsV3,
-        DataV2,
+        Metadata,
     },
 };
-use mpl_token_metadata::instruction::create_metadata_accounts_v3 as mpl_create_metadata;
 
 use crate::{
-    state::{ProgramState, NFTMetadata, ZetaChainGatewayState, NFTOrigin},
+    state::{ProgramState, PauseConfig, NFTMetadata, ZetaChainGatewayState, ChainConfig, NFTOrigin, CollectionConfig, AllowlistEntry, AllowlistMintRecord, AllowlistProof, MintCoupon, MintPhase, VerifiedCollection, CollectionState, AssetBackend, Treasury, TokenBalance},
     errors::UniversalNFTError,
+    payload::CrossChainPayload,
+    metaplex::{NFTCreator, validate_display_data, build_data_v2},
+    programmable::{self, ProgrammableAccounts},
+    codec::derive_token_id,
+    merkle,
+    sigverify::ed25519_verified_signers,
     constants::*,
+    seeds::*,
 };
 
 #[derive(Accounts)]
+#[instruction(metadata_uri: String, zeta_chain_id: u64, payload: CrossChainPayload, token_id_slot: u64)]
 pub struct MintNFT<'info> {
     #[account(
         mut,
-        seeds = [b"program_state"],
-        bump = program_state.bump,
-        has_one = authority
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
     )]
     pub program_state: Account<'info, ProgramState>,
     
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
     #[account(
         mut,
-        seeds = [b"gateway_state"],
+        seeds = [GATEWAY_STATE_SEED],
         bump = gateway_state.bump
     )]
     pub gateway_state: Account<'info, ZetaChainGatewayState>,
     
+    #[account(
+        seeds = [CHAIN_CONFIG_SEED, &zeta_chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    #[account(
+        mut,
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
     #[account(
         init,
         payer = payer,
-        mint = mint,
-        authority = mint_authority,
-        decimals = SOLANA_DECIMALS,
-        freeze_authority = Some(mint_authority.key()),
+        mint::decimals = SOLANA_DECIMALS,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
     )]
     pub mint: Account<'info, Mint>,
     
@@ -47,15 +75,15 @@ pub struct MintNFT<'info> {
         init_if_needed,
         payer = payer,
         associated_token::mint = mint,
-        associated_token::authority = mint_authority,
+        associated_token::authority = minter,
     )]
     pub mint_ata: Account<'info, TokenAccount>,
     
     #[account(
         init,
         payer = payer,
-        space = NFTMetadata::LEN,
-        seeds = [b"nft_metadata", mint.key().as_ref()],
+        space = NFTMetadata::space(collection_config.max_uri_length()),
+        seeds = [NFT_METADATA_SEED, mint.key().as_ref()],
         bump
     )]
     pub nft_metadata: Account<'info, NFTMetadata>,
@@ -63,144 +91,478 @@ pub struct MintNFT<'info> {
     #[account(
         init,
         payer = payer,
-        space = NFTOrigin::LEN,
-        seeds = [TOKEN_ID_SEED, &program_state.next_token_id.to_le_bytes()],
+        space = NFTOrigin::space(collection_config.max_uri_length()),
+        seeds = [TOKEN_ID_SEED, derive_token_id(&mint.key(), token_id_slot, program_state.next_token_id).as_ref()],
         bump
     )]
     pub nft_origin: Account<'info, NFTOrigin>,
     
+    /// CHECK: Metaplex metadata PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Metaplex master edition PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), mint.key().as_ref(), METAPLEX_EDITION_SEED],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA used as mint, freeze and Metaplex update authority for program-minted assets, so the
+    /// program signs every mint and can keep on-chain metadata in sync
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
     #[account(mut)]
     pub payer: Signer<'info>,
     
-    /// CHECK: This is the mint authority for the NFT
-    pub mint_authority: UncheckedAccount<'info>,
+    /// Initial holder of the NFT; the program authority unless public minting is enabled
+    pub minter: Signer<'info>,
+    
+    /// Required while allowlist-gated minting is enabled
+    #[account(
+        seeds = [ALLOWLIST_SEED, minter.key().as_ref()],
+        bump = allowlist_entry.bump
+    )]
+    pub allowlist_entry: Option<Account<'info, AllowlistEntry>>,
+    
+    /// Counts the minter's Merkle allowlist and coupon mints; required when minting with either
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = AllowlistMintRecord::LEN,
+        seeds = [ALLOWLIST_MINT_SEED, minter.key().as_ref()],
+        bump
+    )]
+    pub allowlist_mint_record: Option<Account<'info, AllowlistMintRecord>>,
+    
+    /// When present, the NFT is minted into the program's collection, pending `verify_collection_item`
+    #[account(
+        seeds = [VERIFIED_COLLECTION_SEED],
+        bump = verified_collection.bump
+    )]
+    pub verified_collection: Option<Account<'info, VerifiedCollection>>,
+    
+    /// Receives the mint price; required for public mints while a price is set
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+    
+    /// Token the collection is priced in; required while the collection has an SPL token price
+    pub payment_mint: Option<InterfaceAccount<'info, PaymentMint>>,
+    
+    /// Payer's token account the SPL token price is taken from
+    #[account(mut)]
+    pub payer_payment_account: Option<InterfaceAccount<'info, PaymentTokenAccount>>,
+    
+    /// Treasury-owned token account receiving the SPL token price
+    #[account(mut)]
+    pub treasury_payment_account: Option<InterfaceAccount<'info, PaymentTokenAccount>>,
+    
+    pub payment_token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// When present, the NFT is issued into this collection instead of the global supply; only its
+    /// authority mints into it unless the collection has an SPL token price
+    #[account(
+        mut,
+        seeds = [COLLECTION_STATE_SEED, &collection_state.collection_id.to_le_bytes()],
+        bump = collection_state.bump
+    )]
+    pub collection_state: Option<Account<'info, CollectionState>>,
+    
+    /// Solana balance of a semi-fungible token; required when the payload mints more than one unit, rejected otherwise
+    #[account(
+        init,
+        payer = payer,
+        space = TokenBalance::LEN,
+        seeds = [TOKEN_BALANCE_SEED, derive_token_id(&mint.key(), token_id_slot, program_state.next_token_id).as_ref(), &ZETA_CHAIN_ID_SOLANA.to_le_bytes()],
+        bump
+    )]
+    pub token_balance: Option<Account<'info, TokenBalance>>,
+    
+    /// CHECK: Token record PDA of mint_ata, created by the token metadata program; pNFT mints only
+    #[account(mut)]
+    pub token_record: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Checked against the collection config's rule set; pNFT mints only
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Token auth rules program; pNFT mints only
+    #[account(address = TOKEN_AUTH_RULES_PROGRAM_ID)]
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Instructions sysvar, required by the token metadata program's V1 instructions; pNFT and coupon mints only
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
     
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<MintNFT>,
     metadata_uri: String,
     zeta_chain_id: u64,
-    cross_chain_data: Vec<u8>,
+    payload: CrossChainPayload,
+    token_id_slot: u64,
+    name: String,
+    symbol: String,
+    seller_fee_basis_points: u16,
+    creators: Vec<NFTCreator>,
+    allowlist_proof: Option<AllowlistProof>,
+    coupon: Option<MintCoupon>,
 ) -> Result<()> {
-    // Validate metadata URI length
-    if metadata_uri.len() > MAX_METADATA_URI_LENGTH {
-        return err!(UniversalNFTError::InvalidMetadataURILength);
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_MINT)?;
+    
+    // Global-supply mints are admin-only until the authority opens public minting; collection
+    // mints are signed by the collection authority, or by any wallet buying into a collection
+    // priced in an SPL token once public minting is open
+    let public_mint = ctx.accounts.collection_state.is_none()
+        && ctx.accounts.minter.key() != ctx.accounts.program_state.authority;
+    let public_collection_mint = ctx.accounts.collection_state.as_ref()
+        .is_some_and(|collection_state| collection_state.authority != ctx.accounts.minter.key());
+    if public_collection_mint && ctx.accounts.collection_state.as_ref().is_some_and(|collection_state| collection_state.price_amount == 0) {
+        return err!(UniversalNFTError::Unauthorized);
+    }
+    if (public_mint || public_collection_mint) && !ctx.accounts.program_state.is_feature_enabled(FEATURE_PUBLIC_MINT) {
+        return err!(UniversalNFTError::Unauthorized);
+    }
+    
+    // Scheduled launches run an allowlist phase, then a public phase, for public mints
+    let phase = if public_mint {
+        ctx.accounts.collection_config.mint_phases.current(Clock::get()?.unix_timestamp)?
+    } else {
+        None
+    };
+    
+    // Controlled launches only mint to allowlisted wallets, listed by an AllowlistEntry, a proof
+    // against the Merkle allowlist root, or a coupon signed by the authority
+    let allowlist_required = ctx.accounts.program_state.is_feature_enabled(FEATURE_ALLOWLIST_MINT) || phase == Some(MintPhase::Allowlist);
+    if allowlist_required && ctx.accounts.allowlist_entry.is_none() {
+        let max_mints = match (coupon, allowlist_proof) {
+            (Some(coupon), _) => {
+                let instructions = ctx.accounts.sysvar_instructions.as_ref().ok_or(error!(UniversalNFTError::InvalidMintCoupon))?;
+                if coupon.expiry < Clock::get()?.unix_timestamp {
+                    return err!(UniversalNFTError::InvalidMintCoupon);
+                }
+                let message = coupon_message(&ctx.accounts.minter.key(), &coupon);
+                let signers = ed25519_verified_signers(&instructions.to_account_info(), &message)?;
+                if !signers.contains(&ctx.accounts.program_state.authority) {
+                    return err!(UniversalNFTError::InvalidMintCoupon);
+                }
+                coupon.max_quantity
+            }
+            (None, Some(allowlist_proof)) => {
+                let root = ctx.accounts.collection_config.allowlist_root;
+                if root == [0u8; 32] {
+                    return err!(UniversalNFTError::NotAllowlisted);
+                }
+                if allowlist_proof.proof.len() > MAX_MERKLE_PROOF_DEPTH {
+                    return err!(UniversalNFTError::InvalidProofData);
+                }
+                let leaf = allowlist_leaf(&ctx.accounts.minter.key(), allowlist_proof.max_mints);
+                if !merkle::verify(&allowlist_proof.proof, &root, &leaf) {
+                    return err!(UniversalNFTError::InvalidMerkleProof);
+                }
+                allowlist_proof.max_mints
+            }
+            (None, None) => return err!(UniversalNFTError::NotAllowlisted),
+        };
+        
+        let allowlist_mint_record = ctx.accounts.allowlist_mint_record.as_mut().ok_or(error!(UniversalNFTError::NotAllowlisted))?;
+        allowlist_mint_record.wallet = ctx.accounts.minter.key();
+        allowlist_mint_record.bump = ctx.bumps.allowlist_mint_record;
+        allowlist_mint_record.account_version = ACCOUNT_VERSION;
+        allowlist_mint_record.record(max_mints)?;
     }
     
     // Validate ZetaChain ID
-    if !ctx.accounts.gateway_state.supported_chains.contains(&zeta_chain_id) {
-        return err!(UniversalNFTError::InvalidZetaChainID);
+    if !ctx.accounts.gateway_state.is_chain_supported(ctx.accounts.chain_config.chain_index) {
+        return err!(UniversalNFTError::ChainDisabled);
     }
     
-    // Check max supply
+    // Minted so far in the supply this NFT draws from, for the emission schedule below
+    let minted_before = match ctx.accounts.collection_state.as_ref() {
+        Some(collection_state) => collection_state.total_minted,
+        None => ctx.accounts.program_state.total_minted,
+    };
+    
+    // Check max supply, against the collection when minting into one
     let program_state = &mut ctx.accounts.program_state;
-    if program_state.total_minted >= program_state.max_supply {
-        return err!(UniversalNFTError::MaxSupplyExceeded);
-    }
+    let metadata_uri = match ctx.accounts.collection_state.as_mut() {
+        Some(collection_state) => {
+            if !collection_state.is_chain_allowed(ctx.accounts.chain_config.chain_index) {
+                return err!(UniversalNFTError::ChainNotAllowedForCollection);
+            }
+            if collection_state.asset_backend != AssetBackend::SplToken {
+                return err!(UniversalNFTError::WrongAssetBackend);
+            }
+            collection_state.record_mint()?;
+            collection_state.resolve_uri(&metadata_uri)?
+        }
+        None => {
+            if program_state.total_minted >= program_state.max_supply {
+                return err!(UniversalNFTError::MaxSupplyExceeded);
+            }
+            metadata_uri
+        }
+    };
+    ctx.accounts.collection_config.require_allowed_uri(&metadata_uri)?;
+    ctx.accounts.collection_config.require_uri_length(&metadata_uri)?;
     
-    // Validate cross-chain data length
-    if cross_chain_data.len() > MAX_CROSS_CHAIN_DATA_LENGTH {
-        return err!(UniversalNFTError::InvalidCrossChainData);
-    }
+    // Validate cross-chain payload and display data
+    payload.validate()?;
+    validate_display_data(&name, &symbol, seller_fee_basis_points, &creators)?;
     
     let clock = Clock::get()?;
     
-    // Generate unique token ID: [mint pubkey + block.number + next_token_id]
-    let block_number = clock.slot;
-    let token_id = program_state.next_token_id;
+    // Payloads with an amount above one mint a semi-fungible token instead of a one-of-one NFT
+    let amount = payload.to_latest().amount;
+    if amount == 1 && ctx.accounts.token_balance.is_some() {
+        return err!(UniversalNFTError::InvalidAmount);
+    }
     
-    // Mint 1 token to the mint authority
-    let cpi_accounts = MintTo {
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.mint_ata.to_account_info(),
-        authority: ctx.accounts.mint_authority.to_account_info(),
-    };
+    // Slow-drip drops release supply on a schedule, counted per collection for collection mints
+    if let Some(cap) = ctx.accounts.collection_config.emission_cap(clock.unix_timestamp) {
+        if minted_before >= cap {
+            return err!(UniversalNFTError::EmissionLimitReached);
+        }
+    }
     
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    
-    anchor_spl::token::mint_to(cpi_ctx, 1)?;
-    
-    // Create metadata account
-    let metadata_account = &ctx.accounts.nft_metadata;
-    let metadata_seeds = &[
-        b"metadata",
-        mpl_token_metadata::ID.as_ref(),
-        ctx.accounts.mint.key().as_ref(),
-    ];
-    let metadata_signer = &[&metadata_seeds[..]];
-    
-    let create_metadata_accounts = CreateMetadataAccountsV3 {
-        metadata: metadata_account.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-        mint_authority: ctx.accounts.mint_authority.to_account_info(),
-        payer: ctx.accounts.payer.to_account_info(),
-        update_authority: ctx.accounts.mint_authority.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
-        rent: Some(ctx.accounts.rent.to_account_info()),
-    };
+    // The slot must be recent so token IDs track the block the NFT was minted in
+    if token_id_slot > clock.slot || clock.slot - token_id_slot > MAX_TOKEN_ID_SLOT_AGE {
+        return err!(UniversalNFTError::InvalidTokenIdSlot);
+    }
     
-    let data_v2 = DataV2 {
-        name: DEFAULT_METADATA_NAME.to_string(),
-        symbol: DEFAULT_METADATA_SYMBOL.to_string(),
-        uri: metadata_uri.clone(),
-        seller_fee_basis_points: 0,
-        creators: None,
-        collection: None,
-        uses: None,
+    // Public mints pay the phase's price, or the configured price outside a schedule, into the treasury
+    let mint_price = match phase {
+        Some(phase) => {
+            ctx.accounts.collection_config.record_phase_mint(phase)?;
+            ctx.accounts.collection_config.mint_phases.price(phase)
+        }
+        None => ctx.accounts.collection_config.mint_price,
     };
+    if public_mint && mint_price > 0 {
+        let treasury = ctx.accounts.treasury.as_mut().ok_or(error!(UniversalNFTError::MissingTreasury))?;
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: treasury.to_account_info(),
+                },
+            ),
+            mint_price,
+        )?;
+        treasury.total_collected = treasury.total_collected.saturating_add(mint_price);
+    }
+    
+    // Collections priced in an SPL token are paid for into the treasury's token account, by
+    // public buyers and the collection authority alike
+    let token_price = ctx.accounts.collection_state.as_ref()
+        .filter(|collection_state| collection_state.price_amount > 0)
+        .map(|collection_state| (collection_state.price_mint, collection_state.price_amount));
+    if let Some((price_mint, price_amount)) = token_price {
+        let payment_mint = ctx.accounts.payment_mint.as_ref().ok_or(error!(UniversalNFTError::MissingPaymentAccounts))?;
+        let payer_payment_account = ctx.accounts.payer_payment_account.as_ref().ok_or(error!(UniversalNFTError::MissingPaymentAccounts))?;
+        let treasury_payment_account = ctx.accounts.treasury_payment_account.as_ref().ok_or(error!(UniversalNFTError::MissingPaymentAccounts))?;
+        let payment_token_program = ctx.accounts.payment_token_program.as_ref().ok_or(error!(UniversalNFTError::MissingPaymentAccounts))?;
+        if payment_mint.key() != price_mint
+            || treasury_payment_account.mint != price_mint
+            || treasury_payment_account.owner != find_treasury().0
+        {
+            return err!(UniversalNFTError::InvalidPaymentAccount);
+        }
+        
+        token_interface::transfer_checked(
+            CpiContext::new(
+                payment_token_program.to_account_info(),
+                TransferChecked {
+                    from: payer_payment_account.to_account_info(),
+                    mint: payment_mint.to_account_info(),
+                    to: treasury_payment_account.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            price_amount,
+            payment_mint.decimals,
+        )?;
+    }
     
-    let instruction = mpl_create_metadata(
-        mpl_token_metadata::ID,
-        create_metadata_accounts.metadata.key(),
-        create_metadata_accounts.mint.key(),
-        create_metadata_accounts.mint_authority.key(),
-        create_metadata_accounts.payer.key(),
-        create_metadata_accounts.update_authority.key(),
-        data_v2.name,
-        data_v2.symbol,
-        data_v2.uri,
-        Some(create_metadata_accounts.creators.clone().unwrap_or_default()),
-        data_v2.seller_fee_basis_points,
-        data_v2.uses.clone(),
-        data_v2.collection.clone(),
-        data_v2.is_mutable,
-        data_v2.collection_details.clone(),
-        data_v2.uses.clone(),
+    // Generate unique token ID: keccak(mint pubkey + block.number + next_token_id)
+    let token_id = derive_token_id(&ctx.accounts.mint.key(), token_id_slot, program_state.next_token_id);
+    
+    // Create the Metaplex metadata and master edition so wallets recognize the token as an NFT
+    let data_v2 = build_data_v2(
+        program_state.name_or_default(&name),
+        program_state.symbol_or_default(&symbol),
+        metadata_uri.clone(),
+        seller_fee_basis_points,
+        &creators,
+        &ctx.accounts.mint_authority.key(),
+        ctx.accounts.verified_collection.as_ref().map(|collection| collection.mint),
     );
     
-    let accounts = vec![
-        create_metadata_accounts.metadata.to_account_info(),
-        create_metadata_accounts.mint.to_account_info(),
-        create_metadata_accounts.mint_authority.to_account_info(),
-        create_metadata_accounts.payer.to_account_info(),
-        create_metadata_accounts.update_authority.to_account_info(),
-        create_metadata_accounts.system_program.to_account_info(),
-        create_metadata_accounts.rent.unwrap().to_account_info(),
-    ];
-    
-    solana_program::program::invoke_signed(
-        &instruction,
-        accounts.as_slice(),
-        metadata_signer,
-    )?;
+    let mint_authority_bump = ctx.bumps.mint_authority;
+    let mint_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[mint_authority_bump]];
+    let mint_authority_signer = &[mint_authority_seeds];
+    
+    let rule_set = ctx.accounts.collection_config.rule_set;
+    if amount > 1 && rule_set != Pubkey::default() {
+        return err!(UniversalNFTError::InvalidAmount);
+    }
+    if rule_set != Pubkey::default() {
+        // Programmable NFTs are created and minted through token metadata so the rule set enforces royalties
+        let authorization_rules = ctx.accounts.authorization_rules.as_ref()
+            .ok_or(error!(UniversalNFTError::InvalidRuleSet))?;
+        if authorization_rules.key() != rule_set {
+            return err!(UniversalNFTError::InvalidRuleSet);
+        }
+        let token_record = ctx.accounts.token_record.as_ref()
+            .ok_or(error!(UniversalNFTError::InvalidRuleSet))?;
+        let pnft_accounts = ProgrammableAccounts {
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            master_edition: ctx.accounts.master_edition.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.as_ref()
+                .ok_or(error!(UniversalNFTError::InvalidRuleSet))?
+                .to_account_info(),
+            authorization_rules_program: ctx.accounts.authorization_rules_program.as_ref()
+                .ok_or(error!(UniversalNFTError::InvalidRuleSet))?
+                .to_account_info(),
+            authorization_rules: authorization_rules.to_account_info(),
+        };
+        programmable::create_and_mint(
+            &pnft_accounts,
+            data_v2,
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.mint_ata.to_account_info(),
+            &ctx.accounts.minter.to_account_info(),
+            &token_record.to_account_info(),
+            &ctx.accounts.mint_authority.to_account_info(),
+            &ctx.accounts.mint_authority.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+            mint_authority_signer,
+        )?;
+    } else {
+        // Mint the tokens to the minter, signed by the mint authority PDA
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.mint_ata.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, mint_authority_signer);
+        
+        anchor_spl::token::mint_to(cpi_ctx, amount)?;
+        
+        create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                CreateMetadataAccountsV3 {
+                    metadata: ctx.accounts.metadata.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                    payer: ctx.accounts.payer.to_account_info(),
+                    update_authority: ctx.accounts.mint_authority.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                mint_authority_signer,
+            ),
+            data_v2,
+            true, // is_mutable
+            true, // update_authority_is_signer
+            None,
+        )?;
+        
+        if amount > 1 {
+            // Semi-fungible tokens have no master edition, so the supply is fixed by dropping the authorities
+            for authority_type in [AuthorityType::MintTokens, AuthorityType::FreezeAccount] {
+                token::set_authority(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        SetAuthority {
+                            current_authority: ctx.accounts.mint_authority.to_account_info(),
+                            account_or_mint: ctx.accounts.mint.to_account_info(),
+                        },
+                        mint_authority_signer,
+                    ),
+                    authority_type,
+                    None,
+                )?;
+            }
+            
+            let token_balance = ctx.accounts.token_balance.as_mut().ok_or(error!(UniversalNFTError::MissingTokenBalance))?;
+            token_balance.token_id = token_id;
+            token_balance.chain_id = ZETA_CHAIN_ID_SOLANA;
+            token_balance.amount = amount;
+            token_balance.bump = ctx.bumps.token_balance;
+            token_balance.account_version = ACCOUNT_VERSION;
+        } else {
+            // Max supply 0 makes this a one-of-one; mint and freeze authority move to the edition PDA
+            create_master_edition_v3(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_metadata_program.to_account_info(),
+                    CreateMasterEditionV3 {
+                        edition: ctx.accounts.master_edition.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        update_authority: ctx.accounts.mint_authority.to_account_info(),
+                        mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                        payer: ctx.accounts.payer.to_account_info(),
+                        metadata: ctx.accounts.metadata.to_account_info(),
+                        token_program: ctx.accounts.token_program.to_account_info(),
+                        system_program: ctx.accounts.system_program.to_account_info(),
+                        rent: ctx.accounts.rent.to_account_info(),
+                    },
+                    mint_authority_signer,
+                ),
+                Some(0),
+            )?;
+        }
+    }
     
     // Initialize NFT metadata
     let nft_metadata = &mut ctx.accounts.nft_metadata;
     nft_metadata.mint = ctx.accounts.mint.key();
-    nft_metadata.owner = ctx.accounts.mint_authority.key();
+    nft_metadata.owner = ctx.accounts.minter.key();
     nft_metadata.metadata_uri = metadata_uri.clone();
     nft_metadata.zeta_chain_id = zeta_chain_id;
-    nft_metadata.cross_chain_data_hash = anchor_lang::solana_program::keccak::hash(&cross_chain_data).to_bytes();
+    nft_metadata.cross_chain_data_hash = payload.hash()?;
     nft_metadata.token_id = token_id;
+    nft_metadata.locked = false;
+    nft_metadata.owner_locked = false;
     nft_metadata.created_at = clock.unix_timestamp;
     nft_metadata.updated_at = clock.unix_timestamp;
-    nft_metadata.bump = *ctx.bumps.get("nft_metadata").unwrap();
+    nft_metadata.bump = ctx.bumps.nft_metadata;
+    nft_metadata.account_version = ACCOUNT_VERSION;
+    nft_metadata.collection = ctx.accounts.collection_state.as_ref().map(|collection| collection.key()).unwrap_or_default();
+    nft_metadata.metadata_immutable = false;
+    nft_metadata.metadata_hash = payload.to_latest().metadata_hash;
+    nft_metadata.royalty_basis_points = seller_fee_basis_points;
+    nft_metadata.royalty_recipient = creators.first().map(|creator| creator.address).unwrap_or(ctx.accounts.minter.key());
     
     // Initialize NFT origin tracking
     let nft_origin = &mut ctx.accounts.nft_origin;
@@ -209,19 +571,39 @@ pub fn handler(
     nft_origin.original_metadata_uri = metadata_uri;
     nft_origin.source_chain_id = zeta_chain_id;
     nft_origin.created_at = clock.unix_timestamp;
-    nft_origin.bump = *ctx.bumps.get("nft_origin").unwrap();
+    nft_origin.bump = ctx.bumps.nft_origin;
+    nft_origin.account_version = ACCOUNT_VERSION;
     
     // Update program state
-    program_state.total_minted += 1;
-    program_state.next_token_id += 1;
+    program_state.total_minted = program_state.total_minted.checked_add(1).ok_or(error!(UniversalNFTError::SupplyOverflow))?;
+    program_state.next_token_id = program_state.next_token_id.checked_add(1).ok_or(error!(UniversalNFTError::TokenIdOverflow))?;
     
     msg!("NFT minted successfully");
     msg!("Mint address: {}", ctx.accounts.mint.key());
-    msg!("Owner: {}", ctx.accounts.mint_authority.key());
-    msg!("Token ID: {}", token_id);
+    msg!("Owner: {}", ctx.accounts.minter.key());
+    msg!("Token ID: {:?}", token_id);
     msg!("ZetaChain ID: {}", zeta_chain_id);
     msg!("Total minted: {}", program_state.total_minted);
     msg!("Next token ID: {}", program_state.next_token_id);
     
     Ok(())
 }
+
+/// Allowlist tree leaf: keccak(keccak(wallet || uint32 max_mints LE)), double-hashed so a leaf can
+/// never be read as an inner node
+pub fn allowlist_leaf(wallet: &Pubkey, max_mints: u32) -> [u8; 32] {
+    let inner = keccak::hashv(&[wallet.as_ref(), &max_mints.to_le_bytes()]).to_bytes();
+    keccak::hash(&inner).to_bytes()
+}
+
+/// Message the authority signs for a mint coupon: domain || program_id || wallet || max_quantity || expiry
+pub fn coupon_message(wallet: &Pubkey, coupon: &MintCoupon) -> Vec<u8> {
+    [
+        MINT_COUPON_DOMAIN,
+        crate::ID.as_ref(),
+        wallet.as_ref(),
+        &coupon.max_quantity.to_le_bytes(),
+        &coupon.expiry.to_le_bytes(),
+    ]
+    .concat()
+}
@@ -0,0 +1,349 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken, Create},
+    token_2022::{
+        self,
+        InitializeMint2,
+        MintTo,
+        SetAuthority,
+        Token2022,
+        spl_token_2022::{
+            extension::{metadata_pointer, transfer_hook as transfer_hook_extension, ExtensionType},
+            instruction::{initialize_permanent_delegate, AuthorityType},
+            state::Mint as Token2022Mint,
+        },
+    },
+};
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_token_metadata_interface::state::TokenMetadata;
+
+use crate::{
+    state::{ProgramState, PauseConfig, CollectionConfig, NFTMetadata, NFTOrigin, CollectionState, AssetBackend},
+    errors::UniversalNFTError,
+    metaplex::{validate_display_data, build_data_v2},
+    codec::derive_token_id,
+    transfer_hook::{write_extra_account_metas, EXTRA_ACCOUNT_METAS_LEN},
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(metadata_uri: String, token_id_slot: u64)]
+pub struct MintToken2022NFT<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    #[account(
+        mut,
+        seeds = [COLLECTION_STATE_SEED, &collection_state.collection_id.to_le_bytes()],
+        bump = collection_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+    
+    /// New keypair, created and initialized as a Token-2022 mint with its extensions in the handler
+    #[account(mut)]
+    pub mint: Signer<'info>,
+    
+    /// CHECK: Created as the authority's Token-2022 associated token account in the handler
+    #[account(
+        mut,
+        address = associated_token::get_associated_token_address_with_program_id(
+            &authority.key(),
+            &mint.key(),
+            &token_2022::ID,
+        ) @ UniversalNFTError::InvalidTokenAccount
+    )]
+    pub mint_ata: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = NFTMetadata::space(collection_config.max_uri_length()),
+        seeds = [NFT_METADATA_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = NFTOrigin::space(collection_config.max_uri_length()),
+        seeds = [TOKEN_ID_SEED, derive_token_id(&mint.key(), token_id_slot, program_state.next_token_id).as_ref()],
+        bump
+    )]
+    pub nft_origin: Account<'info, NFTOrigin>,
+    
+    /// CHECK: Transfer hook validation account, written with the hook's extra account metas in the handler
+    #[account(
+        init,
+        payer = authority,
+        space = ExtraAccountMetaList::size_of(EXTRA_ACCOUNT_METAS_LEN).unwrap(),
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA used as mint, freeze and transfer hook authority for program-minted assets
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA that owns escrowed NFTs; set as the mint's permanent delegate
+    #[account(
+        seeds = [ESCROW_AUTHORITY_SEED],
+        bump
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    /// Collection authority, payer and initial holder of the NFT
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<MintToken2022NFT>,
+    metadata_uri: String,
+    token_id_slot: u64,
+    name: String,
+    symbol: String,
+) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_MINT)?;
+    
+    // The collection must mint Token-2022 NFTs
+    if ctx.accounts.collection_state.asset_backend != AssetBackend::Token2022 {
+        return err!(UniversalNFTError::WrongAssetBackend);
+    }
+    
+    // Validate display data
+    validate_display_data(&name, &symbol, 0, &[])?;
+    
+    // Check collection supply and resolve the URI against its base
+    let collection_state = &mut ctx.accounts.collection_state;
+    collection_state.record_mint()?;
+    let metadata_uri = collection_state.resolve_uri(&metadata_uri)?;
+    ctx.accounts.collection_config.require_allowed_uri(&metadata_uri)?;
+    ctx.accounts.collection_config.require_uri_length(&metadata_uri)?;
+    
+    let clock = Clock::get()?;
+    
+    // The slot must be recent so token IDs track the block the NFT was minted in
+    if token_id_slot > clock.slot || clock.slot - token_id_slot > MAX_TOKEN_ID_SLOT_AGE {
+        return err!(UniversalNFTError::InvalidTokenIdSlot);
+    }
+    
+    let program_state = &mut ctx.accounts.program_state;
+    let token_id = derive_token_id(&ctx.accounts.mint.key(), token_id_slot, program_state.next_token_id);
+    
+    let mint_key = ctx.accounts.mint.key();
+    let mint_authority_key = ctx.accounts.mint_authority.key();
+    let token_program_key = ctx.accounts.token_program.key();
+    
+    // Metadata lives in the mint itself, so the account is funded for it up front and
+    // Token-2022 reallocates when it is written
+    let data = build_data_v2(program_state.name_or_default(&name), program_state.symbol_or_default(&symbol), metadata_uri.clone(), 0, &[], &mint_authority_key, None);
+    let token_metadata = TokenMetadata {
+        update_authority: Some(mint_authority_key).try_into()?,
+        mint: mint_key,
+        name: data.name,
+        symbol: data.symbol,
+        uri: data.uri,
+        additional_metadata: Vec::new(),
+    };
+    let mint_space = ExtensionType::try_calculate_account_len::<Token2022Mint>(&[
+        ExtensionType::MetadataPointer,
+        ExtensionType::PermanentDelegate,
+        ExtensionType::TransferHook,
+    ])?;
+    let mint_lamports = Rent::get()?.minimum_balance(mint_space + token_metadata.tlv_size_of()?);
+    system_program::create_account(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        mint_lamports,
+        mint_space as u64,
+        &token_program_key,
+    )?;
+    
+    // The escrow PDA can always move or burn the NFT, so bridged tokens can be reclaimed
+    let permanent_delegate_ix = initialize_permanent_delegate(
+        &token_program_key,
+        &mint_key,
+        &ctx.accounts.escrow_authority.key(),
+    )?;
+    solana_program::program::invoke(
+        &permanent_delegate_ix,
+        &[ctx.accounts.mint.to_account_info()],
+    )?;
+    
+    // Point wallets at the metadata stored in the mint
+    let metadata_pointer_ix = metadata_pointer::instruction::initialize(
+        &token_program_key,
+        &mint_key,
+        Some(mint_authority_key),
+        Some(mint_key),
+    )?;
+    solana_program::program::invoke(
+        &metadata_pointer_ix,
+        &[ctx.accounts.mint.to_account_info()],
+    )?;
+    
+    // Run this program on every transfer so NFTMetadata.owner follows the token
+    let transfer_hook_ix = transfer_hook_extension::instruction::initialize(
+        &token_program_key,
+        &mint_key,
+        Some(mint_authority_key),
+        Some(crate::ID),
+    )?;
+    solana_program::program::invoke(
+        &transfer_hook_ix,
+        &[ctx.accounts.mint.to_account_info()],
+    )?;
+    write_extra_account_metas(&ctx.accounts.extra_account_meta_list.to_account_info())?;
+    
+    token_2022::initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        SOLANA_DECIMALS,
+        &mint_authority_key,
+        Some(&mint_authority_key),
+    )?;
+    
+    let mint_authority_bump = ctx.bumps.mint_authority;
+    let mint_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[mint_authority_bump]];
+    let mint_authority_signer = &[mint_authority_seeds];
+    
+    let initialize_metadata_ix = spl_token_metadata_interface::instruction::initialize(
+        &token_program_key,
+        &mint_key,
+        &mint_authority_key,
+        &mint_key,
+        &mint_authority_key,
+        token_metadata.name.clone(),
+        token_metadata.symbol.clone(),
+        token_metadata.uri.clone(),
+    );
+    solana_program::program::invoke_signed(
+        &initialize_metadata_ix,
+        &[
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.mint_authority.to_account_info(),
+        ],
+        mint_authority_signer,
+    )?;
+    
+    // Create the authority's token account and mint the NFT
+    associated_token::create(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        Create {
+            payer: ctx.accounts.authority.to_account_info(),
+            associated_token: ctx.accounts.mint_ata.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    ))?;
+    
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.mint_ata.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            mint_authority_signer,
+        ),
+        1,
+    )?;
+    
+    // Drop the mint authority so no second token can ever be minted
+    token_2022::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.mint_authority.to_account_info(),
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+            },
+            mint_authority_signer,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+    
+    // Initialize NFT metadata
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.mint = mint_key;
+    nft_metadata.owner = ctx.accounts.authority.key();
+    nft_metadata.metadata_uri = metadata_uri.clone();
+    nft_metadata.zeta_chain_id = ZETA_CHAIN_ID_SOLANA;
+    nft_metadata.cross_chain_data_hash = [0u8; 32]; // No payload is attached at mint time
+    nft_metadata.token_id = token_id;
+    nft_metadata.locked = false;
+    nft_metadata.owner_locked = false;
+    nft_metadata.created_at = clock.unix_timestamp;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    nft_metadata.bump = ctx.bumps.nft_metadata;
+    nft_metadata.account_version = ACCOUNT_VERSION;
+    nft_metadata.collection = ctx.accounts.collection_state.key();
+    nft_metadata.metadata_immutable = false;
+    nft_metadata.metadata_hash = [0u8; 32];
+    nft_metadata.royalty_basis_points = 0;
+    nft_metadata.royalty_recipient = Pubkey::default();
+    
+    // Initialize NFT origin tracking
+    let nft_origin = &mut ctx.accounts.nft_origin;
+    nft_origin.token_id = token_id;
+    nft_origin.original_mint = mint_key;
+    nft_origin.original_metadata_uri = metadata_uri;
+    nft_origin.source_chain_id = ZETA_CHAIN_ID_SOLANA;
+    nft_origin.created_at = clock.unix_timestamp;
+    nft_origin.bump = ctx.bumps.nft_origin;
+    nft_origin.account_version = ACCOUNT_VERSION;
+    
+    // Update program state
+    program_state.total_minted = program_state.total_minted.checked_add(1).ok_or(error!(UniversalNFTError::SupplyOverflow))?;
+    program_state.next_token_id = program_state.next_token_id.checked_add(1).ok_or(error!(UniversalNFTError::TokenIdOverflow))?;
+    
+    msg!("Token-2022 NFT minted successfully");
+    msg!("Mint address: {}", mint_key);
+    msg!("Owner: {}", ctx.accounts.authority.key());
+    msg!("Token ID: {:?}", token_id);
+    msg!("Collection: {}", ctx.accounts.collection_state.collection_id);
+    
+    Ok(())
+}
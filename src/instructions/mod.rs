@@ -1,3 +1,6 @@
+// Every instruction module names its entry point `handler`; lib.rs calls them by module path
+#![allow(ambiguous_glob_reexports)]
+
 pub mod initialize;
 pub mod mint_nft;
 pub mod transfer_nft;
@@ -7,6 +10,99 @@ pub mod update_metadata;
 pub mod burn_nft;
 pub mod setup_gateway;
 pub mod process_incoming_nft;
+pub mod revert_cross_chain_transfer;
+pub mod rebuild_registry_entry;
+pub mod cancel_cross_chain_transfer;
+pub mod set_transfer_timeout;
+pub mod set_feature_flags;
+pub mod confirm_outbound_transfer;
+pub mod register_notification;
+pub mod unregister_notification;
+pub mod process_incoming_attestation;
+pub mod bind_identity;
+pub mod unbind_identity;
+pub mod migrate_token_id;
+pub mod configure_rewards;
+pub mod open_points_ledger;
+pub mod redeem_points;
+pub mod simulate_migration;
+pub mod process_returning_nft;
+pub mod set_next_gateway;
+pub mod promote_next_gateway;
+pub mod register_chain;
+pub mod update_chain;
+pub mod remove_chain;
+pub mod quote_cross_chain_fee;
+pub mod set_royalty_policy;
+pub mod set_message_expiry_window;
+pub mod set_emission_schedule;
+pub mod faucet_mint;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod migrate_chain_index;
+pub mod set_authority_program;
+pub mod pause;
+pub mod unpause;
+pub mod set_paused_operations;
+pub mod set_chain_enabled;
+pub mod set_rate_limit;
+pub mod set_velocity_limit;
+pub mod resolve_stuck_transfer;
+pub mod rotate_tss_address;
+pub mod set_observers;
+pub mod publish_ownership_root;
+pub mod get_cross_chain_owner;
+pub mod set_watcher;
+pub mod freeze_asset;
+pub mod unfreeze_asset;
+pub mod update_denylist;
+pub mod add_to_allowlist;
+pub mod remove_from_allowlist;
+pub mod set_challenge_period;
+pub mod finalize_incoming_nft;
+pub mod dispute_incoming_nft;
+pub mod clear_delivery_dispute;
+pub mod submit_fraud_proof;
+pub mod lock_nft;
+pub mod unlock_nft;
+pub mod migrate_account;
+pub mod create_collection;
+pub mod verify_collection_item;
+pub mod register_collection;
+pub mod update_collection;
+pub mod set_rule_set;
+pub mod process_incoming_core_nft;
+pub mod mint_token_2022_nft;
+pub mod execute_transfer_hook;
+pub mod create_collection_tree;
+pub mod process_incoming_compressed_nft;
+pub mod lock_metadata;
+pub mod set_uri_schemes;
+pub mod set_max_uri_length;
+pub mod set_collection_branding;
+pub mod set_mint_price;
+pub mod withdraw_treasury;
+pub mod set_collection_price;
+pub mod withdraw_treasury_tokens;
+pub mod set_allowlist_root;
+pub mod set_mint_phases;
+pub mod batch_mint;
+pub mod create_airdrop;
+pub mod claim_airdrop;
+pub mod approve;
+pub mod set_approval_for_all;
+pub mod set_program_delegation;
+pub mod transfer_from;
+pub mod permit_transfer;
+pub mod sell_nft;
+pub mod close_orphaned_metadata;
+pub mod process_remote_burn;
+pub mod wrap_external_nft;
+pub mod unwrap_external_nft;
+pub mod process_incoming_batch;
+pub mod claim_incoming_nft;
+pub mod sweep_unclaimed_nft;
+pub mod revoke_mint_authority;
 
 pub use initialize::*;
 pub use mint_nft::*;
@@ -17,3 +113,96 @@ pub use update_metadata::*;
 pub use burn_nft::*;
 pub use setup_gateway::*;
 pub use process_incoming_nft::*;
+pub use revert_cross_chain_transfer::*;
+pub use rebuild_registry_entry::*;
+pub use cancel_cross_chain_transfer::*;
+pub use set_transfer_timeout::*;
+pub use set_feature_flags::*;
+pub use confirm_outbound_transfer::*;
+pub use register_notification::*;
+pub use unregister_notification::*;
+pub use process_incoming_attestation::*;
+pub use bind_identity::*;
+pub use unbind_identity::*;
+pub use migrate_token_id::*;
+pub use configure_rewards::*;
+pub use open_points_ledger::*;
+pub use redeem_points::*;
+pub use simulate_migration::*;
+pub use process_returning_nft::*;
+pub use set_next_gateway::*;
+pub use promote_next_gateway::*;
+pub use register_chain::*;
+pub use update_chain::*;
+pub use remove_chain::*;
+pub use quote_cross_chain_fee::*;
+pub use set_royalty_policy::*;
+pub use set_message_expiry_window::*;
+pub use set_emission_schedule::*;
+pub use faucet_mint::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use migrate_chain_index::*;
+pub use set_authority_program::*;
+pub use pause::*;
+pub use unpause::*;
+pub use set_paused_operations::*;
+pub use set_chain_enabled::*;
+pub use set_rate_limit::*;
+pub use set_velocity_limit::*;
+pub use resolve_stuck_transfer::*;
+pub use rotate_tss_address::*;
+pub use set_observers::*;
+pub use publish_ownership_root::*;
+pub use get_cross_chain_owner::*;
+pub use set_watcher::*;
+pub use freeze_asset::*;
+pub use unfreeze_asset::*;
+pub use update_denylist::*;
+pub use add_to_allowlist::*;
+pub use remove_from_allowlist::*;
+pub use set_challenge_period::*;
+pub use finalize_incoming_nft::*;
+pub use dispute_incoming_nft::*;
+pub use clear_delivery_dispute::*;
+pub use submit_fraud_proof::*;
+pub use lock_nft::*;
+pub use unlock_nft::*;
+pub use migrate_account::*;
+pub use create_collection::*;
+pub use verify_collection_item::*;
+pub use register_collection::*;
+pub use update_collection::*;
+pub use set_rule_set::*;
+pub use process_incoming_core_nft::*;
+pub use mint_token_2022_nft::*;
+pub use execute_transfer_hook::*;
+pub use create_collection_tree::*;
+pub use process_incoming_compressed_nft::*;
+pub use lock_metadata::*;
+pub use set_uri_schemes::*;
+pub use set_max_uri_length::*;
+pub use set_collection_branding::*;
+pub use set_mint_price::*;
+pub use withdraw_treasury::*;
+pub use set_collection_price::*;
+pub use withdraw_treasury_tokens::*;
+pub use set_allowlist_root::*;
+pub use set_mint_phases::*;
+pub use batch_mint::*;
+pub use create_airdrop::*;
+pub use claim_airdrop::*;
+pub use approve::*;
+pub use set_approval_for_all::*;
+pub use set_program_delegation::*;
+pub use transfer_from::*;
+pub use permit_transfer::*;
+pub use sell_nft::*;
+pub use close_orphaned_metadata::*;
+pub use process_remote_burn::*;
+pub use wrap_external_nft::*;
+pub use unwrap_external_nft::*;
+pub use process_incoming_batch::*;
+pub use claim_incoming_nft::*;
+pub use sweep_unclaimed_nft::*;
+pub use revoke_mint_authority::*;
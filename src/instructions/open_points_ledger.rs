@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::PointsLedger,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct OpenPointsLedger<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = PointsLedger::LEN,
+        seeds = [POINTS_SEED, wallet.key().as_ref()],
+        bump
+    )]
+    pub points_ledger: Account<'info, PointsLedger>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub wallet: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenPointsLedger>) -> Result<()> {
+    let points_ledger = &mut ctx.accounts.points_ledger;
+    points_ledger.wallet = ctx.accounts.wallet.key();
+    points_ledger.balance = 0;
+    points_ledger.lifetime_earned = 0;
+    points_ledger.round_trips = 0;
+    points_ledger.last_credited_at = 0;
+    points_ledger.bump = ctx.bumps.points_ledger;
+    points_ledger.account_version = ACCOUNT_VERSION;
+    
+    msg!("Points ledger opened");
+    msg!("Wallet: {}", ctx.accounts.wallet.key());
+    
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::ProgramState,
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<Pause>) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let clock = Clock::get()?;
+    
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.paused = true;
+    
+    msg!("Program paused");
+    msg!("Authority: {}", program_state.authority);
+    msg!("Paused at: {}", clock.unix_timestamp);
+    
+    Ok(())
+}
@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::{ProgramState, PauseConfig, Denylist, NFTMetadata, CollectionConfig, RoyaltyPolicy, PermitNonce, TransferHistory, TransferRecord, TransferKind},
+    errors::UniversalNFTError,
+    sigverify::ed25519_verified_signers,
+    transfer_hook,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(new_owner_key: Pubkey, nonce: u64, expiry: i64)]
+pub struct PermitTransfer<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+    
+    #[account(
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump,
+        has_one = owner @ UniversalNFTError::NotNFTOwner
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        mut,
+        constraint = nft_mint.key() == nft_metadata.mint @ UniversalNFTError::MintMismatch
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+        constraint = owner_token_account.amount == 1 @ UniversalNFTError::TokenNotHeld,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = new_owner,
+        associated_token::token_program = token_program,
+    )]
+    pub new_owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Current NFT owner, checked against the NFT metadata; authorizes the transfer with an off-chain signature
+    pub owner: UncheckedAccount<'info>,
+    
+    /// CHECK: Recipient wallet named in the permit; only used to derive its token account
+    #[account(address = new_owner_key @ UniversalNFTError::InvalidRecipientAddress)]
+    pub new_owner: UncheckedAccount<'info>,
+    
+    /// Latest ownership changes of the NFT
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = TransferHistory::LEN,
+        seeds = [TRANSFER_HISTORY_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_history: Account<'info, TransferHistory>,
+    
+    /// Submits the permit and pays the fees and the recipient's token account
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = PermitNonce::LEN,
+        seeds = [PERMIT_NONCE_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub permit_nonce: Account<'info, PermitNonce>,
+    
+    /// CHECK: PDA the owner's token account is delegated to by `set_program_delegation` or `approve`
+    #[account(
+        seeds = [TRANSFER_AUTHORITY_SEED],
+        bump
+    )]
+    pub transfer_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Instructions sysvar, read to find the ed25519 program instruction verifying the permit
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// CHECK: Transfer hook authority PDA, required for Token-2022 NFTs that use this program's hook
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: Option<UncheckedAccount<'info>>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<PermitTransfer>,
+    new_owner_key: Pubkey,
+    nonce: u64,
+    expiry: i64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.program_state.require_feature(FEATURE_MARKETPLACE)?;
+    ctx.accounts.pause_config.require_active(PAUSE_TRANSFER)?;
+    ctx.accounts.denylist.require_allowed(ctx.accounts.new_owner.key().as_ref())?;
+    ctx.accounts.nft_metadata.require_unlocked()?;
+    
+    // A plain SPL transfer pays no royalties; enforced collections must use the royalty-enforcing path
+    if ctx.accounts.collection_config.royalty_policy == RoyaltyPolicy::Enforced {
+        return err!(UniversalNFTError::RoyaltyEnforced);
+    }
+    
+    // The permit only authorizes the transfer; the program still needs the owner's one-time on-chain delegation to move the token
+    let transfer_authority = ctx.accounts.transfer_authority.key();
+    if Option::<Pubkey>::from(ctx.accounts.owner_token_account.delegate) != Some(transfer_authority)
+        || ctx.accounts.owner_token_account.delegated_amount < 1
+    {
+        return err!(UniversalNFTError::NotDelegatedToProgram);
+    }
+    
+    let clock = Clock::get()?;
+    
+    // The owner signs the permit off-chain; a preceding ed25519 program instruction verifies it
+    let owner = ctx.accounts.owner.key();
+    if expiry < clock.unix_timestamp || nonce != ctx.accounts.permit_nonce.nonce {
+        return err!(UniversalNFTError::InvalidPermit);
+    }
+    let message = permit_message(&ctx.accounts.nft_mint.key(), &new_owner_key, nonce, expiry);
+    let signers = ed25519_verified_signers(&ctx.accounts.instructions.to_account_info(), &message)?;
+    if !signers.contains(&owner) {
+        return err!(UniversalNFTError::InvalidPermit);
+    }
+    
+    // Using the nonce invalidates every other permit signed with it
+    let permit_nonce = &mut ctx.accounts.permit_nonce;
+    permit_nonce.owner = owner;
+    permit_nonce.nonce = nonce.checked_add(1).ok_or(error!(UniversalNFTError::InvalidPermit))?;
+    permit_nonce.bump = ctx.bumps.permit_nonce;
+    permit_nonce.account_version = ACCOUNT_VERSION;
+    
+    // Transfer NFT from current owner to new owner, signed by the delegate PDA
+    let transfer_authority_bump = ctx.bumps.transfer_authority;
+    let transfer_authority_seeds: &[&[u8]] = &[TRANSFER_AUTHORITY_SEED, &[transfer_authority_bump]];
+    transfer_hook::transfer_nft(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.owner_token_account.to_account_info(),
+        &ctx.accounts.nft_mint.to_account_info(),
+        &ctx.accounts.new_owner_token_account.to_account_info(),
+        &ctx.accounts.transfer_authority.to_account_info(),
+        ctx.accounts.mint_authority.as_ref().map(|a| a.to_account_info()),
+        &[transfer_authority_seeds],
+    )?;
+    
+    // Record the ownership change for activity feeds
+    let transfer_history = &mut ctx.accounts.transfer_history;
+    transfer_history.mint = ctx.accounts.nft_mint.key();
+    transfer_history.bump = ctx.bumps.transfer_history;
+    transfer_history.account_version = ACCOUNT_VERSION;
+    transfer_history.push(TransferRecord {
+        owner: new_owner_key,
+        timestamp: clock.unix_timestamp,
+        kind: TransferKind::Local,
+    });
+    
+    // Update NFT metadata
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.owner = new_owner_key;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    
+    msg!("NFT transferred by permit");
+    msg!("From: {}", owner);
+    msg!("To: {}", new_owner_key);
+    msg!("Relayer: {}", ctx.accounts.relayer.key());
+    msg!("NFT: {}", ctx.accounts.nft_mint.key());
+    
+    Ok(())
+}
+
+/// Message the owner signs for a permit: domain || program_id || mint || recipient || nonce || expiry
+pub fn permit_message(mint: &Pubkey, recipient: &Pubkey, nonce: u64, expiry: i64) -> Vec<u8> {
+    [
+        PERMIT_DOMAIN,
+        crate::ID.as_ref(),
+        mint.as_ref(),
+        recipient.as_ref(),
+        &nonce.to_le_bytes(),
+        &expiry.to_le_bytes(),
+    ]
+    .concat()
+}
@@ -0,0 +1,277 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken, Create},
+    token_2022::{
+        self,
+        InitializeMint2,
+        MintTo,
+        SetAuthority,
+        Token2022,
+        spl_token_2022::{
+            extension::ExtensionType,
+            instruction::{initialize_non_transferable_mint, AuthorityType},
+            state::Mint as Token2022Mint,
+        },
+    },
+};
+
+use crate::{
+    state::{ProgramState, PauseConfig, Denylist, RateLimiter, ZetaChainGatewayState, ChainConfig, AttestationRecord, ObserverSignature},
+    errors::UniversalNFTError,
+    events::AttestationImported,
+    codec::decode_nft_message,
+    payload::AttestationPayload,
+    sigverify::secp256k1_verified_addresses,
+    instructions::process_incoming_nft::incoming_message_digest,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(source_chain_id: u64)]
+pub struct ProcessIncomingAttestation<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+    
+    #[account(
+        mut,
+        seeds = [RATE_LIMITER_SEED],
+        bump = rate_limiter.bump
+    )]
+    pub rate_limiter: Account<'info, RateLimiter>,
+    
+    #[account(
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    #[account(
+        seeds = [CHAIN_CONFIG_SEED, &source_chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    /// New keypair, created and initialized as a non-transferable Token-2022 mint in the handler
+    #[account(mut)]
+    pub attestation_mint: Signer<'info>,
+    
+    /// CHECK: Created as the recipient's Token-2022 associated token account in the handler
+    #[account(
+        mut,
+        address = associated_token::get_associated_token_address_with_program_id(
+            &recipient.key(),
+            &attestation_mint.key(),
+            &token_2022::ID,
+        ) @ UniversalNFTError::InvalidTokenAccount
+    )]
+    pub recipient_token_account: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = gateway_authority,
+        space = AttestationRecord::LEN,
+        seeds = [ATTESTATION_SEED, attestation_mint.key().as_ref()],
+        bump
+    )]
+    pub attestation_record: Account<'info, AttestationRecord>,
+    
+    /// CHECK: Validated against the receiver in the decoded message
+    pub recipient: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA used as mint and freeze authority for program-minted assets
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = gateway_state.is_gateway_authority(&gateway_authority.key(), Clock::get()?.unix_timestamp) @ UniversalNFTError::Unauthorized
+    )]
+    pub gateway_authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected for secp256k1 precompile signatures from observers
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<ProcessIncomingAttestation>,
+    source_chain_id: u64,
+    payload: AttestationPayload,
+    zeta_tx_hash: [u8; 32],
+    observer_signatures: Vec<ObserverSignature>,
+) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_INBOUND)?;
+    
+    // Gateway must be configured and recently maintained before messages can flow
+    ctx.accounts.gateway_state.require_live(Clock::get()?.unix_timestamp)?;
+    
+    // Validate source chain ID
+    if !ctx.accounts.gateway_state.is_chain_supported(ctx.accounts.chain_config.chain_index) {
+        return err!(UniversalNFTError::ChainDisabled);
+    }
+    
+    // Cannot process from the same chain
+    if source_chain_id == ZETA_CHAIN_ID_SOLANA {
+        return err!(UniversalNFTError::InvalidZetaChainID);
+    }
+    
+    let clock = Clock::get()?;
+    payload.require_fresh(clock.unix_timestamp, ctx.accounts.gateway_state.message_expiry_window)?;
+    
+    // The observer set must have signed this exact message, inline or through the secp256k1 precompile
+    let digest = incoming_message_digest(source_chain_id, &zeta_tx_hash, &payload.hash());
+    let precompile_addresses = secp256k1_verified_addresses(&ctx.accounts.instructions, &digest)?;
+    ctx.accounts.gateway_state.verify_observer_signatures(&digest, &observer_signatures, &precompile_addresses)?;
+    
+    // Bound what a compromised gateway key can deliver; inbound messages carry no lamports
+    ctx.accounts.rate_limiter.record(true, clock.unix_timestamp, 0)?;
+    
+    // Decode the attestation message (issuer is carried in the sender field)
+    let message = decode_nft_message(&payload.message)?;
+    if message.receiver != ctx.accounts.recipient.key().to_bytes().to_vec() {
+        return err!(UniversalNFTError::RecipientMismatch);
+    }
+    if message.sender.is_empty() || message.sender.len() > MAX_RECIPIENT_ADDRESS_LENGTH {
+        return err!(UniversalNFTError::InvalidCrossChainData);
+    }
+    ctx.accounts.denylist.require_allowed(ctx.accounts.recipient.key().as_ref())?;
+    
+    // Create the mint account sized for the NonTransferable extension
+    let mint_space = ExtensionType::try_calculate_account_len::<Token2022Mint>(&[ExtensionType::NonTransferable])?;
+    let mint_lamports = Rent::get()?.minimum_balance(mint_space);
+    system_program::create_account(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.gateway_authority.to_account_info(),
+                to: ctx.accounts.attestation_mint.to_account_info(),
+            },
+        ),
+        mint_lamports,
+        mint_space as u64,
+        &ctx.accounts.token_program.key(),
+    )?;
+    
+    // Make the token soulbound before initializing the mint
+    let non_transferable_ix = initialize_non_transferable_mint(
+        &ctx.accounts.token_program.key(),
+        &ctx.accounts.attestation_mint.key(),
+    )?;
+    solana_program::program::invoke(
+        &non_transferable_ix,
+        &[ctx.accounts.attestation_mint.to_account_info()],
+    )?;
+    
+    let mint_authority_key = ctx.accounts.mint_authority.key();
+    token_2022::initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.attestation_mint.to_account_info(),
+            },
+        ),
+        SOLANA_DECIMALS,
+        &mint_authority_key,
+        Some(&mint_authority_key),
+    )?;
+    
+    // Create the recipient's token account and mint the attestation
+    associated_token::create(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        Create {
+            payer: ctx.accounts.gateway_authority.to_account_info(),
+            associated_token: ctx.accounts.recipient_token_account.to_account_info(),
+            authority: ctx.accounts.recipient.to_account_info(),
+            mint: ctx.accounts.attestation_mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    ))?;
+    
+    let mint_authority_bump = ctx.bumps.mint_authority;
+    let mint_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[mint_authority_bump]];
+    let mint_authority_signer = &[mint_authority_seeds];
+    
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.attestation_mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            mint_authority_signer,
+        ),
+        1,
+    )?;
+    
+    // Drop the mint authority so no second token can ever be minted
+    token_2022::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.mint_authority.to_account_info(),
+                account_or_mint: ctx.accounts.attestation_mint.to_account_info(),
+            },
+            mint_authority_signer,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+    
+    // Record the attestation
+    let attestation_record = &mut ctx.accounts.attestation_record;
+    attestation_record.mint = ctx.accounts.attestation_mint.key();
+    attestation_record.recipient = ctx.accounts.recipient.key();
+    attestation_record.issuer = message.sender.clone();
+    attestation_record.token_id = message.token_id;
+    attestation_record.source_chain_id = source_chain_id;
+    attestation_record.metadata_uri = message.uri;
+    attestation_record.zeta_tx_hash = zeta_tx_hash;
+    attestation_record.created_at = clock.unix_timestamp;
+    attestation_record.bump = ctx.bumps.attestation_record;
+    attestation_record.account_version = ACCOUNT_VERSION;
+    
+    emit!(AttestationImported {
+        mint: attestation_record.mint,
+        recipient: attestation_record.recipient,
+        issuer: message.sender,
+        token_id: message.token_id,
+        source_chain_id,
+        zeta_tx_hash,
+    });
+    
+    msg!("Soulbound attestation imported");
+    msg!("Mint address: {}", attestation_record.mint);
+    msg!("Recipient: {}", attestation_record.recipient);
+    msg!("Token ID: {:?}", message.token_id);
+    msg!("Source chain: {}", source_chain_id);
+    
+    Ok(())
+}
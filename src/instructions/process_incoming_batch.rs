@@ -0,0 +1,407 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::{
+    associated_token::{self, get_associated_token_address, AssociatedToken, Create},
+    token::{self, spl_token, InitializeMint2, MintTo, Token},
+    metadata::{
+        create_master_edition_v3,
+        create_metadata_accounts_v3,
+        CreateMasterEditionV3,
+        CreateMetadataAccountsV3,
+        Metadata,
+    },
+};
+
+use crate::{
+    state::{ProgramState, PauseConfig, CollectionConfig, Denylist, RateLimiter, NFTMetadata, NFTOrigin, ZetaChainGatewayState, ChainConfig, ObserverSignature, IncomingBatch, Provenance, ProvenanceHop},
+    errors::UniversalNFTError,
+    events::IncomingBatchProcessed,
+    payload::IncomingBatchPayload,
+    metaplex::build_data_v2,
+    sigverify::secp256k1_verified_addresses,
+    instructions::{batch_mint::create_record, process_incoming_nft::incoming_message_digest},
+    constants::*,
+    seeds::*,
+};
+
+/// Remaining accounts per item: mint, recipient, recipient token account, NFT metadata,
+/// NFT origin, Metaplex metadata, master edition, provenance
+const ACCOUNTS_PER_ITEM: usize = 8;
+
+#[derive(Accounts)]
+#[instruction(source_chain_id: u64, payload: IncomingBatchPayload)]
+pub struct ProcessIncomingBatch<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    #[account(
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+    
+    #[account(
+        mut,
+        seeds = [RATE_LIMITER_SEED],
+        bump = rate_limiter.bump
+    )]
+    pub rate_limiter: Account<'info, RateLimiter>,
+    
+    #[account(
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    #[account(
+        seeds = [CHAIN_CONFIG_SEED, &source_chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = IncomingBatch::LEN,
+        seeds = [INCOMING_BATCH_SEED, payload.hash().as_ref()],
+        bump
+    )]
+    pub incoming_batch: Account<'info, IncomingBatch>,
+    
+    /// CHECK: PDA set as the Metaplex update authority, so the program can keep on-chain metadata in sync
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub update_authority: UncheckedAccount<'info>,
+    
+    /// Relayer paying for every account; mint authority of the new mints until their master editions take over
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected for secp256k1 precompile signatures from observers
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ProcessIncomingBatch<'info>>,
+    source_chain_id: u64,
+    payload: IncomingBatchPayload,
+    zeta_tx_hash: [u8; 32],
+    observer_signatures: Vec<ObserverSignature>,
+) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_INBOUND)?;
+    
+    // Gateway must be configured and recently maintained before messages can flow
+    ctx.accounts.gateway_state.require_live(Clock::get()?.unix_timestamp)?;
+    
+    // Validate source chain ID
+    if !ctx.accounts.gateway_state.is_chain_supported(ctx.accounts.chain_config.chain_index) {
+        return err!(UniversalNFTError::ChainDisabled);
+    }
+    if source_chain_id == ZETA_CHAIN_ID_SOLANA {
+        return err!(UniversalNFTError::InvalidZetaChainID);
+    }
+    
+    // Batches mint straight to their recipients, so they cannot wait out a challenge period
+    if ctx.accounts.gateway_state.challenge_period > 0 {
+        return err!(UniversalNFTError::ChallengePeriodActive);
+    }
+    
+    payload.validate()?;
+    
+    let clock = Clock::get()?;
+    let payload_hash = payload.hash();
+    
+    // The observers are checked once, when the session is opened; later calls are bound to it by the payload hash
+    let incoming_batch = &mut ctx.accounts.incoming_batch;
+    if incoming_batch.total_items == 0 {
+        payload.require_fresh(clock.unix_timestamp, ctx.accounts.gateway_state.message_expiry_window)?;
+        
+        let digest = incoming_message_digest(source_chain_id, &zeta_tx_hash, &payload_hash);
+        let precompile_addresses = secp256k1_verified_addresses(&ctx.accounts.instructions, &digest)?;
+        ctx.accounts.gateway_state.verify_observer_signatures(&digest, &observer_signatures, &precompile_addresses)?;
+        
+        incoming_batch.payload_hash = payload_hash;
+        incoming_batch.source_chain_id = source_chain_id;
+        incoming_batch.zeta_tx_hash = zeta_tx_hash;
+        incoming_batch.total_items = payload.items.len() as u16;
+        incoming_batch.processed_items = 0;
+        incoming_batch.created_at = clock.unix_timestamp;
+        incoming_batch.bump = ctx.bumps.incoming_batch;
+        incoming_batch.account_version = ACCOUNT_VERSION;
+    } else if incoming_batch.source_chain_id != source_chain_id || incoming_batch.zeta_tx_hash != zeta_tx_hash {
+        return err!(UniversalNFTError::InvalidCrossChainData);
+    }
+    if incoming_batch.is_complete() {
+        return err!(UniversalNFTError::BatchAlreadyProcessed);
+    }
+    
+    // This call delivers the next items, as many as there are account groups
+    let start = incoming_batch.processed_items as usize;
+    let count = ctx.remaining_accounts.len() / ACCOUNTS_PER_ITEM;
+    if count == 0 || count * ACCOUNTS_PER_ITEM != ctx.remaining_accounts.len() || start + count > payload.items.len() {
+        return err!(UniversalNFTError::InvalidBatchAccounts);
+    }
+    
+    let update_authority_bump = ctx.bumps.update_authority;
+    let update_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[update_authority_bump]];
+    let update_authority_signer = &[update_authority_seeds];
+    
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let token_metadata_program = ctx.accounts.token_metadata_program.to_account_info();
+    let update_authority = ctx.accounts.update_authority.to_account_info();
+    let rent = ctx.accounts.rent.to_account_info();
+    let max_uri_length = ctx.accounts.collection_config.max_uri_length();
+    
+    for (item, accounts) in payload.items[start..start + count].iter().zip(ctx.remaining_accounts.chunks(ACCOUNTS_PER_ITEM)) {
+        let [mint, recipient, recipient_token_account, nft_metadata_info, nft_origin_info, metadata, master_edition, provenance_info] = accounts else {
+            return err!(UniversalNFTError::InvalidBatchAccounts);
+        };
+        
+        // Validate the item against its accounts and the collection policy
+        if recipient.key() != item.recipient || recipient_token_account.key() != get_associated_token_address(&item.recipient, &mint.key()) {
+            return err!(UniversalNFTError::RecipientMismatch);
+        }
+        ctx.accounts.denylist.require_allowed(item.recipient.as_ref())?;
+        ctx.accounts.collection_config.require_allowed_uri(&item.uri)?;
+        ctx.accounts.collection_config.require_uri_length(&item.uri)?;
+        ctx.accounts.rate_limiter.record(true, clock.unix_timestamp, 0)?;
+        
+        // Each mint is a fresh keypair signing the transaction, and the token ID must be new to Solana
+        if !mint.is_signer {
+            return err!(UniversalNFTError::InvalidBatchAccounts);
+        }
+        let (nft_metadata_key, nft_metadata_bump) = find_nft_metadata(&mint.key());
+        let (nft_origin_key, nft_origin_bump) = find_nft_origin(&item.token_id);
+        let (provenance_key, provenance_bump) = find_provenance(&item.token_id);
+        if nft_metadata_info.key() != nft_metadata_key || nft_origin_info.key() != nft_origin_key || provenance_info.key() != provenance_key {
+            return err!(UniversalNFTError::InvalidBatchAccounts);
+        }
+        if !nft_origin_info.data_is_empty() {
+            return err!(UniversalNFTError::InvalidTokenId);
+        }
+        
+        // Create the mint and the recipient's token account, then mint the single token
+        system_program::create_account(
+            CpiContext::new(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: mint.clone(),
+                },
+            ),
+            Rent::get()?.minimum_balance(spl_token::state::Mint::LEN),
+            spl_token::state::Mint::LEN as u64,
+            &token_program.key(),
+        )?;
+        token::initialize_mint2(
+            CpiContext::new(token_program.clone(), InitializeMint2 { mint: mint.clone() }),
+            SOLANA_DECIMALS,
+            &payer.key(),
+            Some(&payer.key()),
+        )?;
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: payer.clone(),
+                associated_token: recipient_token_account.clone(),
+                authority: recipient.clone(),
+                mint: mint.clone(),
+                system_program: system_program.clone(),
+                token_program: token_program.clone(),
+            },
+        ))?;
+        token::mint_to(
+            CpiContext::new(
+                token_program.clone(),
+                MintTo {
+                    mint: mint.clone(),
+                    to: recipient_token_account.clone(),
+                    authority: payer.clone(),
+                },
+            ),
+            1,
+        )?;
+        
+        // Create the Metaplex metadata and master edition so wallets recognize the token as an NFT
+        let program_state = &ctx.accounts.program_state;
+        let data_v2 = build_data_v2(
+            program_state.name_or_default(""),
+            program_state.symbol_or_default(""),
+            item.uri.clone(),
+            0,
+            &[],
+            &update_authority.key(),
+            None,
+        );
+        create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                token_metadata_program.clone(),
+                CreateMetadataAccountsV3 {
+                    metadata: metadata.clone(),
+                    mint: mint.clone(),
+                    mint_authority: payer.clone(),
+                    payer: payer.clone(),
+                    update_authority: update_authority.clone(),
+                    system_program: system_program.clone(),
+                    rent: rent.clone(),
+                },
+                update_authority_signer,
+            ),
+            data_v2,
+            true, // is_mutable
+            true, // update_authority_is_signer
+            None,
+        )?;
+        
+        // Max supply 0 makes this a one-of-one; mint and freeze authority move to the edition PDA
+        create_master_edition_v3(
+            CpiContext::new_with_signer(
+                token_metadata_program.clone(),
+                CreateMasterEditionV3 {
+                    edition: master_edition.clone(),
+                    mint: mint.clone(),
+                    update_authority: update_authority.clone(),
+                    mint_authority: payer.clone(),
+                    payer: payer.clone(),
+                    metadata: metadata.clone(),
+                    token_program: token_program.clone(),
+                    system_program: system_program.clone(),
+                    rent: rent.clone(),
+                },
+                update_authority_signer,
+            ),
+            Some(0),
+        )?;
+        
+        // Create the program's NFT metadata and origin records
+        let nft_metadata = NFTMetadata {
+            mint: mint.key(),
+            owner: item.recipient,
+            metadata_uri: item.uri.clone(),
+            zeta_chain_id: source_chain_id,
+            cross_chain_data_hash: payload_hash,
+            token_id: item.token_id,
+            locked: false,
+            owner_locked: false,
+            created_at: clock.unix_timestamp,
+            updated_at: clock.unix_timestamp,
+            bump: nft_metadata_bump,
+            account_version: ACCOUNT_VERSION,
+            collection: Pubkey::default(),
+            metadata_immutable: false,
+            metadata_hash: [0u8; 32],
+            royalty_basis_points: 0, // The creator's Solana address isn't known for NFTs minted elsewhere
+            royalty_recipient: Pubkey::default(),
+        };
+        create_record(
+            nft_metadata_info,
+            &payer,
+            &system_program,
+            NFTMetadata::space(max_uri_length),
+            &[NFT_METADATA_SEED, mint.key().as_ref(), &[nft_metadata_bump]],
+            &nft_metadata,
+        )?;
+        
+        let nft_origin = NFTOrigin {
+            token_id: item.token_id,
+            original_mint: mint.key(),
+            original_metadata_uri: item.uri.clone(),
+            source_chain_id,
+            created_at: clock.unix_timestamp,
+            bump: nft_origin_bump,
+            account_version: ACCOUNT_VERSION,
+        };
+        create_record(
+            nft_origin_info,
+            &payer,
+            &system_program,
+            NFTOrigin::space(max_uri_length),
+            &[TOKEN_ID_SEED, item.token_id.as_ref(), &[nft_origin_bump]],
+            &nft_origin,
+        )?;
+        
+        // Append the hop to the token ID's multichain history; it may already exist from an earlier visit
+        let hop = ProvenanceHop {
+            chain_id: source_chain_id,
+            outbound: false,
+            tx_hash: zeta_tx_hash,
+            timestamp: clock.unix_timestamp,
+        };
+        if provenance_info.data_is_empty() {
+            let mut provenance = Provenance {
+                token_id: item.token_id,
+                hops: Vec::new(),
+                total_hops: 0,
+                bump: provenance_bump,
+                account_version: ACCOUNT_VERSION,
+            };
+            provenance.record(item.token_id, provenance_bump, hop);
+            create_record(
+                provenance_info,
+                &payer,
+                &system_program,
+                Provenance::LEN,
+                &[PROVENANCE_SEED, item.token_id.as_ref(), &[provenance_bump]],
+                &provenance,
+            )?;
+        } else {
+            let mut provenance: Account<Provenance> = Account::try_from(provenance_info)?;
+            provenance.record(item.token_id, provenance_bump, hop);
+            provenance.exit(&crate::ID)?;
+        }
+        
+        msg!("Delivered {} to {} (token ID {:?})", mint.key(), item.recipient, item.token_id);
+    }
+    
+    // Update program state
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_minted = program_state.total_minted.checked_add(count as u64).ok_or(error!(UniversalNFTError::SupplyOverflow))?;
+    
+    let incoming_batch = &mut ctx.accounts.incoming_batch;
+    incoming_batch.processed_items += count as u16;
+    
+    emit!(IncomingBatchProcessed {
+        batch: incoming_batch.key(),
+        source_chain_id,
+        zeta_tx_hash,
+        processed_items: incoming_batch.processed_items,
+        total_items: incoming_batch.total_items,
+        processed_at: clock.unix_timestamp,
+    });
+    
+    msg!("Incoming batch processed");
+    msg!("Source chain: {}", source_chain_id);
+    msg!("ZetaChain TX: {:?}", zeta_tx_hash);
+    msg!("Delivered: {} of {}", incoming_batch.processed_items, incoming_batch.total_items);
+    
+    Ok(())
+}
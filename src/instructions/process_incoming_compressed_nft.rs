@@ -0,0 +1,387 @@
+use anchor_lang::prelude::*;
+use mpl_bubblegum::{
+    accounts::TreeConfig,
+    instructions::MintV1CpiBuilder,
+    programs::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID},
+    types::{Creator, MetadataArgs, TokenProgramVersion, TokenStandard},
+    utils::get_asset_id,
+};
+
+use crate::{
+    state::{ProgramState, PauseConfig, CollectionConfig, Denylist, FrozenAsset, RateLimiter, NFTMetadata, CrossChainTransferState, ZetaChainGatewayState, ChainConfig, TransferStatus, NFTOrigin, ObserverSignature, CollectionState, AssetBackend, Provenance, ProvenanceHop, TransferHistory, TransferRecord, TransferKind},
+    errors::UniversalNFTError,
+    payload::CrossChainPayload,
+    metaplex::build_data_v2,
+    sigverify::secp256k1_verified_addresses,
+    instructions::process_incoming_nft::incoming_message_digest,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(source_chain_id: u64)]
+pub struct ProcessIncomingCompressedNFT<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    #[account(
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+    
+    #[account(
+        mut,
+        seeds = [RATE_LIMITER_SEED],
+        bump = rate_limiter.bump
+    )]
+    pub rate_limiter: Account<'info, RateLimiter>,
+    
+    #[account(
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    #[account(
+        seeds = [CHAIN_CONFIG_SEED, &source_chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    #[account(
+        mut,
+        seeds = [COLLECTION_STATE_SEED, &collection_state.collection_id.to_le_bytes()],
+        bump = collection_state.bump
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+    
+    #[account(
+        mut,
+        seeds = [CROSS_CHAIN_TRANSFER_SEED, asset_id.key().as_ref()],
+        bump = transfer_state.bump,
+        constraint = transfer_state.status == TransferStatus::InProgress @ UniversalNFTError::InvalidTransferStatus
+    )]
+    pub transfer_state: Account<'info, CrossChainTransferState>,
+    
+    /// CHECK: FrozenAsset PDA for the incoming asset; delivery is rejected while it exists
+    #[account(
+        seeds = [FROZEN_ASSET_SEED, asset_id.key().as_ref()],
+        bump
+    )]
+    pub frozen_asset: UncheckedAccount<'info>,
+    
+    /// CHECK: Bubblegum asset ID of the leaf about to be minted, checked against the tree in the handler
+    pub asset_id: UncheckedAccount<'info>,
+    
+    /// CHECK: The collection's merkle tree, updated by the account compression program
+    #[account(
+        mut,
+        address = collection_state.merkle_tree @ UniversalNFTError::InvalidMerkleTree
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+    
+    /// CHECK: Bubblegum tree config PDA of the merkle tree
+    #[account(
+        mut,
+        address = TreeConfig::find_pda(&merkle_tree.key()).0
+    )]
+    pub tree_config: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = NFTMetadata::space(collection_config.max_uri_length()),
+        seeds = [NFT_METADATA_SEED, asset_id.key().as_ref()],
+        bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = NFTOrigin::space(collection_config.max_uri_length()),
+        seeds = [TOKEN_ID_SEED, transfer_state.token_id.as_ref()],
+        bump
+    )]
+    pub nft_origin: Account<'info, NFTOrigin>,
+    
+    /// Chain hop history of the token ID
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Provenance::LEN,
+        seeds = [PROVENANCE_SEED, transfer_state.token_id.as_ref()],
+        bump
+    )]
+    pub provenance: Account<'info, Provenance>,
+    
+    /// CHECK: PDA used as mint and freeze authority for program-minted assets; the tree creator
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Validated against the recipient in the payload
+    pub recipient: UncheckedAccount<'info>,
+    
+    /// Latest ownership changes of the NFT
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TransferHistory::LEN,
+        seeds = [TRANSFER_HISTORY_SEED, asset_id.key().as_ref()],
+        bump
+    )]
+    pub transfer_history: Account<'info, TransferHistory>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected for secp256k1 precompile signatures from observers
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// CHECK: Bubblegum program
+    #[account(address = mpl_bubblegum::ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL noop program, used by Bubblegum as its log wrapper
+    #[account(address = SPL_NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL account compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ProcessIncomingCompressedNFT>,
+    source_chain_id: u64,
+    payload: CrossChainPayload,
+    zeta_tx_hash: [u8; 32],
+    observer_signatures: Vec<ObserverSignature>,
+) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.program_state.require_feature(FEATURE_COMPRESSED_MODE)?;
+    ctx.accounts.pause_config.require_active(PAUSE_INBOUND)?;
+    FrozenAsset::require_not_frozen(&ctx.accounts.frozen_asset)?;
+    
+    // Gateway must be configured and recently maintained before messages can flow
+    ctx.accounts.gateway_state.require_live(Clock::get()?.unix_timestamp)?;
+    
+    // Validate source chain ID
+    if !ctx.accounts.gateway_state.is_chain_supported(ctx.accounts.chain_config.chain_index) {
+        return err!(UniversalNFTError::ChainDisabled);
+    }
+    
+    // Cannot process from the same chain
+    if source_chain_id == ZETA_CHAIN_ID_SOLANA {
+        return err!(UniversalNFTError::InvalidZetaChainID);
+    }
+    
+    // The collection must mint compressed NFTs into a configured tree and accept NFTs from the source chain
+    let collection_state = &ctx.accounts.collection_state;
+    if collection_state.asset_backend != AssetBackend::Compressed {
+        return err!(UniversalNFTError::WrongAssetBackend);
+    }
+    if collection_state.merkle_tree == Pubkey::default() {
+        return err!(UniversalNFTError::InvalidMerkleTree);
+    }
+    if !collection_state.is_chain_allowed(ctx.accounts.chain_config.chain_index) {
+        return err!(UniversalNFTError::ChainNotAllowedForCollection);
+    }
+    
+    // Compressed deliveries have no escrow, so they can't wait out a challenge period
+    if ctx.accounts.gateway_state.challenge_period > 0 {
+        return err!(UniversalNFTError::ChallengePeriodActive);
+    }
+    
+    let clock = Clock::get()?;
+    
+    // Validate the cross-chain payload
+    payload.validate()?;
+    payload.require_fresh(clock.unix_timestamp, ctx.accounts.gateway_state.message_expiry_window)?;
+    ctx.accounts.collection_config.require_allowed_uri(&payload.to_latest().uri)?;
+    ctx.accounts.collection_config.require_uri_length(&payload.to_latest().uri)?;
+    
+    // The observer set must have signed this exact message, inline or through the secp256k1 precompile
+    let digest = incoming_message_digest(source_chain_id, &zeta_tx_hash, &payload.hash()?);
+    let precompile_addresses = secp256k1_verified_addresses(&ctx.accounts.instructions, &digest)?;
+    ctx.accounts.gateway_state.verify_observer_signatures(&digest, &observer_signatures, &precompile_addresses)?;
+    
+    // Bound what a compromised gateway key can deliver; inbound messages carry no lamports
+    ctx.accounts.rate_limiter.record(true, clock.unix_timestamp, 0)?;
+    
+    // Compressed NFTs are delivered straight to a Solana wallet
+    let message = payload.to_latest();
+    // Semi-fungible amounts only travel for tokens minted on Solana, which return to their escrow
+    if message.amount != 1 {
+        return err!(UniversalNFTError::InvalidAmount);
+    }
+    if message.recipient != ctx.accounts.recipient.key().to_bytes().to_vec() {
+        return err!(UniversalNFTError::InvalidRecipientAddress);
+    }
+    ctx.accounts.denylist.require_allowed(&message.recipient)?;
+    
+    // Verify transfer state matches
+    let transfer_state = &mut ctx.accounts.transfer_state;
+    if transfer_state.source_chain_id != source_chain_id {
+        return err!(UniversalNFTError::InvalidCrossChainData);
+    }
+    if transfer_state.token_id != message.token_id {
+        return err!(UniversalNFTError::InvalidTokenId);
+    }
+    let token_id = transfer_state.token_id;
+    
+    // Reuse the original metadata when the NFT has been on Solana before
+    let nft_origin = &mut ctx.accounts.nft_origin;
+    let final_metadata_uri = if nft_origin.token_id != [0u8; 32] {
+        nft_origin.original_metadata_uri.clone()
+    } else {
+        ctx.accounts.collection_state.record_mint()?;
+        nft_origin.token_id = token_id;
+        nft_origin.original_mint = ctx.accounts.asset_id.key();
+        nft_origin.original_metadata_uri = message.uri.clone();
+        nft_origin.source_chain_id = source_chain_id;
+        nft_origin.created_at = clock.unix_timestamp;
+        nft_origin.bump = ctx.bumps.nft_origin;
+        nft_origin.account_version = ACCOUNT_VERSION;
+        message.uri.clone()
+    };
+    
+    // The next leaf's asset ID is fixed by the tree's mint count, so records keyed by it line up
+    let num_minted = {
+        let data = ctx.accounts.tree_config.try_borrow_data()?;
+        TreeConfig::from_bytes(&data).map_err(|_| error!(UniversalNFTError::InvalidMerkleTree))?.num_minted
+    };
+    if ctx.accounts.asset_id.key() != get_asset_id(&ctx.accounts.merkle_tree.key(), num_minted) {
+        return err!(UniversalNFTError::InvalidMerkleTree);
+    }
+    
+    // A leaf in the collection's tree replaces the mint, token account, metadata and master edition
+    let data = build_data_v2(
+        ctx.accounts.program_state.name_or_default(&message.name),
+        ctx.accounts.program_state.symbol_or_default(&message.symbol),
+        final_metadata_uri.clone(),
+        message.royalty_basis_points,
+        &message.creators,
+        &ctx.accounts.mint_authority.key(),
+        None,
+    );
+    let metadata = MetadataArgs {
+        name: data.name,
+        symbol: data.symbol,
+        uri: data.uri,
+        seller_fee_basis_points: data.seller_fee_basis_points,
+        primary_sale_happened: false,
+        is_mutable: true,
+        edition_nonce: None,
+        token_standard: Some(TokenStandard::NonFungible),
+        collection: None,
+        uses: None,
+        token_program_version: TokenProgramVersion::Original,
+        creators: data
+            .creators
+            .unwrap_or_default()
+            .into_iter()
+            .map(|creator| Creator {
+                address: creator.address,
+                verified: creator.verified,
+                share: creator.share,
+            })
+            .collect(),
+    };
+    
+    let mint_authority_bump = ctx.bumps.mint_authority;
+    let mint_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[mint_authority_bump]];
+    
+    MintV1CpiBuilder::new(&ctx.accounts.bubblegum_program.to_account_info())
+        .tree_config(&ctx.accounts.tree_config.to_account_info())
+        .leaf_owner(&ctx.accounts.recipient.to_account_info())
+        .leaf_delegate(&ctx.accounts.recipient.to_account_info())
+        .merkle_tree(&ctx.accounts.merkle_tree.to_account_info())
+        .payer(&ctx.accounts.payer.to_account_info())
+        .tree_creator_or_delegate(&ctx.accounts.mint_authority.to_account_info())
+        .log_wrapper(&ctx.accounts.log_wrapper.to_account_info())
+        .compression_program(&ctx.accounts.compression_program.to_account_info())
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .metadata(metadata)
+        .invoke_signed(&[mint_authority_seeds])?;
+    
+    // Record the ownership change for activity feeds
+    let transfer_history = &mut ctx.accounts.transfer_history;
+    transfer_history.mint = ctx.accounts.asset_id.key();
+    transfer_history.bump = ctx.bumps.transfer_history;
+    transfer_history.account_version = ACCOUNT_VERSION;
+    transfer_history.push(TransferRecord {
+        owner: ctx.accounts.recipient.key(),
+        timestamp: clock.unix_timestamp,
+        kind: TransferKind::CrossChain,
+    });
+    
+    // Initialize NFT metadata, keyed by the asset ID
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.mint = ctx.accounts.asset_id.key();
+    nft_metadata.owner = ctx.accounts.recipient.key();
+    nft_metadata.metadata_uri = final_metadata_uri;
+    nft_metadata.zeta_chain_id = source_chain_id;
+    nft_metadata.cross_chain_data_hash = payload.hash()?;
+    nft_metadata.token_id = token_id;
+    nft_metadata.locked = false;
+    nft_metadata.owner_locked = false;
+    nft_metadata.created_at = clock.unix_timestamp;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    nft_metadata.bump = ctx.bumps.nft_metadata;
+    nft_metadata.account_version = ACCOUNT_VERSION;
+    nft_metadata.collection = ctx.accounts.collection_state.key();
+    nft_metadata.metadata_immutable = false;
+    nft_metadata.metadata_hash = message.metadata_hash;
+    nft_metadata.royalty_basis_points = 0; // The creator's Solana address isn't known for NFTs minted elsewhere
+    nft_metadata.royalty_recipient = Pubkey::default();
+    
+    transfer_state.status = TransferStatus::Completed;
+    transfer_state.zeta_tx_hash = zeta_tx_hash;
+    
+    // Append the hop to the token ID's multichain history
+    let provenance_bump = ctx.bumps.provenance;
+    ctx.accounts.provenance.record(token_id, provenance_bump, ProvenanceHop {
+        chain_id: source_chain_id,
+        outbound: false,
+        tx_hash: zeta_tx_hash,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    // Update program state
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_minted = program_state.total_minted.checked_add(1).ok_or(error!(UniversalNFTError::SupplyOverflow))?;
+    
+    msg!("Incoming compressed NFT processed successfully");
+    msg!("Asset ID: {}", ctx.accounts.asset_id.key());
+    msg!("Merkle tree: {}", ctx.accounts.merkle_tree.key());
+    msg!("Recipient: {}", ctx.accounts.recipient.key());
+    msg!("Token ID: {:?}", token_id);
+    msg!("Source chain: {}", source_chain_id);
+    msg!("ZetaChain TX: {:?}", zeta_tx_hash);
+    
+    Ok(())
+}
@@ -1,68 +1,150 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{Mint, Token, TokenAccount, MintTo},
     metadata::{
+        create_master_edition_v3,
         create_metadata_accounts_v3,
+        CreateMasterEditionV3,
         CreateMetadataAccountsV3,
-        DataV2,
+        Metadata,
     },
 };
-use mpl_token_metadata::instruction::create_metadata_accounts_v3 as mpl_create_metadata;
 
 use crate::{
-    state::{ProgramState, NFTMetadata, CrossChainTransferState, ZetaChainGatewayState, TransferStatus, NFTOrigin},
+    state::{ProgramState, PauseConfig, CollectionConfig, Denylist, FrozenAsset, RateLimiter, NFTMetadata, PendingDelivery, CrossChainTransferState, ZetaChainGatewayState, ChainConfig, TransferStatus, NFTOrigin, NotificationRegistration, IdentityBinding, ObserverSignature, VerifiedCollection, CollectionState, AssetBackend, ClaimableNFT, Provenance, ProvenanceHop, TransferHistory, TransferRecord, TransferKind},
     errors::UniversalNFTError,
+    events::IncomingNFTNotification,
+    payload::CrossChainPayload,
+    metaplex::build_data_v2,
+    sigverify::secp256k1_verified_addresses,
     constants::*,
+    seeds::*,
 };
 
 #[derive(Accounts)]
+#[instruction(source_chain_id: u64)]
 pub struct ProcessIncomingNFT<'info> {
     #[account(
         mut,
-        seeds = [b"program_state"],
+        seeds = [PROGRAM_STATE_SEED],
         bump = program_state.bump
     )]
     pub program_state: Account<'info, ProgramState>,
     
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    #[account(
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+    
     #[account(
         mut,
-        seeds = [b"gateway_state"],
+        seeds = [RATE_LIMITER_SEED],
+        bump = rate_limiter.bump
+    )]
+    pub rate_limiter: Account<'info, RateLimiter>,
+    
+    #[account(
+        mut,
+        seeds = [GATEWAY_STATE_SEED],
         bump = gateway_state.bump
     )]
     pub gateway_state: Account<'info, ZetaChainGatewayState>,
     
+    #[account(
+        seeds = [CHAIN_CONFIG_SEED, &source_chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
     #[account(
         mut,
-        seeds = [b"cross_chain_transfer", incoming_nft_mint.key().as_ref()],
+        seeds = [CROSS_CHAIN_TRANSFER_SEED, incoming_nft_mint.key().as_ref()],
         bump = transfer_state.bump,
-        constraint = transfer_state.status == TransferStatus::InProgress
+        constraint = transfer_state.status == TransferStatus::InProgress @ UniversalNFTError::InvalidTransferStatus
     )]
     pub transfer_state: Account<'info, CrossChainTransferState>,
     
+    /// CHECK: FrozenAsset PDA for the incoming mint; delivery is rejected while it exists
+    #[account(
+        seeds = [FROZEN_ASSET_SEED, incoming_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub frozen_asset: UncheckedAccount<'info>,
+    
     #[account(
         init,
         payer = payer,
-        mint = incoming_nft_mint,
-        authority = recipient,
-        decimals = SOLANA_DECIMALS,
-        freeze_authority = Some(recipient.key()),
+        mint::decimals = SOLANA_DECIMALS,
+        mint::authority = payer,
+        mint::freeze_authority = payer,
     )]
     pub incoming_nft_mint: Account<'info, Mint>,
     
+    /// Required unless the NFT is held in escrow for the recipient to claim or wait out a challenge period
     #[account(
         init_if_needed,
         payer = payer,
         associated_token::mint = incoming_nft_mint,
         associated_token::authority = recipient,
     )]
-    pub recipient_token_account: Account<'info, TokenAccount>,
+    pub recipient_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: PDA that holds incoming NFTs while the challenge period runs or until they are claimed
+    #[account(
+        seeds = [ESCROW_AUTHORITY_SEED],
+        bump
+    )]
+    pub escrow_authority: Option<UncheckedAccount<'info>>,
+    
+    /// Required when the gateway has a challenge period or the NFT is held for claim
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = incoming_nft_mint,
+        associated_token::authority = escrow_authority,
+    )]
+    pub escrow_token_account: Option<Account<'info, TokenAccount>>,
     
+    /// Required when the gateway has a challenge period
     #[account(
         init,
         payer = payer,
-        space = NFTMetadata::LEN,
-        seeds = [b"nft_metadata", incoming_nft_mint.key().as_ref()],
+        space = PendingDelivery::LEN,
+        seeds = [PENDING_DELIVERY_SEED, incoming_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub pending_delivery: Option<Account<'info, PendingDelivery>>,
+    
+    /// Required when the NFT is held for the recipient to claim with `claim_incoming_nft`
+    #[account(
+        init,
+        payer = payer,
+        space = ClaimableNFT::LEN,
+        seeds = [CLAIMABLE_NFT_SEED, incoming_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub claimable_nft: Option<Account<'info, ClaimableNFT>>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = NFTMetadata::space(collection_config.max_uri_length()),
+        seeds = [NFT_METADATA_SEED, incoming_nft_mint.key().as_ref()],
         bump
     )]
     pub nft_metadata: Account<'info, NFTMetadata>,
@@ -70,39 +152,117 @@ pub struct ProcessIncomingNFT<'info> {
     #[account(
         init_if_needed,
         payer = payer,
-        space = NFTOrigin::LEN,
-        seeds = [TOKEN_ID_SEED, &transfer_state.token_id.to_le_bytes()],
+        space = NFTOrigin::space(collection_config.max_uri_length()),
+        seeds = [TOKEN_ID_SEED, transfer_state.token_id.as_ref()],
         bump
     )]
     pub nft_origin: Account<'info, NFTOrigin>,
     
+    /// Chain hop history of the token ID
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Provenance::LEN,
+        seeds = [PROVENANCE_SEED, transfer_state.token_id.as_ref()],
+        bump
+    )]
+    pub provenance: Account<'info, Provenance>,
+    
+    /// Latest ownership changes of the NFT
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TransferHistory::LEN,
+        seeds = [TRANSFER_HISTORY_SEED, incoming_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_history: Account<'info, TransferHistory>,
+    
+    /// CHECK: Metaplex metadata PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), incoming_nft_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Metaplex master edition PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), incoming_nft_mint.key().as_ref(), METAPLEX_EDITION_SEED],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA set as the Metaplex update authority, so the program can keep on-chain metadata in sync
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub update_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [NOTIFICATION_SEED, recipient.key().as_ref()],
+        bump = notification_registration.bump
+    )]
+    pub notification_registration: Option<Account<'info, NotificationRegistration>>,
+    
+    /// Required when the message addresses a bound remote identity instead of a Solana pubkey
+    pub identity_binding: Option<Account<'info, IdentityBinding>>,
+    
+    /// When present, the NFT is minted into the program's collection, pending `verify_collection_item`
+    #[account(
+        seeds = [VERIFIED_COLLECTION_SEED],
+        bump = verified_collection.bump
+    )]
+    pub verified_collection: Option<Account<'info, VerifiedCollection>>,
+    
+    /// When present, the NFT arrives into this collection and counts against its supply
+    #[account(
+        mut,
+        seeds = [COLLECTION_STATE_SEED, &collection_state.collection_id.to_le_bytes()],
+        bump = collection_state.bump
+    )]
+    pub collection_state: Option<Account<'info, CollectionState>>,
+    
+    /// Pays for every account; mint authority of the new mint until the master edition takes over
     #[account(mut)]
     pub payer: Signer<'info>,
     
-    #[account(mut)]
-    pub recipient: Signer<'info>,
+    /// CHECK: Solana wallet the message is addressed to, directly or through a bound identity; need not sign
+    pub recipient: UncheckedAccount<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected for secp256k1 precompile signatures from observers
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
     
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
     pub rent: Sysvar<'info, Rent>,
 }
 
 pub fn handler(
     ctx: Context<ProcessIncomingNFT>,
-    metadata_uri: String,
     source_chain_id: u64,
-    cross_chain_data: Vec<u8>,
+    payload: CrossChainPayload,
     zeta_tx_hash: [u8; 32],
+    observer_signatures: Vec<ObserverSignature>,
+    hold_for_claim: bool,
 ) -> Result<()> {
-    // Validate metadata URI length
-    if metadata_uri.len() > MAX_METADATA_URI_LENGTH {
-        return err!(UniversalNFTError::InvalidMetadataURILength);
-    }
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_INBOUND)?;
+    FrozenAsset::require_not_frozen(&ctx.accounts.frozen_asset)?;
+    
+    // Gateway must be configured and recently maintained before messages can flow
+    ctx.accounts.gateway_state.require_live(Clock::get()?.unix_timestamp)?;
     
     // Validate source chain ID
-    if !ctx.accounts.gateway_state.supported_chains.contains(&source_chain_id) {
-        return err!(UniversalNFTError::InvalidZetaChainID);
+    if !ctx.accounts.gateway_state.is_chain_supported(ctx.accounts.chain_config.chain_index) {
+        return err!(UniversalNFTError::ChainDisabled);
     }
     
     // Cannot process from the same chain
@@ -110,9 +270,59 @@ pub fn handler(
         return err!(UniversalNFTError::InvalidZetaChainID);
     }
     
-    // Validate cross-chain data length
-    if cross_chain_data.len() > MAX_CROSS_CHAIN_DATA_LENGTH {
-        return err!(UniversalNFTError::InvalidCrossChainData);
+    // The collection must accept NFTs from the source chain as SPL mints
+    if let Some(collection_state) = ctx.accounts.collection_state.as_ref() {
+        if !collection_state.is_chain_allowed(ctx.accounts.chain_config.chain_index) {
+            return err!(UniversalNFTError::ChainNotAllowedForCollection);
+        }
+        if collection_state.asset_backend != AssetBackend::SplToken {
+            return err!(UniversalNFTError::WrongAssetBackend);
+        }
+    }
+    
+    let clock = Clock::get()?;
+    
+    // Validate the cross-chain payload
+    payload.validate()?;
+    payload.require_fresh(clock.unix_timestamp, ctx.accounts.gateway_state.message_expiry_window)?;
+    ctx.accounts.collection_config.require_allowed_uri(&payload.to_latest().uri)?;
+    ctx.accounts.collection_config.require_uri_length(&payload.to_latest().uri)?;
+    
+    // The observer set must have signed this exact message, inline or through the secp256k1 precompile
+    let digest = incoming_message_digest(source_chain_id, &zeta_tx_hash, &payload.hash()?);
+    let precompile_addresses = secp256k1_verified_addresses(&ctx.accounts.instructions, &digest)?;
+    ctx.accounts.gateway_state.verify_observer_signatures(&digest, &observer_signatures, &precompile_addresses)?;
+    
+    // Bound what a compromised gateway key can deliver; inbound messages carry no lamports
+    ctx.accounts.rate_limiter.record(true, clock.unix_timestamp, 0)?;
+    
+    let message = payload.to_latest();
+    // Semi-fungible amounts only travel for tokens minted on Solana, which return to their escrow
+    if message.amount != 1 {
+        return err!(UniversalNFTError::InvalidAmount);
+    }
+    if message.recipient.is_empty() {
+        return err!(UniversalNFTError::InvalidRecipientAddress);
+    }
+    
+    // Neither the addressed identity nor the receiving wallet may be denied
+    ctx.accounts.denylist.require_allowed(&message.recipient)?;
+    ctx.accounts.denylist.require_allowed(ctx.accounts.recipient.key().as_ref())?;
+    
+    // The message must be addressed to the recipient, directly or via a bound identity
+    if message.recipient.len() == EVM_ADDRESS_LENGTH {
+        let identity_binding = ctx.accounts.identity_binding.as_ref()
+            .ok_or(error!(UniversalNFTError::IdentityVerificationFailed))?;
+        let (expected_binding, _) = Pubkey::find_program_address(
+            &[IDENTITY_SEED, message.recipient.as_ref()],
+            ctx.program_id,
+        );
+        if identity_binding.key() != expected_binding || identity_binding.wallet != ctx.accounts.recipient.key() {
+            return err!(UniversalNFTError::IdentityVerificationFailed);
+        }
+        msg!("Resolved identity {:?} to {}", message.recipient, identity_binding.wallet);
+    } else if message.recipient != ctx.accounts.recipient.key().to_bytes().to_vec() {
+        return err!(UniversalNFTError::InvalidRecipientAddress);
     }
     
     // Verify transfer state matches
@@ -120,19 +330,26 @@ pub fn handler(
     if transfer_state.source_chain_id != source_chain_id {
         return err!(UniversalNFTError::InvalidCrossChainData);
     }
-    
-    let clock = Clock::get()?;
+    if transfer_state.token_id != message.token_id {
+        return err!(UniversalNFTError::InvalidTokenId);
+    }
+    let metadata_uri = message.uri;
     
     // Get the token ID from the transfer state
     let token_id = transfer_state.token_id;
     
     // Check if this NFT has been minted on Solana before by looking at the NFTOrigin
     let nft_origin = &mut ctx.accounts.nft_origin;
-    let is_existing_nft = nft_origin.token_id != 0;
+    let is_existing_nft = nft_origin.token_id != [0u8; 32];
     
     let final_metadata_uri = if is_existing_nft {
+        // Solana-origin and wrapped NFTs still sit in escrow, so they are released rather than minted again
+        if nft_origin.source_chain_id == ZETA_CHAIN_ID_SOLANA {
+            return err!(UniversalNFTError::ReturnToEscrowedMint);
+        }
+        
         // This NFT was minted on Solana before - use original metadata
-        msg!("Processing existing NFT with token ID: {}", token_id);
+        msg!("Processing existing NFT with token ID: {:?}", token_id);
         msg!("Original mint: {}", nft_origin.original_mint);
         msg!("Original metadata URI: {}", nft_origin.original_metadata_uri);
         
@@ -140,7 +357,10 @@ pub fn handler(
         nft_origin.original_metadata_uri.clone()
     } else {
         // This is a new NFT coming to Solana for the first time
-        msg!("Processing new NFT with token ID: {}", token_id);
+        msg!("Processing new NFT with token ID: {:?}", token_id);
+        if let Some(collection_state) = ctx.accounts.collection_state.as_mut() {
+            collection_state.record_mint()?;
+        }
         
         // Initialize NFT origin tracking
         nft_origin.token_id = token_id;
@@ -148,16 +368,37 @@ pub fn handler(
         nft_origin.original_metadata_uri = metadata_uri.clone();
         nft_origin.source_chain_id = source_chain_id;
         nft_origin.created_at = clock.unix_timestamp;
-        nft_origin.bump = *ctx.bumps.get("nft_origin").unwrap();
+        nft_origin.bump = ctx.bumps.nft_origin;
+        nft_origin.account_version = ACCOUNT_VERSION;
         
         metadata_uri
     };
     
-    // Mint 1 token to the recipient
+    // With a challenge period the NFT waits in escrow until finalize_incoming_nft releases it;
+    // claim deliveries wait there until the recipient calls claim_incoming_nft
+    let challenge_period = ctx.accounts.gateway_state.challenge_period;
+    let escrowed = challenge_period > 0;
+    if escrowed && hold_for_claim {
+        return err!(UniversalNFTError::ChallengePeriodActive);
+    }
+    let held = escrowed || hold_for_claim;
+    let (mint_destination, holder) = if held {
+        let escrow_token_account = ctx.accounts.escrow_token_account.as_ref()
+            .ok_or(error!(UniversalNFTError::ChallengePeriodActive))?;
+        let escrow_authority = ctx.accounts.escrow_authority.as_ref()
+            .ok_or(error!(UniversalNFTError::ChallengePeriodActive))?;
+        (escrow_token_account.to_account_info(), escrow_authority.key())
+    } else {
+        let recipient_token_account = ctx.accounts.recipient_token_account.as_ref()
+            .ok_or(error!(UniversalNFTError::InvalidRecipientAddress))?;
+        (recipient_token_account.to_account_info(), ctx.accounts.recipient.key())
+    };
+    
+    // Mint 1 token to the recipient, or to escrow while the message can be challenged or claimed
     let cpi_accounts = MintTo {
         mint: ctx.accounts.incoming_nft_mint.to_account_info(),
-        to: ctx.accounts.recipient_token_account.to_account_info(),
-        authority: ctx.accounts.recipient.to_account_info(),
+        to: mint_destination,
+        authority: ctx.accounts.payer.to_account_info(),
     };
     
     let cpi_program = ctx.accounts.token_program.to_account_info();
@@ -165,97 +406,179 @@ pub fn handler(
     
     anchor_spl::token::mint_to(cpi_ctx, 1)?;
     
-    // Create metadata account
-    let metadata_account = &ctx.accounts.nft_metadata;
-    let metadata_seeds = &[
-        b"metadata",
-        mpl_token_metadata::ID.as_ref(),
-        ctx.accounts.incoming_nft_mint.key().as_ref(),
-    ];
-    let metadata_signer = &[&metadata_seeds[..]];
-    
-    let create_metadata_accounts = CreateMetadataAccountsV3 {
-        metadata: metadata_account.to_account_info(),
-        mint: ctx.accounts.incoming_nft_mint.to_account_info(),
-        mint_authority: ctx.accounts.recipient.to_account_info(),
-        payer: ctx.accounts.payer.to_account_info(),
-        update_authority: ctx.accounts.recipient.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
-        rent: Some(ctx.accounts.rent.to_account_info()),
-    };
+    // Create the Metaplex metadata and master edition so wallets recognize the token as an NFT,
+    // with the program's PDA as update authority so update_metadata can keep them in sync
+    let data_v2 = build_data_v2(
+        ctx.accounts.program_state.name_or_default(&message.name),
+        ctx.accounts.program_state.symbol_or_default(&message.symbol),
+        final_metadata_uri.clone(),
+        message.royalty_basis_points,
+        &message.creators,
+        &ctx.accounts.update_authority.key(),
+        ctx.accounts.verified_collection.as_ref().map(|collection| collection.mint),
+    );
     
-    let data_v2 = DataV2 {
-        name: DEFAULT_METADATA_NAME.to_string(),
-        symbol: DEFAULT_METADATA_SYMBOL.to_string(),
-        uri: final_metadata_uri.clone(),
-        seller_fee_basis_points: 0,
-        creators: None,
-        collection: None,
-        uses: None,
-    };
+    let update_authority_bump = ctx.bumps.update_authority;
+    let update_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[update_authority_bump]];
+    let update_authority_signer = &[update_authority_seeds];
     
-    let instruction = mpl_create_metadata(
-        mpl_token_metadata::ID,
-        create_metadata_accounts.metadata.key(),
-        create_metadata_accounts.mint.key(),
-        create_metadata_accounts.mint_authority.key(),
-        create_metadata_accounts.payer.key(),
-        create_metadata_accounts.update_authority.key(),
-        data_v2.name,
-        data_v2.symbol,
-        data_v2.uri,
-        Some(create_metadata_accounts.creators.clone().unwrap_or_default()),
-        data_v2.seller_fee_basis_points,
-        data_v2.uses.clone(),
-        data_v2.collection.clone(),
-        data_v2.is_mutable,
-        data_v2.collection_details.clone(),
-        data_v2.uses.clone(),
-    );
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.incoming_nft_mint.to_account_info(),
+                mint_authority: ctx.accounts.payer.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                update_authority: ctx.accounts.update_authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            update_authority_signer,
+        ),
+        data_v2,
+        true, // is_mutable
+        true, // update_authority_is_signer
+        None,
+    )?;
     
-    let accounts = vec![
-        create_metadata_accounts.metadata.to_account_info(),
-        create_metadata_accounts.mint.to_account_info(),
-        create_metadata_accounts.mint_authority.to_account_info(),
-        create_metadata_accounts.payer.to_account_info(),
-        create_metadata_accounts.update_authority.to_account_info(),
-        create_metadata_accounts.system_program.to_account_info(),
-        create_metadata_accounts.rent.unwrap().to_account_info(),
-    ];
-    
-    solana_program::program::invoke_signed(
-        &instruction,
-        accounts.as_slice(),
-        metadata_signer,
+    // Max supply 0 makes this a one-of-one; mint and freeze authority move to the edition PDA
+    create_master_edition_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMasterEditionV3 {
+                edition: ctx.accounts.master_edition.to_account_info(),
+                mint: ctx.accounts.incoming_nft_mint.to_account_info(),
+                update_authority: ctx.accounts.update_authority.to_account_info(),
+                mint_authority: ctx.accounts.payer.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            update_authority_signer,
+        ),
+        Some(0),
     )?;
     
     // Initialize NFT metadata
     let nft_metadata = &mut ctx.accounts.nft_metadata;
     nft_metadata.mint = ctx.accounts.incoming_nft_mint.key();
-    nft_metadata.owner = ctx.accounts.recipient.key();
+    nft_metadata.owner = holder;
     nft_metadata.metadata_uri = final_metadata_uri;
     nft_metadata.zeta_chain_id = source_chain_id;
-    nft_metadata.cross_chain_data_hash = anchor_lang::solana_program::keccak::hash(&cross_chain_data).to_bytes();
+    nft_metadata.cross_chain_data_hash = payload.hash()?;
     nft_metadata.token_id = token_id;
+    nft_metadata.locked = held; // Released by finalize_incoming_nft or claim_incoming_nft
+    nft_metadata.owner_locked = false;
     nft_metadata.created_at = clock.unix_timestamp;
     nft_metadata.updated_at = clock.unix_timestamp;
-    nft_metadata.bump = *ctx.bumps.get("nft_metadata").unwrap();
+    nft_metadata.bump = ctx.bumps.nft_metadata;
+    nft_metadata.account_version = ACCOUNT_VERSION;
+    nft_metadata.collection = ctx.accounts.collection_state.as_ref().map(|collection| collection.key()).unwrap_or_default();
+    nft_metadata.metadata_immutable = false;
+    nft_metadata.metadata_hash = message.metadata_hash;
+    nft_metadata.royalty_basis_points = 0; // The creator's Solana address isn't known for NFTs minted elsewhere
+    nft_metadata.royalty_recipient = Pubkey::default();
     
-    // Update transfer state
-    transfer_state.status = TransferStatus::Completed;
+    // Update transfer state; escrowed deliveries complete on finalization
+    if !escrowed {
+        transfer_state.status = TransferStatus::Completed;
+    }
     transfer_state.zeta_tx_hash = zeta_tx_hash;
     
+    // Append the hop to the token ID's multichain history
+    let provenance_bump = ctx.bumps.provenance;
+    ctx.accounts.provenance.record(token_id, provenance_bump, ProvenanceHop {
+        chain_id: source_chain_id,
+        outbound: false,
+        tx_hash: zeta_tx_hash,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    // Record the ownership change for activity feeds; held deliveries are recorded when they leave escrow
+    let transfer_history = &mut ctx.accounts.transfer_history;
+    transfer_history.mint = ctx.accounts.incoming_nft_mint.key();
+    transfer_history.bump = ctx.bumps.transfer_history;
+    transfer_history.account_version = ACCOUNT_VERSION;
+    if !held {
+        transfer_history.push(TransferRecord {
+            owner: ctx.accounts.recipient.key(),
+            timestamp: clock.unix_timestamp,
+            kind: TransferKind::CrossChain,
+        });
+    }
+    
+    if escrowed {
+        let pending_delivery = ctx.accounts.pending_delivery.as_mut()
+            .ok_or(error!(UniversalNFTError::ChallengePeriodActive))?;
+        pending_delivery.mint = ctx.accounts.incoming_nft_mint.key();
+        pending_delivery.recipient = ctx.accounts.recipient.key();
+        pending_delivery.relayer = ctx.accounts.payer.key();
+        pending_delivery.source_chain_id = source_chain_id;
+        pending_delivery.zeta_tx_hash = zeta_tx_hash;
+        pending_delivery.payload_hash = nft_metadata.cross_chain_data_hash;
+        pending_delivery.delivered_at = clock.unix_timestamp;
+        pending_delivery.available_at = clock.unix_timestamp
+            .checked_add(challenge_period)
+            .ok_or(error!(UniversalNFTError::InvalidChallengePeriod))?;
+        pending_delivery.disputed = false;
+        pending_delivery.bump = ctx.bumps.pending_delivery;
+        pending_delivery.account_version = ACCOUNT_VERSION;
+        msg!("Held in escrow until: {}", pending_delivery.available_at);
+    }
+    
+    if hold_for_claim {
+        let claimable_nft = ctx.accounts.claimable_nft.as_mut()
+            .ok_or(error!(UniversalNFTError::InvalidRecipientAddress))?;
+        claimable_nft.mint = ctx.accounts.incoming_nft_mint.key();
+        claimable_nft.recipient = ctx.accounts.recipient.key();
+        claimable_nft.relayer = ctx.accounts.payer.key();
+        claimable_nft.token_id = token_id;
+        claimable_nft.source_chain_id = source_chain_id;
+        claimable_nft.delivered_at = clock.unix_timestamp;
+        claimable_nft.bump = ctx.bumps.claimable_nft;
+        claimable_nft.account_version = ACCOUNT_VERSION;
+        msg!("Held in escrow for the recipient to claim");
+    }
+    
     // Update program state
     let program_state = &mut ctx.accounts.program_state;
-    program_state.total_minted += 1;
+    program_state.total_minted = program_state.total_minted.checked_add(1).ok_or(error!(UniversalNFTError::SupplyOverflow))?;
+    
+    // Let notification services know the NFT arrived
+    if let Some(registration) = &ctx.accounts.notification_registration {
+        emit!(IncomingNFTNotification {
+            recipient: ctx.accounts.recipient.key(),
+            registration: registration.key(),
+            commitment: registration.commitment,
+            nft_mint: ctx.accounts.incoming_nft_mint.key(),
+            token_id,
+            source_chain_id,
+            delivered_at: clock.unix_timestamp,
+        });
+    }
     
     msg!("Incoming NFT processed successfully");
     msg!("Mint address: {}", ctx.accounts.incoming_nft_mint.key());
     msg!("Recipient: {}", ctx.accounts.recipient.key());
-    msg!("Token ID: {}", token_id);
+    msg!("Token ID: {:?}", token_id);
     msg!("Source chain: {}", source_chain_id);
     msg!("ZetaChain TX: {:?}", zeta_tx_hash);
-    msg!("Status: Completed");
+    msg!("Status: {}", if escrowed { "Pending" } else if hold_for_claim { "Claimable" } else { "Completed" });
     
     Ok(())
 }
+
+/// Digest signed by the observers: keccak(domain || program_id || source_chain_id || zeta_tx_hash || payload_hash)
+pub fn incoming_message_digest(source_chain_id: u64, zeta_tx_hash: &[u8; 32], payload_hash: &[u8; 32]) -> [u8; 32] {
+    keccak::hashv(&[
+        INCOMING_MESSAGE_DOMAIN,
+        crate::ID.as_ref(),
+        &source_chain_id.to_le_bytes(),
+        zeta_tx_hash,
+        payload_hash,
+    ])
+    .to_bytes()
+}
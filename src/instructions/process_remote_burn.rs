@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, CloseAccount, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    state::{ProgramState, PauseConfig, Denylist, FrozenAsset, RateLimiter, NFTMetadata, NFTOrigin, CrossChainTransferState, ZetaChainGatewayState, ChainConfig, TransferStatus, ObserverSignature},
+    errors::UniversalNFTError,
+    events::NFTBurnedRemotely,
+    payload::RemoteBurnPayload,
+    sigverify::secp256k1_verified_addresses,
+    instructions::process_incoming_nft::incoming_message_digest,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(source_chain_id: u64, payload: RemoteBurnPayload)]
+pub struct ProcessRemoteBurn<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+    
+    #[account(
+        mut,
+        seeds = [RATE_LIMITER_SEED],
+        bump = rate_limiter.bump
+    )]
+    pub rate_limiter: Account<'info, RateLimiter>,
+    
+    #[account(
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    #[account(
+        seeds = [CHAIN_CONFIG_SEED, &source_chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    #[account(
+        mut,
+        close = original_sender,
+        seeds = [TOKEN_ID_SEED, payload.token_id.as_ref()],
+        bump = nft_origin.bump,
+        constraint = nft_origin.original_mint == nft_mint.key() @ UniversalNFTError::MintMismatch
+    )]
+    pub nft_origin: Account<'info, NFTOrigin>,
+    
+    #[account(
+        mut,
+        close = original_sender,
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(mut)]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Outbound transfer that took the NFT to the source chain
+    #[account(
+        mut,
+        close = original_sender,
+        seeds = [CROSS_CHAIN_TRANSFER_SEED, nft_mint.key().as_ref()],
+        bump = transfer_state.bump,
+        constraint = transfer_state.status == TransferStatus::Completed @ UniversalNFTError::InvalidTransferStatus,
+        constraint = transfer_state.target_chain_id == source_chain_id @ UniversalNFTError::InvalidZetaChainID
+    )]
+    pub transfer_state: Account<'info, CrossChainTransferState>,
+    
+    /// CHECK: FrozenAsset PDA for the mint; the burn is rejected while it exists
+    #[account(
+        seeds = [FROZEN_ASSET_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub frozen_asset: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the rent of every closed account; validated against the recorded sender
+    #[account(
+        mut,
+        constraint = original_sender.key() == transfer_state.sender @ UniversalNFTError::SenderMismatch
+    )]
+    pub original_sender: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA that owns escrowed NFTs while they live on other chains
+    #[account(
+        seeds = [ESCROW_AUTHORITY_SEED],
+        bump
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = escrow_authority,
+        associated_token::token_program = token_program,
+        constraint = escrow_token_account.amount == 1 @ UniversalNFTError::TokenNotHeld,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        constraint = gateway_state.is_gateway_authority(&gateway_authority.key(), Clock::get()?.unix_timestamp) @ UniversalNFTError::Unauthorized
+    )]
+    pub gateway_authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected for secp256k1 precompile signatures from observers
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(
+    ctx: Context<ProcessRemoteBurn>,
+    source_chain_id: u64,
+    payload: RemoteBurnPayload,
+    zeta_tx_hash: [u8; 32],
+    observer_signatures: Vec<ObserverSignature>,
+) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_INBOUND)?;
+    ctx.accounts.pause_config.require_active(PAUSE_BURN)?;
+    FrozenAsset::require_not_frozen(&ctx.accounts.frozen_asset)?;
+    
+    // Gateway must be configured and recently maintained before messages can flow
+    ctx.accounts.gateway_state.require_live(Clock::get()?.unix_timestamp)?;
+    
+    // Validate source chain ID
+    if !ctx.accounts.gateway_state.is_chain_supported(ctx.accounts.chain_config.chain_index) {
+        return err!(UniversalNFTError::ChainDisabled);
+    }
+    if source_chain_id == ZETA_CHAIN_ID_SOLANA {
+        return err!(UniversalNFTError::InvalidZetaChainID);
+    }
+    
+    let clock = Clock::get()?;
+    
+    // The source chain contract checks the holder before the gateway relays the burn
+    payload.validate()?;
+    payload.require_fresh(clock.unix_timestamp, ctx.accounts.gateway_state.message_expiry_window)?;
+    
+    // The observer set must have signed this exact message, inline or through the secp256k1 precompile
+    let digest = incoming_message_digest(source_chain_id, &zeta_tx_hash, &payload.hash());
+    let precompile_addresses = secp256k1_verified_addresses(&ctx.accounts.instructions, &digest)?;
+    ctx.accounts.gateway_state.verify_observer_signatures(&digest, &observer_signatures, &precompile_addresses)?;
+    
+    // Bound what a compromised gateway key can destroy
+    ctx.accounts.rate_limiter.record(true, clock.unix_timestamp, 0)?;
+    ctx.accounts.denylist.require_allowed(&payload.owner)?;
+    
+    // Burn the escrowed token and close its escrow account
+    let escrow_bump = ctx.bumps.escrow_authority;
+    let escrow_seeds: &[&[u8]] = &[ESCROW_AUTHORITY_SEED, &[escrow_bump]];
+    let escrow_signer = &[escrow_seeds];
+    
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                authority: ctx.accounts.escrow_authority.to_account_info(),
+            },
+            escrow_signer,
+        ),
+        1,
+    )?;
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow_token_account.to_account_info(),
+            destination: ctx.accounts.original_sender.to_account_info(),
+            authority: ctx.accounts.escrow_authority.to_account_info(),
+        },
+        escrow_signer,
+    ))?;
+    
+    // The escrowed token is burned above; the NFT already left the Solana supply count when it
+    // was sent out, so total_minted is not decremented again
+    emit!(NFTBurnedRemotely {
+        mint: ctx.accounts.nft_mint.key(),
+        token_id: payload.token_id,
+        source_chain_id,
+        owner: payload.owner.clone(),
+        zeta_tx_hash,
+        burned_at: clock.unix_timestamp,
+    });
+    
+    msg!("NFT burned by remote owner");
+    msg!("Mint address: {}", ctx.accounts.nft_mint.key());
+    msg!("Token ID: {:?}", payload.token_id);
+    msg!("Source chain: {}", source_chain_id);
+    msg!("Remote owner: {:?}", payload.owner);
+    msg!("ZetaChain TX: {:?}", zeta_tx_hash);
+    
+    Ok(())
+}
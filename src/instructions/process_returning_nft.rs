@@ -0,0 +1,347 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::{ProgramState, PauseConfig, Denylist, FrozenAsset, RateLimiter, NFTMetadata, NFTOrigin, CrossChainTransferState, ZetaChainGatewayState, ChainConfig, TransferStatus, TokenBalance, RewardsConfig, PointsLedger, ObserverSignature, Provenance, ProvenanceHop, TransferHistory, TransferRecord, TransferKind},
+    errors::UniversalNFTError,
+    events::PointsCredited,
+    transfer_hook,
+    programmable::ProgrammableTransfer,
+    payload::CrossChainPayload,
+    sigverify::secp256k1_verified_addresses,
+    instructions::process_incoming_nft::incoming_message_digest,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(source_chain_id: u64, payload: CrossChainPayload)]
+pub struct ProcessReturningNFT<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+    
+    #[account(
+        mut,
+        seeds = [RATE_LIMITER_SEED],
+        bump = rate_limiter.bump
+    )]
+    pub rate_limiter: Account<'info, RateLimiter>,
+    
+    #[account(
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    #[account(
+        seeds = [CHAIN_CONFIG_SEED, &source_chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    #[account(
+        seeds = [TOKEN_ID_SEED, payload.to_latest().token_id.as_ref()],
+        bump = nft_origin.bump,
+        constraint = nft_origin.original_mint == nft_mint.key() @ UniversalNFTError::MintMismatch
+    )]
+    pub nft_origin: Account<'info, NFTOrigin>,
+    
+    /// Chain hop history of the token ID
+    #[account(
+        init_if_needed,
+        payer = gateway_authority,
+        space = Provenance::LEN,
+        seeds = [PROVENANCE_SEED, payload.to_latest().token_id.as_ref()],
+        bump
+    )]
+    pub provenance: Account<'info, Provenance>,
+    
+    /// Latest ownership changes of the NFT
+    #[account(
+        init_if_needed,
+        payer = gateway_authority,
+        space = TransferHistory::LEN,
+        seeds = [TRANSFER_HISTORY_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_history: Account<'info, TransferHistory>,
+    
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        close = original_sender,
+        seeds = [CROSS_CHAIN_TRANSFER_SEED, nft_mint.key().as_ref()],
+        bump = transfer_state.bump,
+        constraint = transfer_state.status == TransferStatus::Completed @ UniversalNFTError::InvalidTransferStatus
+    )]
+    pub transfer_state: Account<'info, CrossChainTransferState>,
+    
+    /// CHECK: FrozenAsset PDA for the mint; release is rejected while it exists
+    #[account(
+        seeds = [FROZEN_ASSET_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub frozen_asset: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the outbound transfer state rent; validated against the recorded sender
+    #[account(
+        mut,
+        constraint = original_sender.key() == transfer_state.sender @ UniversalNFTError::SenderMismatch
+    )]
+    pub original_sender: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA that owns escrowed NFTs while they live on other chains
+    #[account(
+        seeds = [ESCROW_AUTHORITY_SEED],
+        bump
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = escrow_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = gateway_authority,
+        associated_token::mint = nft_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Must be the Solana recipient named in the payload
+    #[account(
+        constraint = payload.to_latest().recipient == recipient.key().to_bytes().to_vec() @ UniversalNFTError::RecipientMismatch
+    )]
+    pub recipient: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = gateway_state.is_gateway_authority(&gateway_authority.key(), Clock::get()?.unix_timestamp) @ UniversalNFTError::Unauthorized
+    )]
+    pub gateway_authority: Signer<'info>,
+    
+    /// CHECK: Transfer hook authority PDA, required for Token-2022 NFTs that use this program's hook
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: Option<UncheckedAccount<'info>>,
+    
+    /// Source chain balance of a semi-fungible token; required when more than one unit returns
+    #[account(
+        mut,
+        seeds = [TOKEN_BALANCE_SEED, payload.to_latest().token_id.as_ref(), &source_chain_id.to_le_bytes()],
+        bump = source_balance.bump
+    )]
+    pub source_balance: Option<Account<'info, TokenBalance>>,
+    
+    /// Solana balance of a semi-fungible token, credited with the returned units
+    #[account(
+        mut,
+        seeds = [TOKEN_BALANCE_SEED, payload.to_latest().token_id.as_ref(), &ZETA_CHAIN_ID_SOLANA.to_le_bytes()],
+        bump = solana_balance.bump
+    )]
+    pub solana_balance: Option<Account<'info, TokenBalance>>,
+    
+    #[account(
+        seeds = [REWARDS_CONFIG_SEED],
+        bump = rewards_config.bump
+    )]
+    pub rewards_config: Option<Account<'info, RewardsConfig>>,
+    
+    #[account(
+        mut,
+        seeds = [POINTS_SEED, recipient.key().as_ref()],
+        bump = points_ledger.bump
+    )]
+    pub points_ledger: Option<Account<'info, PointsLedger>>,
+    
+    /// CHECK: Instructions sysvar, inspected for secp256k1 precompile signatures from observers
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProcessReturningNFT<'info>>,
+    source_chain_id: u64,
+    payload: CrossChainPayload,
+    zeta_tx_hash: [u8; 32],
+    observer_signatures: Vec<ObserverSignature>,
+) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_INBOUND)?;
+    FrozenAsset::require_not_frozen(&ctx.accounts.frozen_asset)?;
+    
+    // Gateway must be configured and recently maintained before messages can flow
+    ctx.accounts.gateway_state.require_live(Clock::get()?.unix_timestamp)?;
+    
+    // Validate source chain ID
+    if !ctx.accounts.gateway_state.is_chain_supported(ctx.accounts.chain_config.chain_index) {
+        return err!(UniversalNFTError::ChainDisabled);
+    }
+    if source_chain_id == ZETA_CHAIN_ID_SOLANA {
+        return err!(UniversalNFTError::InvalidZetaChainID);
+    }
+    
+    let clock = Clock::get()?;
+    
+    // Validate the cross-chain payload
+    payload.validate()?;
+    payload.require_fresh(clock.unix_timestamp, ctx.accounts.gateway_state.message_expiry_window)?;
+    
+    // The observer set must have signed this exact message, inline or through the secp256k1 precompile
+    let digest = incoming_message_digest(source_chain_id, &zeta_tx_hash, &payload.hash()?);
+    let precompile_addresses = secp256k1_verified_addresses(&ctx.accounts.instructions, &digest)?;
+    ctx.accounts.gateway_state.verify_observer_signatures(&digest, &observer_signatures, &precompile_addresses)?;
+    
+    // Bound what a compromised gateway key can release; inbound messages carry no lamports
+    ctx.accounts.rate_limiter.record(true, clock.unix_timestamp, 0)?;
+    
+    // The receiving wallet may not be denied
+    ctx.accounts.denylist.require_allowed(ctx.accounts.recipient.key().as_ref())?;
+    
+    let message = payload.to_latest();
+    
+    // Every unit sent out comes back together, since the transfer state is closed on return
+    if message.amount != ctx.accounts.transfer_state.amount() {
+        return err!(UniversalNFTError::InvalidAmount);
+    }
+    match (ctx.accounts.source_balance.as_mut(), ctx.accounts.solana_balance.as_mut()) {
+        (Some(source_balance), Some(solana_balance)) => {
+            source_balance.debit(message.amount)?;
+            solana_balance.credit(message.amount)?;
+        }
+        _ if message.amount > 1 => return err!(UniversalNFTError::MissingTokenBalance),
+        _ => {}
+    }
+    
+    // Release the original token from escrow
+    let escrow_bump = ctx.bumps.escrow_authority;
+    let escrow_seeds: &[&[u8]] = &[ESCROW_AUTHORITY_SEED, &[escrow_bump]];
+    let escrow_signer = &[escrow_seeds];
+    
+    match ProgrammableTransfer::from_remaining(ctx.remaining_accounts, &ctx.accounts.nft_mint.key())? {
+        // pNFTs leave escrow through token metadata so their token records follow them
+        Some(pnft) => pnft.transfer(
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.escrow_token_account.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.recipient_token_account.to_account_info(),
+            &ctx.accounts.recipient.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.gateway_authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+            escrow_signer,
+        )?,
+        None => {
+            transfer_hook::transfer_amount(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.escrow_token_account.to_account_info(),
+                &ctx.accounts.nft_mint.to_account_info(),
+                &ctx.accounts.recipient_token_account.to_account_info(),
+                &ctx.accounts.escrow_authority.to_account_info(),
+                ctx.accounts.mint_authority.as_ref().map(|a| a.to_account_info()),
+                escrow_signer,
+                message.amount,
+            )?;
+        }
+    }
+    
+    // Hand ownership to the recipient, keeping the original metadata
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.owner = ctx.accounts.recipient.key();
+    nft_metadata.locked = false;
+    nft_metadata.cross_chain_data_hash = payload.hash()?;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    
+    // Append the hop to the token ID's multichain history
+    let provenance_bump = ctx.bumps.provenance;
+    ctx.accounts.provenance.record(message.token_id, provenance_bump, ProvenanceHop {
+        chain_id: source_chain_id,
+        outbound: false,
+        tx_hash: zeta_tx_hash,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    // Record the ownership change for activity feeds
+    let transfer_history = &mut ctx.accounts.transfer_history;
+    transfer_history.mint = ctx.accounts.nft_mint.key();
+    transfer_history.bump = ctx.bumps.transfer_history;
+    transfer_history.account_version = ACCOUNT_VERSION;
+    transfer_history.push(TransferRecord {
+        owner: ctx.accounts.recipient.key(),
+        timestamp: clock.unix_timestamp,
+        kind: TransferKind::CrossChain,
+    });
+    
+    // The NFT is back on Solana
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_minted = program_state.total_minted.checked_add(1).ok_or(error!(UniversalNFTError::SupplyOverflow))?;
+    
+    // Credit rewards now that the Solana-origin NFT has completed a round-trip
+    if let (Some(rewards_config), Some(points_ledger)) = (&ctx.accounts.rewards_config, &mut ctx.accounts.points_ledger) {
+        let points = rewards_config.points_per_round_trip;
+        if points > 0 {
+            points_ledger.balance = points_ledger.balance.checked_add(points).ok_or(error!(UniversalNFTError::PointsOverflow))?;
+            points_ledger.lifetime_earned = points_ledger.lifetime_earned.checked_add(points).ok_or(error!(UniversalNFTError::PointsOverflow))?;
+            points_ledger.round_trips = points_ledger.round_trips.saturating_add(1);
+            points_ledger.last_credited_at = clock.unix_timestamp;
+            
+            emit!(PointsCredited {
+                wallet: points_ledger.wallet,
+                token_id: message.token_id,
+                points,
+                balance: points_ledger.balance,
+            });
+        }
+    }
+    
+    msg!("Returning NFT released to original mint");
+    msg!("Mint address: {}", ctx.accounts.nft_mint.key());
+    msg!("Recipient: {}", ctx.accounts.recipient.key());
+    msg!("Token ID: {:?}", message.token_id);
+    msg!("Source chain: {}", source_chain_id);
+    msg!("ZetaChain TX: {:?}", zeta_tx_hash);
+    
+    Ok(())
+}
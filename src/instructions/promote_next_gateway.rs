@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ZetaChainGatewayState, ProgramState},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct PromoteNextGateway<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<PromoteNextGateway>) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let clock = Clock::get()?;
+    
+    let gateway_state = &mut ctx.accounts.gateway_state;
+    if gateway_state.next_gateway_authority == Pubkey::default() || clock.unix_timestamp < gateway_state.next_valid_from {
+        return err!(UniversalNFTError::NextGatewayNotActive);
+    }
+    
+    // Complete the migration: the next gateway becomes the only accepted gateway
+    gateway_state.gateway_address = gateway_state.next_gateway_address;
+    gateway_state.gateway_authority = gateway_state.next_gateway_authority;
+    gateway_state.current_valid_until = i64::MAX;
+    gateway_state.next_gateway_address = DEFAULT_GATEWAY_ADDRESS;
+    gateway_state.next_gateway_authority = Pubkey::default();
+    gateway_state.next_valid_from = i64::MAX;
+    gateway_state.updated_at = clock.unix_timestamp;
+    
+    msg!("Next gateway promoted to current");
+    msg!("Gateway address: {:?}", gateway_state.gateway_address);
+    msg!("Gateway authority: {}", gateway_state.gateway_authority);
+    
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::ProgramState,
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Proposing the default key cancels a pending rotation; the current authority is a no-op
+    if new_authority == ctx.accounts.program_state.authority {
+        return err!(UniversalNFTError::InvalidPendingAuthority);
+    }
+    
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.pending_authority = new_authority;
+    
+    msg!("Authority rotation proposed");
+    msg!("Current authority: {}", program_state.authority);
+    msg!("Pending authority: {}", new_authority);
+    
+    Ok(())
+}
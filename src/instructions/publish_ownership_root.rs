@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ZetaChainGatewayState, ChainConfig, OwnershipRoot},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct PublishOwnershipRoot<'info> {
+    #[account(
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    #[account(
+        seeds = [CHAIN_CONFIG_SEED, &chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = gateway_authority,
+        space = OwnershipRoot::LEN,
+        seeds = [OWNERSHIP_ROOT_SEED, &chain_id.to_le_bytes()],
+        bump
+    )]
+    pub ownership_root: Account<'info, OwnershipRoot>,
+    
+    #[account(
+        mut,
+        constraint = gateway_state.is_gateway_authority(&gateway_authority.key(), Clock::get()?.unix_timestamp) @ UniversalNFTError::Unauthorized
+    )]
+    pub gateway_authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<PublishOwnershipRoot>,
+    chain_id: u64,
+    root: [u8; 32],
+) -> Result<()> {
+    // Gateway must be configured before it can publish roots
+    if !ctx.accounts.gateway_state.is_configured() {
+        return err!(UniversalNFTError::GatewayNotConfigured);
+    }
+    
+    // Roots describe ownership on remote chains only
+    if !ctx.accounts.gateway_state.is_chain_supported(ctx.accounts.chain_config.chain_index) {
+        return err!(UniversalNFTError::ChainDisabled);
+    }
+    if chain_id == ZETA_CHAIN_ID_SOLANA {
+        return err!(UniversalNFTError::InvalidZetaChainID);
+    }
+    
+    let clock = Clock::get()?;
+    
+    let ownership_root = &mut ctx.accounts.ownership_root;
+    ownership_root.chain_id = chain_id;
+    ownership_root.root = root;
+    ownership_root.published_at = clock.unix_timestamp;
+    ownership_root.bump = ctx.bumps.ownership_root;
+    ownership_root.account_version = ACCOUNT_VERSION;
+    
+    msg!("Ownership root published");
+    msg!("Chain ID: {}", chain_id);
+    msg!("Root: {:?}", root);
+    
+    Ok(())
+}
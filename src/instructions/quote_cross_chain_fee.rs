@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token::TokenAccount,
+    token_interface::Mint,
+};
+
+use crate::{
+    state::{ChainConfig, CrossChainTransferState, OutboxMessage, MessageLane},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(target_chain_id: u64)]
+pub struct QuoteCrossChainFee<'info> {
+    #[account(
+        seeds = [CHAIN_CONFIG_SEED, &target_chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Escrow token account for the mint; only its balance is read to decide whether rent is due
+    #[account(
+        address = get_associated_token_address_with_program_id(
+            &find_escrow_authority().0,
+            &nft_mint.key(),
+            nft_mint.to_account_info().owner,
+        ) @ UniversalNFTError::InvalidTokenAccount
+    )]
+    pub escrow_token_account: UncheckedAccount<'info>,
+}
+
+/// Cost breakdown of an outbound transfer, in lamports
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FeeQuote {
+    pub protocol_fee: u64, // Transfer fee plus the lane surcharge
+    pub destination_gas: u64,
+    pub rent: u64, // Transfer state, outbox message and (first time only) escrow token account
+    pub total: u64,
+}
+
+pub fn handler(
+    ctx: Context<QuoteCrossChainFee>,
+    target_chain_id: u64,
+    lane: MessageLane,
+    gas_amount: u64,
+) -> Result<FeeQuote> {
+    if !ctx.accounts.chain_config.enabled {
+        return err!(UniversalNFTError::ChainDisabled);
+    }
+    if target_chain_id == ZETA_CHAIN_ID_SOLANA {
+        return err!(UniversalNFTError::UnsupportedTargetChain);
+    }
+    
+    let lane_fee = match lane {
+        MessageLane::Standard => 0,
+        MessageLane::Priority => PRIORITY_LANE_FEE,
+    };
+    let protocol_fee = CROSS_CHAIN_TRANSFER_FEE + lane_fee;
+    
+    // Rent for the accounts cross_chain_transfer creates
+    let rent = Rent::get()?;
+    let mut rent_due = rent.minimum_balance(CrossChainTransferState::LEN) + rent.minimum_balance(OutboxMessage::LEN);
+    if ctx.accounts.escrow_token_account.lamports() == 0 {
+        rent_due += rent.minimum_balance(TokenAccount::LEN);
+    }
+    
+    let quote = FeeQuote {
+        protocol_fee,
+        destination_gas: gas_amount,
+        rent: rent_due,
+        total: protocol_fee + gas_amount + rent_due,
+    };
+    
+    msg!("Cross-chain fee quote");
+    msg!("Target chain: {}", target_chain_id);
+    msg!("Protocol fee: {}", quote.protocol_fee);
+    msg!("Destination gas: {}", quote.destination_gas);
+    msg!("Rent: {}", quote.rent);
+    msg!("Total: {}", quote.total);
+    
+    Ok(quote)
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, NFTMetadata, NFTOrigin},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(token_id: [u8; 32])]
+pub struct RebuildRegistryEntry<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [NFT_METADATA_SEED, nft_metadata.mint.as_ref()],
+        bump = nft_metadata.bump,
+        constraint = nft_metadata.token_id == token_id @ UniversalNFTError::NFTNotFound
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = NFTOrigin::LEN,
+        seeds = [TOKEN_ID_SEED, token_id.as_ref()],
+        bump
+    )]
+    pub nft_origin: Account<'info, NFTOrigin>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<RebuildRegistryEntry>,
+    token_id: [u8; 32],
+    proof_data: Vec<u8>,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Validate proof data length
+    if proof_data.is_empty() || proof_data.len() > MAX_CROSS_CHAIN_DATA_LENGTH {
+        return err!(UniversalNFTError::InvalidProofData);
+    }
+    
+    // The proof must be the cross-chain data the NFT was created with
+    let proof_hash = anchor_lang::solana_program::keccak::hash(&proof_data).to_bytes();
+    if proof_hash != ctx.accounts.nft_metadata.cross_chain_data_hash {
+        return err!(UniversalNFTError::CrossChainDataHashMismatch);
+    }
+    
+    let nft_metadata = &ctx.accounts.nft_metadata;
+    let nft_origin = &mut ctx.accounts.nft_origin;
+    let was_present = nft_origin.token_id != [0u8; 32];
+    
+    // Rebuild the token ID index from the primary metadata record
+    nft_origin.token_id = token_id;
+    nft_origin.original_mint = nft_metadata.mint;
+    nft_origin.original_metadata_uri = nft_metadata.metadata_uri.clone();
+    nft_origin.source_chain_id = nft_metadata.zeta_chain_id;
+    nft_origin.created_at = nft_metadata.created_at;
+    nft_origin.bump = ctx.bumps.nft_origin;
+    nft_origin.account_version = ACCOUNT_VERSION;
+    
+    msg!("Registry entry rebuilt");
+    msg!("Token ID: {:?}", token_id);
+    msg!("Mint: {}", nft_origin.original_mint);
+    msg!("Source chain: {}", nft_origin.source_chain_id);
+    msg!("Previously present: {}", was_present);
+    
+    Ok(())
+}
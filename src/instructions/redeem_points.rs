@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke};
+
+use crate::{
+    state::{RewardsConfig, PointsLedger},
+    errors::UniversalNFTError,
+    events::PointsRedeemed,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct RedeemPoints<'info> {
+    #[account(
+        seeds = [REWARDS_CONFIG_SEED],
+        bump = rewards_config.bump
+    )]
+    pub rewards_config: Account<'info, RewardsConfig>,
+    
+    #[account(
+        mut,
+        seeds = [POINTS_SEED, wallet.key().as_ref()],
+        bump = points_ledger.bump,
+        has_one = wallet @ UniversalNFTError::Unauthorized
+    )]
+    pub points_ledger: Account<'info, PointsLedger>,
+    
+    /// CHECK: Must match the configured redemption hook program
+    #[account(
+        executable,
+        constraint = redemption_hook.key() == rewards_config.redemption_hook @ UniversalNFTError::InvalidRedemptionHook
+    )]
+    pub redemption_hook: UncheckedAccount<'info>,
+    
+    pub wallet: Signer<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedeemPoints<'info>>,
+    points: u64,
+) -> Result<()> {
+    // Redemption requires a configured hook
+    if ctx.accounts.rewards_config.redemption_hook == Pubkey::default() {
+        return err!(UniversalNFTError::InvalidRedemptionHook);
+    }
+    
+    // Check balance
+    let points_ledger = &mut ctx.accounts.points_ledger;
+    if points == 0 || points > points_ledger.balance {
+        return err!(UniversalNFTError::InsufficientPoints);
+    }
+    points_ledger.balance -= points;
+    
+    // Notify the redemption hook: data = wallet || points, accounts = wallet + remaining accounts
+    let mut data = Vec::with_capacity(40);
+    data.extend_from_slice(ctx.accounts.wallet.key().as_ref());
+    data.extend_from_slice(&points.to_le_bytes());
+    
+    let mut account_metas = vec![AccountMeta::new_readonly(ctx.accounts.wallet.key(), true)];
+    let mut account_infos = vec![ctx.accounts.wallet.to_account_info()];
+    for account in ctx.remaining_accounts.iter() {
+        account_metas.push(if account.is_writable {
+            AccountMeta::new(account.key(), account.is_signer)
+        } else {
+            AccountMeta::new_readonly(account.key(), account.is_signer)
+        });
+        account_infos.push(account.clone());
+    }
+    
+    let instruction = Instruction {
+        program_id: ctx.accounts.redemption_hook.key(),
+        accounts: account_metas,
+        data,
+    };
+    invoke(&instruction, &account_infos)?;
+    
+    emit!(PointsRedeemed {
+        wallet: ctx.accounts.wallet.key(),
+        points,
+        balance: points_ledger.balance,
+        redemption_hook: ctx.accounts.redemption_hook.key(),
+    });
+    
+    msg!("Points redeemed");
+    msg!("Wallet: {}", ctx.accounts.wallet.key());
+    msg!("Points: {}", points);
+    msg!("Remaining balance: {}", points_ledger.balance);
+    
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, CollectionState, AssetBackend},
+    errors::UniversalNFTError,
+    events::CollectionRegistered,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(collection_id: u64)]
+pub struct RegisterCollection<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = CollectionState::LEN,
+        seeds = [COLLECTION_STATE_SEED, &collection_id.to_le_bytes()],
+        bump
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterCollection>,
+    collection_id: u64,
+    collection_authority: Pubkey,
+    base_uri: String,
+    max_supply: u64,
+    allowed_chain_mask: u128,
+    asset_backend: AssetBackend,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Validate base URI length
+    if base_uri.len() > MAX_METADATA_URI_LENGTH {
+        return err!(UniversalNFTError::InvalidMetadataURILength);
+    }
+    
+    // Validate max supply
+    if max_supply == 0 {
+        return err!(UniversalNFTError::MaxSupplyExceeded);
+    }
+    
+    let clock = Clock::get()?;
+    
+    let collection_state = &mut ctx.accounts.collection_state;
+    collection_state.collection_id = collection_id;
+    collection_state.authority = collection_authority;
+    collection_state.base_uri = base_uri;
+    collection_state.max_supply = max_supply;
+    collection_state.total_minted = 0;
+    collection_state.allowed_chain_mask = allowed_chain_mask;
+    collection_state.asset_backend = asset_backend;
+    collection_state.merkle_tree = Pubkey::default(); // Set by create_collection_tree
+    collection_state.price_mint = Pubkey::default();
+    collection_state.price_amount = 0; // Free until set_collection_price
+    collection_state.created_at = clock.unix_timestamp;
+    collection_state.updated_at = clock.unix_timestamp;
+    collection_state.bump = ctx.bumps.collection_state;
+    collection_state.account_version = ACCOUNT_VERSION;
+    
+    emit!(CollectionRegistered {
+        collection: collection_state.key(),
+        collection_id,
+        authority: collection_authority,
+        max_supply,
+        registered_at: clock.unix_timestamp,
+    });
+    
+    msg!("Collection registered");
+    msg!("Collection ID: {}", collection_id);
+    msg!("Authority: {}", collection_authority);
+    msg!("Max supply: {}", max_supply);
+    msg!("Core assets: {}", asset_backend == AssetBackend::Core);
+    
+    Ok(())
+}
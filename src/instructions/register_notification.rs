@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::NotificationRegistration,
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct RegisterNotification<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = NotificationRegistration::LEN,
+        seeds = [NOTIFICATION_SEED, wallet.key().as_ref()],
+        bump
+    )]
+    pub registration: Account<'info, NotificationRegistration>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub wallet: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterNotification>,
+    commitment: [u8; 32],
+) -> Result<()> {
+    // Validate commitment
+    if commitment == [0u8; 32] {
+        return err!(UniversalNFTError::InvalidNotificationCommitment);
+    }
+    
+    let clock = Clock::get()?;
+    
+    let registration = &mut ctx.accounts.registration;
+    registration.wallet = ctx.accounts.wallet.key();
+    registration.commitment = commitment;
+    registration.registered_at = clock.unix_timestamp;
+    registration.bump = ctx.bumps.registration;
+    registration.account_version = ACCOUNT_VERSION;
+    
+    msg!("Notification registration updated");
+    msg!("Wallet: {}", ctx.accounts.wallet.key());
+    msg!("Commitment: {:?}", commitment);
+    
+    Ok(())
+}
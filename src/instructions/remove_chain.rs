@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, ZetaChainGatewayState, ChainConfig},
+    errors::UniversalNFTError,
+    events::ChainRemoved,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct RemoveChain<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [CHAIN_CONFIG_SEED, &chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveChain>, chain_id: u64) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let clock = Clock::get()?;
+    
+    // Free the chain's bit for reuse
+    let gateway_state = &mut ctx.accounts.gateway_state;
+    gateway_state.release_chain_index(ctx.accounts.chain_config.chain_index);
+    gateway_state.updated_at = clock.unix_timestamp;
+    
+    emit!(ChainRemoved {
+        chain_id,
+        removed_at: clock.unix_timestamp,
+    });
+    
+    msg!("Chain removed");
+    msg!("Chain ID: {}", chain_id);
+    msg!("Name: {}", ctx.accounts.chain_config.name);
+    
+    Ok(())
+}
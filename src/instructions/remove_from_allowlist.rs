@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, AllowlistEntry},
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct RemoveFromAllowlist<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [ALLOWLIST_SEED, wallet.as_ref()],
+        bump = allowlist_entry.bump
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<RemoveFromAllowlist>,
+    wallet: Pubkey,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    msg!("Wallet removed from mint allowlist");
+    msg!("Wallet: {}", wallet);
+    
+    Ok(())
+}
@@ -0,0 +1,194 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::{ProgramState, NFTMetadata, CrossChainTransferState, TransferStatus, TransferHistory, TransferRecord, TransferKind},
+    errors::UniversalNFTError,
+    transfer_hook,
+    programmable::ProgrammableTransfer,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct ResolveStuckTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [CROSS_CHAIN_TRANSFER_SEED, nft_mint.key().as_ref()],
+        bump = transfer_state.bump,
+        constraint = transfer_state.status == TransferStatus::InProgress @ UniversalNFTError::InvalidTransferStatus
+    )]
+    pub transfer_state: Account<'info, CrossChainTransferState>,
+    
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        constraint = nft_mint.key() == transfer_state.nft_mint @ UniversalNFTError::MintMismatch
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: PDA that owns escrowed NFTs while a cross-chain transfer is pending
+    #[account(
+        seeds = [ESCROW_AUTHORITY_SEED],
+        bump
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = escrow_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = nft_mint,
+        associated_token::authority = original_owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Validated against the sender recorded in the transfer state
+    #[account(
+        mut,
+        constraint = original_owner.key() == transfer_state.sender @ UniversalNFTError::SenderMismatch
+    )]
+    pub original_owner: UncheckedAccount<'info>,
+    
+    /// Latest ownership changes of the NFT
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TransferHistory::LEN,
+        seeds = [TRANSFER_HISTORY_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_history: Account<'info, TransferHistory>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// CHECK: Transfer hook authority PDA, required for Token-2022 NFTs that use this program's hook
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: Option<UncheckedAccount<'info>>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ResolveStuckTransfer<'info>>) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let clock = Clock::get()?;
+    
+    // Only transfers the gateway has clearly abandoned can be force-failed
+    let elapsed = clock.unix_timestamp - ctx.accounts.transfer_state.created_at;
+    if elapsed < MIN_STUCK_TRANSFER_AGE {
+        return err!(UniversalNFTError::TransferTimeoutNotReached);
+    }
+    
+    // Release the NFT from escrow to the original owner
+    let escrow_bump = ctx.bumps.escrow_authority;
+    let escrow_seeds: &[&[u8]] = &[ESCROW_AUTHORITY_SEED, &[escrow_bump]];
+    let escrow_signer = &[escrow_seeds];
+    
+    match ProgrammableTransfer::from_remaining(ctx.remaining_accounts, &ctx.accounts.nft_mint.key())? {
+        // pNFTs leave escrow through token metadata so their token records follow them
+        Some(pnft) => pnft.transfer(
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.escrow_token_account.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.owner_token_account.to_account_info(),
+            &ctx.accounts.original_owner.to_account_info(),
+            &ctx.accounts.escrow_authority.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+            escrow_signer,
+        )?,
+        None => {
+            transfer_hook::transfer_amount(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.escrow_token_account.to_account_info(),
+                &ctx.accounts.nft_mint.to_account_info(),
+                &ctx.accounts.owner_token_account.to_account_info(),
+                &ctx.accounts.escrow_authority.to_account_info(),
+                ctx.accounts.mint_authority.as_ref().map(|a| a.to_account_info()),
+                escrow_signer,
+                ctx.accounts.transfer_state.amount(),
+            )?;
+        }
+    }
+    
+    // Record the ownership change for activity feeds
+    let transfer_history = &mut ctx.accounts.transfer_history;
+    transfer_history.mint = ctx.accounts.nft_mint.key();
+    transfer_history.bump = ctx.bumps.transfer_history;
+    transfer_history.account_version = ACCOUNT_VERSION;
+    transfer_history.push(TransferRecord {
+        owner: ctx.accounts.original_owner.key(),
+        timestamp: clock.unix_timestamp,
+        kind: TransferKind::CrossChain,
+    });
+    
+    // Restore ownership record
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.owner = ctx.accounts.original_owner.key();
+    nft_metadata.locked = false;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    
+    // Refund the destination gas deposit
+    let gas_amount = ctx.accounts.transfer_state.gas_amount;
+    if gas_amount > 0 {
+        **ctx.accounts.transfer_state.to_account_info().try_borrow_mut_lamports()? -= gas_amount;
+        **ctx.accounts.original_owner.to_account_info().try_borrow_mut_lamports()? += gas_amount;
+    }
+    
+    // Mark transfer as failed; the record stays for auditing
+    let transfer_state = &mut ctx.accounts.transfer_state;
+    transfer_state.status = TransferStatus::Failed;
+    transfer_state.gas_amount = 0;
+    
+    // The NFT is back on Solana
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_minted = program_state.total_minted.checked_add(1).ok_or(error!(UniversalNFTError::SupplyOverflow))?;
+    
+    msg!("Stuck cross-chain transfer resolved");
+    msg!("NFT: {}", ctx.accounts.nft_mint.key());
+    msg!("Token ID: {:?}", transfer_state.token_id);
+    msg!("Returned to: {}", ctx.accounts.original_owner.key());
+    msg!("Pending for: {} seconds", elapsed);
+    msg!("Gas refunded: {} lamports", gas_amount);
+    msg!("Status: Failed");
+    
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::instruction::AuthorityType,
+    token_interface::{self, Mint, SetAuthority, TokenInterface},
+};
+
+use crate::{
+    state::NFTMetadata,
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct RevokeMintAuthority<'info> {
+    /// Proves the mint was issued by this program
+    #[account(
+        seeds = [NFT_METADATA_SEED, mint.key().as_ref()],
+        bump = nft_metadata.bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        mut,
+        constraint = mint.mint_authority == Some(mint_authority.key()).into() @ UniversalNFTError::MintAuthorityRevoked
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: PDA used as mint and freeze authority for program-minted assets
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Anyone can revoke, since it only ever fixes a mint's supply
+    pub caller: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<RevokeMintAuthority>) -> Result<()> {
+    // Mints issued before the authority was dropped at mint time still name the PDA
+    let mint_authority_bump = ctx.bumps.mint_authority;
+    let mint_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[mint_authority_bump]];
+    
+    token_interface::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.mint_authority.to_account_info(),
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[mint_authority_seeds],
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+    
+    msg!("Mint authority revoked");
+    msg!("Mint: {}", ctx.accounts.mint.key());
+    msg!("Supply: {}", ctx.accounts.mint.supply);
+    
+    Ok(())
+}
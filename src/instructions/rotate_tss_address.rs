@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ZetaChainGatewayState, ProgramState},
+    errors::UniversalNFTError,
+    events::TssAddressRotated,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct RotateTssAddress<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<RotateTssAddress>,
+    tss_authority: Pubkey,
+    activation_time: i64,
+    grace_period: i64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    if tss_authority == Pubkey::default() {
+        return err!(UniversalNFTError::InvalidGatewayWindow);
+    }
+    
+    if !(MIN_TSS_GRACE_PERIOD..=MAX_TSS_GRACE_PERIOD).contains(&grace_period) {
+        return err!(UniversalNFTError::InvalidGracePeriod);
+    }
+    
+    let clock = Clock::get()?;
+    
+    if activation_time < clock.unix_timestamp {
+        return err!(UniversalNFTError::InvalidGatewayWindow);
+    }
+    
+    let gateway_state = &mut ctx.accounts.gateway_state;
+    
+    // Only the current key can be rotated; finish any scheduled migration with promote_next_gateway first
+    if gateway_state.gateway_authority == Pubkey::default() || gateway_state.next_gateway_authority != Pubkey::default() {
+        return err!(UniversalNFTError::GatewayRotationPending);
+    }
+    if tss_authority == gateway_state.gateway_authority {
+        return err!(UniversalNFTError::InvalidGatewayWindow);
+    }
+    
+    let previous_valid_until = activation_time.checked_add(grace_period).ok_or(error!(UniversalNFTError::InvalidGracePeriod))?;
+    
+    // The gateway contract is unchanged; only its signer rotates, with the old key honoured through the grace period
+    let previous_authority = gateway_state.gateway_authority;
+    gateway_state.next_gateway_address = gateway_state.gateway_address;
+    gateway_state.next_gateway_authority = tss_authority;
+    gateway_state.next_valid_from = activation_time;
+    gateway_state.current_valid_until = previous_valid_until;
+    gateway_state.updated_at = clock.unix_timestamp;
+    
+    emit!(TssAddressRotated {
+        previous_authority,
+        new_authority: tss_authority,
+        activation_time,
+        previous_valid_until,
+    });
+    
+    msg!("TSS address rotation scheduled");
+    msg!("Previous authority: {}", previous_authority);
+    msg!("New authority: {}", tss_authority);
+    msg!("Activates at: {}", activation_time);
+    msg!("Previous key valid until: {}", previous_valid_until);
+    
+    Ok(())
+}
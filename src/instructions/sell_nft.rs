@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::{ProgramState, PauseConfig, Denylist, NFTMetadata, TransferHistory, TransferRecord, TransferKind},
+    errors::UniversalNFTError,
+    events::NFTSold,
+    transfer_hook,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SellNFT<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+    
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump,
+        has_one = owner @ UniversalNFTError::NotNFTOwner
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        mut,
+        constraint = nft_mint.key() == nft_metadata.mint @ UniversalNFTError::MintMismatch
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+        constraint = owner_token_account.amount == 1 @ UniversalNFTError::TokenNotHeld,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program,
+    )]
+    pub buyer_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Seller; receives the price less the royalty
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// Latest ownership changes of the NFT
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TransferHistory::LEN,
+        seeds = [TRANSFER_HISTORY_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_history: Account<'info, TransferHistory>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Pays the price and receives the NFT
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// CHECK: Creator paid the royalty, checked against the NFT metadata; required when a royalty is due
+    #[account(mut)]
+    pub royalty_recipient: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Transfer hook authority PDA, required for Token-2022 NFTs that use this program's hook
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: Option<UncheckedAccount<'info>>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<SellNFT>,
+    price: u64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.program_state.require_feature(FEATURE_MARKETPLACE)?;
+    ctx.accounts.pause_config.require_active(PAUSE_TRANSFER)?;
+    ctx.accounts.denylist.require_allowed(ctx.accounts.buyer.key().as_ref())?;
+    ctx.accounts.nft_metadata.require_unlocked()?;
+    
+    let clock = Clock::get()?;
+    
+    // The buyer pays the creator's royalty out of the price and the rest to the seller, so sales
+    // through the program honour royalties even under an enforced royalty policy
+    let royalty = settle_sale(
+        &ctx.accounts.nft_metadata,
+        ctx.accounts.royalty_recipient.as_ref(),
+        &ctx.accounts.buyer.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        price,
+    )?;
+    
+    // Transfer NFT from seller to buyer
+    transfer_hook::transfer_nft(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.owner_token_account.to_account_info(),
+        &ctx.accounts.nft_mint.to_account_info(),
+        &ctx.accounts.buyer_token_account.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        ctx.accounts.mint_authority.as_ref().map(|a| a.to_account_info()),
+        &[],
+    )?;
+    
+    // Record the ownership change for activity feeds
+    let transfer_history = &mut ctx.accounts.transfer_history;
+    transfer_history.mint = ctx.accounts.nft_mint.key();
+    transfer_history.bump = ctx.bumps.transfer_history;
+    transfer_history.account_version = ACCOUNT_VERSION;
+    transfer_history.push(TransferRecord {
+        owner: ctx.accounts.buyer.key(),
+        timestamp: clock.unix_timestamp,
+        kind: TransferKind::Local,
+    });
+    
+    // Update NFT metadata
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.owner = ctx.accounts.buyer.key();
+    nft_metadata.updated_at = clock.unix_timestamp;
+    
+    emit!(NFTSold {
+        mint: ctx.accounts.nft_mint.key(),
+        seller: ctx.accounts.owner.key(),
+        buyer: ctx.accounts.buyer.key(),
+        price,
+        royalty,
+        royalty_recipient: nft_metadata.royalty_recipient,
+        sold_at: clock.unix_timestamp,
+    });
+    
+    msg!("NFT sold");
+    msg!("Seller: {}", ctx.accounts.owner.key());
+    msg!("Buyer: {}", ctx.accounts.buyer.key());
+    msg!("NFT: {}", ctx.accounts.nft_mint.key());
+    msg!("Price: {} lamports", price);
+    msg!("Royalty: {} lamports", royalty);
+    
+    Ok(())
+}
+
+/// Pay `price` lamports from `buyer`: the NFT's royalty to its royalty recipient and the rest to `seller`.
+/// Returns the royalty paid
+pub fn settle_sale<'info>(
+    nft_metadata: &NFTMetadata,
+    royalty_recipient: Option<&UncheckedAccount<'info>>,
+    buyer: &AccountInfo<'info>,
+    seller: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    price: u64,
+) -> Result<u64> {
+    let royalty = nft_metadata.royalty_for(price);
+    if royalty > 0 {
+        pay_royalty(nft_metadata, royalty_recipient, buyer, system_program, royalty)?;
+    }
+    let proceeds = price - royalty;
+    if proceeds > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: buyer.clone(),
+                    to: seller.clone(),
+                },
+            ),
+            proceeds,
+        )?;
+    }
+    Ok(royalty)
+}
+
+/// Pay `royalty` lamports from `payer` to the NFT's royalty recipient
+fn pay_royalty<'info>(
+    nft_metadata: &NFTMetadata,
+    royalty_recipient: Option<&UncheckedAccount<'info>>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    royalty: u64,
+) -> Result<()> {
+    let royalty_recipient = royalty_recipient.ok_or(error!(UniversalNFTError::InvalidRoyaltyRecipient))?;
+    if royalty_recipient.key() != nft_metadata.royalty_recipient {
+        return err!(UniversalNFTError::InvalidRoyaltyRecipient);
+    }
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            system_program.clone(),
+            anchor_lang::system_program::Transfer {
+                from: payer.clone(),
+                to: royalty_recipient.to_account_info(),
+            },
+        ),
+        royalty,
+    )?;
+    msg!("Royalty paid: {} lamports to {}", royalty, royalty_recipient.key());
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, CollectionConfig},
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetAllowlistRoot<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetAllowlistRoot>,
+    allowlist_root: [u8; 32],
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Mint counts are kept per wallet, so a wallet keeps its used mints when the root is replaced
+    let collection_config = &mut ctx.accounts.collection_config;
+    collection_config.allowlist_root = allowlist_root;
+    collection_config.updated_at = Clock::get()?.unix_timestamp;
+    
+    msg!("Allowlist root updated");
+    msg!("Root: {:?}", allowlist_root);
+    msg!("Merkle allowlist enabled: {}", allowlist_root != [0u8; 32]);
+    
+    Ok(())
+}
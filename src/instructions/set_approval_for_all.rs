@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::OperatorApproval,
+    errors::UniversalNFTError,
+    events::ApprovalForAll,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(operator: Pubkey)]
+pub struct SetApprovalForAll<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OperatorApproval::LEN,
+        seeds = [OPERATOR_APPROVAL_SEED, owner.key().as_ref(), operator.as_ref()],
+        bump
+    )]
+    pub operator_approval: Account<'info, OperatorApproval>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetApprovalForAll>,
+    operator: Pubkey,
+    approved: bool,
+) -> Result<()> {
+    if operator == Pubkey::default() || operator == ctx.accounts.owner.key() {
+        return err!(UniversalNFTError::InvalidOperator);
+    }
+    
+    // Only records the grant; SPL delegation is per token account, so the operator can move an NFT
+    // once its owner delegates that token account to the program with set_program_delegation
+    let operator_approval = &mut ctx.accounts.operator_approval;
+    operator_approval.owner = ctx.accounts.owner.key();
+    operator_approval.operator = operator;
+    operator_approval.approved = approved;
+    operator_approval.bump = ctx.bumps.operator_approval;
+    operator_approval.account_version = ACCOUNT_VERSION;
+    
+    emit!(ApprovalForAll {
+        owner: operator_approval.owner,
+        operator,
+        approved,
+    });
+    
+    msg!("Operator approval set");
+    msg!("Owner: {}", operator_approval.owner);
+    msg!("Operator: {}", operator);
+    msg!("Approved: {}", approved);
+    
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::ProgramState,
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetAuthorityProgram<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetAuthorityProgram>,
+    authority_program: Pubkey,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Admin calls relayed by this program would never see it as the outer instruction
+    if authority_program == crate::ID {
+        return err!(UniversalNFTError::InvalidAuthorityProgram);
+    }
+    
+    let program_state = &mut ctx.accounts.program_state;
+    let previous_program = program_state.authority_program;
+    program_state.authority_program = authority_program;
+    
+    msg!("Authority program updated");
+    msg!("Previous program: {}", previous_program);
+    msg!("New program: {}", authority_program);
+    
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, ZetaChainGatewayState, ChainConfig},
+    errors::UniversalNFTError,
+    events::ChainStatusChanged,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct SetChainEnabled<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    #[account(
+        mut,
+        seeds = [CHAIN_CONFIG_SEED, &chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetChainEnabled>,
+    chain_id: u64,
+    enabled: bool,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let clock = Clock::get()?;
+    
+    // Only this route changes; the registry entry and other chains are untouched
+    let chain_config = &mut ctx.accounts.chain_config;
+    chain_config.enabled = enabled;
+    chain_config.updated_at = clock.unix_timestamp;
+    
+    let gateway_state = &mut ctx.accounts.gateway_state;
+    gateway_state.set_chain_supported(chain_config.chain_index, enabled);
+    gateway_state.updated_at = clock.unix_timestamp;
+    
+    emit!(ChainStatusChanged {
+        chain_id,
+        enabled,
+        changed_at: clock.unix_timestamp,
+    });
+    
+    msg!("Chain status updated");
+    msg!("Chain ID: {}", chain_id);
+    msg!("Enabled: {}", enabled);
+    
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ZetaChainGatewayState, ProgramState},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetChallengePeriod<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetChallengePeriod>,
+    challenge_period: i64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Zero disables escrow and finalizes deliveries instantly
+    if !(0..=MAX_CHALLENGE_PERIOD).contains(&challenge_period) {
+        return err!(UniversalNFTError::InvalidChallengePeriod);
+    }
+    
+    let clock = Clock::get()?;
+    
+    let gateway_state = &mut ctx.accounts.gateway_state;
+    gateway_state.challenge_period = challenge_period;
+    gateway_state.updated_at = clock.unix_timestamp;
+    
+    msg!("Challenge period updated");
+    msg!("Period: {} seconds", challenge_period);
+    
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::ProgramState,
+    errors::UniversalNFTError,
+    metaplex::validate_display_data,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetCollectionBranding<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetCollectionBranding>,
+    default_name: String,
+    default_symbol: String,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Defaults must themselves be valid Metaplex display data
+    if default_name.is_empty() {
+        return err!(UniversalNFTError::InvalidMetadataName);
+    }
+    if default_symbol.is_empty() {
+        return err!(UniversalNFTError::InvalidMetadataSymbol);
+    }
+    validate_display_data(&default_name, &default_symbol, 0, &[])?;
+    
+    // Already minted NFTs keep the name and symbol they were minted with
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.default_name = default_name;
+    program_state.default_symbol = default_symbol;
+    
+    msg!("Collection branding updated");
+    msg!("Default name: {}", program_state.default_name);
+    msg!("Default symbol: {}", program_state.default_symbol);
+    
+    Ok(())
+}
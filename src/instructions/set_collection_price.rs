@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::CollectionState,
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetCollectionPrice<'info> {
+    #[account(
+        mut,
+        seeds = [COLLECTION_STATE_SEED, &collection_state.collection_id.to_le_bytes()],
+        bump = collection_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetCollectionPrice>,
+    price_mint: Pubkey,
+    price_amount: u64,
+) -> Result<()> {
+    // A zero amount makes mints into the collection free again
+    let collection_state = &mut ctx.accounts.collection_state;
+    collection_state.price_mint = if price_amount == 0 { Pubkey::default() } else { price_mint };
+    collection_state.price_amount = price_amount;
+    collection_state.updated_at = Clock::get()?.unix_timestamp;
+    
+    msg!("Collection price updated");
+    msg!("Collection ID: {}", collection_state.collection_id);
+    msg!("Price mint: {}", collection_state.price_mint);
+    msg!("Price: {}", price_amount);
+    
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, CollectionConfig},
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetEmissionSchedule<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetEmissionSchedule>,
+    emission_start: i64,
+    emission_period: i64,
+    emission_per_period: u64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // A zero period clears the schedule; otherwise each period must release supply
+    if emission_period < 0 || (emission_period > 0 && emission_per_period == 0) {
+        return err!(UniversalNFTError::InvalidEmissionSchedule);
+    }
+    
+    let clock = Clock::get()?;
+    
+    let collection_config = &mut ctx.accounts.collection_config;
+    collection_config.emission_start = emission_start;
+    collection_config.emission_period = emission_period;
+    collection_config.emission_per_period = emission_per_period;
+    collection_config.updated_at = clock.unix_timestamp;
+    
+    msg!("Emission schedule updated");
+    msg!("Start: {}", emission_start);
+    msg!("Period: {} seconds", emission_period);
+    msg!("Per period: {}", emission_per_period);
+    
+    Ok(())
+}
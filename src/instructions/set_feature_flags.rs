@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::ProgramState,
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetFeatureFlags<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetFeatureFlags>,
+    feature_flags: u64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Reject unknown feature bits
+    if feature_flags & !FEATURE_ALL != 0 {
+        return err!(UniversalNFTError::InvalidFeatureFlags);
+    }
+    
+    let program_state = &mut ctx.accounts.program_state;
+    let previous_flags = program_state.feature_flags;
+    program_state.feature_flags = feature_flags;
+    
+    msg!("Feature flags updated");
+    msg!("Previous flags: {:#b}", previous_flags);
+    msg!("New flags: {:#b}", feature_flags);
+    
+    Ok(())
+}
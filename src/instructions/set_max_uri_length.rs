@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, CollectionConfig},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetMaxUriLength<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetMaxUriLength>,
+    max_uri_length: u16,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Existing NFTs keep their size; update_metadata grows them when a longer URI is set
+    if max_uri_length == 0 || max_uri_length as usize > MAX_METADATA_URI_LENGTH_LIMIT {
+        return err!(UniversalNFTError::InvalidMetadataURILength);
+    }
+    
+    let collection_config = &mut ctx.accounts.collection_config;
+    collection_config.max_uri_length = max_uri_length;
+    collection_config.updated_at = Clock::get()?.unix_timestamp;
+    
+    msg!("Maximum metadata URI length updated");
+    msg!("Max URI length: {}", max_uri_length);
+    
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ZetaChainGatewayState, ProgramState},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetMessageExpiryWindow<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetMessageExpiryWindow>,
+    message_expiry_window: i64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Validate window bounds
+    if !(MIN_MESSAGE_EXPIRY_WINDOW..=MAX_MESSAGE_EXPIRY_WINDOW).contains(&message_expiry_window) {
+        return err!(UniversalNFTError::InvalidMessageExpiryWindow);
+    }
+    
+    let gateway_state = &mut ctx.accounts.gateway_state;
+    gateway_state.message_expiry_window = message_expiry_window;
+    
+    msg!("Message expiry window updated");
+    msg!("Window: {} seconds", message_expiry_window);
+    
+    Ok(())
+}
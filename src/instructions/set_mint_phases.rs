@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, CollectionConfig, MintPhases},
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetMintPhases<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetMintPhases>,
+    mint_phases: MintPhases,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // All zeros clears the schedule; otherwise the allowlist phase comes first
+    if mint_phases != MintPhases::default() {
+        let ordered = mint_phases.allowlist_start > 0
+            && mint_phases.allowlist_start <= mint_phases.public_start
+            && (mint_phases.end == 0 || mint_phases.end > mint_phases.public_start);
+        if !ordered {
+            return err!(UniversalNFTError::InvalidMintPhases);
+        }
+    }
+    
+    // A new schedule starts its phase counts from zero
+    let collection_config = &mut ctx.accounts.collection_config;
+    collection_config.mint_phases = mint_phases;
+    collection_config.allowlist_phase_minted = 0;
+    collection_config.public_phase_minted = 0;
+    collection_config.updated_at = Clock::get()?.unix_timestamp;
+    
+    msg!("Mint phases updated");
+    msg!("Allowlist phase: {} ({} lamports, limit {})", mint_phases.allowlist_start, mint_phases.allowlist_price, mint_phases.allowlist_limit);
+    msg!("Public phase: {} ({} lamports, limit {})", mint_phases.public_start, mint_phases.public_price, mint_phases.public_limit);
+    msg!("End: {}", mint_phases.end);
+    
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, CollectionConfig, Treasury},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetMintPrice<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    /// Created with the first price so paid mints always have somewhere to pay
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Treasury::LEN,
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetMintPrice>,
+    mint_price: u64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // A new treasury starts with zeroed totals
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.bump = ctx.bumps.treasury;
+    treasury.account_version = ACCOUNT_VERSION;
+    
+    let collection_config = &mut ctx.accounts.collection_config;
+    collection_config.mint_price = mint_price;
+    collection_config.updated_at = Clock::get()?.unix_timestamp;
+    
+    msg!("Mint price updated");
+    msg!("Price: {} lamports", mint_price);
+    msg!("Treasury: {}", treasury.key());
+    
+    Ok(())
+}
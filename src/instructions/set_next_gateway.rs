@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ZetaChainGatewayState, ProgramState},
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetNextGateway<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetNextGateway>,
+    gateway_address: [u8; 20],
+    gateway_authority: Pubkey,
+    valid_from: i64,
+    current_valid_until: i64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    if gateway_authority == Pubkey::default() {
+        return err!(UniversalNFTError::InvalidGatewayWindow);
+    }
+    
+    let clock = Clock::get()?;
+    
+    // The windows must overlap so inbound messages are never rejected by both gateways
+    if valid_from > current_valid_until || current_valid_until < clock.unix_timestamp {
+        return err!(UniversalNFTError::InvalidGatewayWindow);
+    }
+    
+    // Schedule the next gateway and close the current gateway's window
+    let gateway_state = &mut ctx.accounts.gateway_state;
+    gateway_state.next_gateway_address = gateway_address;
+    gateway_state.next_gateway_authority = gateway_authority;
+    gateway_state.next_valid_from = valid_from;
+    gateway_state.current_valid_until = current_valid_until;
+    gateway_state.updated_at = clock.unix_timestamp;
+    
+    msg!("Next gateway scheduled");
+    msg!("Gateway address: {:?}", gateway_address);
+    msg!("Gateway authority: {}", gateway_authority);
+    msg!("Valid from: {}", valid_from);
+    msg!("Current gateway valid until: {}", current_valid_until);
+    
+    Ok(())
+}
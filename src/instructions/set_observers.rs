@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ZetaChainGatewayState, ProgramState},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetObservers<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetObservers>,
+    observers: Vec<[u8; 20]>,
+    threshold: u8,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Validate set size and threshold
+    if observers.is_empty() || observers.len() > MAX_OBSERVERS {
+        return err!(UniversalNFTError::InvalidObserverSet);
+    }
+    if threshold == 0 || threshold as usize > observers.len() {
+        return err!(UniversalNFTError::InvalidObserverSet);
+    }
+    
+    // Each observer must be a distinct, non-zero address so the threshold counts real signers
+    for (i, observer) in observers.iter().enumerate() {
+        if *observer == [0u8; 20] || observers[..i].contains(observer) {
+            return err!(UniversalNFTError::InvalidObserverSet);
+        }
+    }
+    
+    let clock = Clock::get()?;
+    
+    let gateway_state = &mut ctx.accounts.gateway_state;
+    gateway_state.observers = [[0u8; 20]; MAX_OBSERVERS];
+    gateway_state.observers[..observers.len()].copy_from_slice(&observers);
+    gateway_state.observer_count = observers.len() as u8;
+    gateway_state.observer_threshold = threshold;
+    gateway_state.updated_at = clock.unix_timestamp;
+    
+    msg!("Observer set updated");
+    msg!("Observers: {}", observers.len());
+    msg!("Threshold: {}", threshold);
+    
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, PauseConfig},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetPausedOperations<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetPausedOperations>,
+    paused_operations: u64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Reject unknown operation bits
+    if paused_operations & !PAUSE_ALL != 0 {
+        return err!(UniversalNFTError::InvalidPauseFlags);
+    }
+    
+    let clock = Clock::get()?;
+    
+    let pause_config = &mut ctx.accounts.pause_config;
+    let previous_operations = pause_config.paused_operations;
+    pause_config.paused_operations = paused_operations;
+    pause_config.updated_at = clock.unix_timestamp;
+    
+    msg!("Paused operations updated");
+    msg!("Previous: {:#b}", previous_operations);
+    msg!("Paused: {:#b}", paused_operations);
+    
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Approve, Mint, Revoke, TokenAccount, TokenInterface};
+
+use crate::{
+    state::NFTMetadata,
+    errors::UniversalNFTError,
+    events::ProgramDelegation,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetProgramDelegation<'info> {
+    #[account(
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump,
+        has_one = owner @ UniversalNFTError::NotNFTOwner
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        constraint = nft_mint.key() == nft_metadata.mint @ UniversalNFTError::MintMismatch
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+        constraint = owner_token_account.amount == 1 @ UniversalNFTError::TokenNotHeld,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: PDA the owner's token account is delegated to, so approved operators and signed permits can move the NFT
+    #[account(
+        seeds = [TRANSFER_AUTHORITY_SEED],
+        bump
+    )]
+    pub transfer_authority: UncheckedAccount<'info>,
+    
+    pub owner: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(
+    ctx: Context<SetProgramDelegation>,
+    enabled: bool,
+) -> Result<()> {
+    // The delegation is what lets the program move the token; approvals and permits decide for whom
+    if enabled {
+        ctx.accounts.nft_metadata.require_unlocked()?;
+        token_interface::approve(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Approve {
+                    to: ctx.accounts.owner_token_account.to_account_info(),
+                    delegate: ctx.accounts.transfer_authority.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+    } else {
+        token_interface::revoke(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Revoke {
+                source: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ))?;
+    }
+    
+    emit!(ProgramDelegation {
+        mint: ctx.accounts.nft_mint.key(),
+        owner: ctx.accounts.owner.key(),
+        enabled,
+    });
+    
+    msg!("Program delegation set");
+    msg!("NFT: {}", ctx.accounts.nft_mint.key());
+    msg!("Owner: {}", ctx.accounts.owner.key());
+    msg!("Enabled: {}", enabled);
+    
+    Ok(())
+}
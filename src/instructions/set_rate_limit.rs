@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, RateLimiter},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetRateLimit<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [RATE_LIMITER_SEED],
+        bump = rate_limiter.bump
+    )]
+    pub rate_limiter: Account<'info, RateLimiter>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetRateLimit>,
+    max_transfers: u64,
+    max_value: u64,
+    window: i64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Validate window bounds
+    if !(MIN_RATE_LIMIT_WINDOW..=MAX_RATE_LIMIT_WINDOW).contains(&window) {
+        return err!(UniversalNFTError::InvalidRateLimit);
+    }
+    
+    let clock = Clock::get()?;
+    
+    let rate_limiter = &mut ctx.accounts.rate_limiter;
+    rate_limiter.max_transfers = max_transfers;
+    rate_limiter.max_value = max_value;
+    
+    // Counts taken under a different window length can't be carried over
+    if window != rate_limiter.window {
+        rate_limiter.window = window;
+        rate_limiter.outbound = Default::default();
+        rate_limiter.inbound = Default::default();
+    }
+    rate_limiter.updated_at = clock.unix_timestamp;
+    
+    msg!("Rate limit updated");
+    msg!("Max transfers per window: {}", max_transfers);
+    msg!("Max value per window: {}", max_value);
+    msg!("Window: {} seconds", window);
+    
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, CollectionConfig, RoyaltyPolicy},
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetRoyaltyPolicy<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetRoyaltyPolicy>,
+    royalty_policy: RoyaltyPolicy,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let clock = Clock::get()?;
+    
+    let collection_config = &mut ctx.accounts.collection_config;
+    collection_config.royalty_policy = royalty_policy;
+    collection_config.updated_at = clock.unix_timestamp;
+    
+    msg!("Royalty policy updated");
+    msg!("Enforced: {}", royalty_policy == RoyaltyPolicy::Enforced);
+    msg!("Updated at: {}", clock.unix_timestamp);
+    
+    Ok(())
+}
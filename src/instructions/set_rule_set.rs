@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, CollectionConfig},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetRuleSet<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    /// CHECK: Token auth rule set, or the default pubkey to go back to standard NFTs
+    pub rule_set: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SetRuleSet>) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // The rule set must be owned by the token auth rules program
+    let rule_set = ctx.accounts.rule_set.key();
+    if rule_set != Pubkey::default() && *ctx.accounts.rule_set.owner != TOKEN_AUTH_RULES_PROGRAM_ID {
+        return err!(UniversalNFTError::InvalidRuleSet);
+    }
+    
+    let clock = Clock::get()?;
+    
+    let collection_config = &mut ctx.accounts.collection_config;
+    collection_config.rule_set = rule_set;
+    collection_config.updated_at = clock.unix_timestamp;
+    
+    msg!("Rule set updated");
+    msg!("Rule set: {}", rule_set);
+    msg!("Programmable: {}", rule_set != Pubkey::default());
+    
+    Ok(())
+}
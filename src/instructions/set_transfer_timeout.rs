@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ZetaChainGatewayState, ProgramState},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetTransferTimeout<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetTransferTimeout>,
+    transfer_timeout: i64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Validate timeout bounds
+    if !(MIN_TRANSFER_TIMEOUT..=MAX_TRANSFER_TIMEOUT).contains(&transfer_timeout) {
+        return err!(UniversalNFTError::InvalidTransferTimeout);
+    }
+    
+    let gateway_state = &mut ctx.accounts.gateway_state;
+    gateway_state.transfer_timeout = transfer_timeout;
+    
+    msg!("Transfer timeout updated");
+    msg!("Timeout: {} seconds", transfer_timeout);
+    
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, CollectionConfig},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetUriSchemes<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetUriSchemes>,
+    allowed_uri_schemes: u8,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Only known schemes, and at least one of them
+    if allowed_uri_schemes == 0 || allowed_uri_schemes & !URI_SCHEME_ALL != 0 {
+        return err!(UniversalNFTError::InvalidUriSchemes);
+    }
+    
+    let collection_config = &mut ctx.accounts.collection_config;
+    collection_config.allowed_uri_schemes = allowed_uri_schemes;
+    collection_config.updated_at = Clock::get()?.unix_timestamp;
+    
+    msg!("Allowed URI schemes updated");
+    msg!("ipfs://: {}", allowed_uri_schemes & URI_SCHEME_IPFS != 0);
+    msg!("ar://: {}", allowed_uri_schemes & URI_SCHEME_ARWEAVE != 0);
+    msg!("https://: {}", allowed_uri_schemes & URI_SCHEME_HTTPS != 0);
+    
+    Ok(())
+}
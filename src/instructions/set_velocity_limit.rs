@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, RateLimiter},
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetVelocityLimit<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [RATE_LIMITER_SEED],
+        bump = rate_limiter.bump
+    )]
+    pub rate_limiter: Account<'info, RateLimiter>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetVelocityLimit>,
+    max_owner_transfers_per_day: u64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let clock = Clock::get()?;
+    
+    let rate_limiter = &mut ctx.accounts.rate_limiter;
+    rate_limiter.max_owner_transfers_per_day = max_owner_transfers_per_day;
+    rate_limiter.updated_at = clock.unix_timestamp;
+    
+    msg!("Per-wallet velocity limit updated");
+    msg!("Max transfers per wallet per day: {}", max_owner_transfers_per_day);
+    
+    Ok(())
+}
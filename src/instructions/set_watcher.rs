@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, PauseConfig},
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SetWatcher<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetWatcher>,
+    watcher: Pubkey,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let clock = Clock::get()?;
+    
+    // The default key removes the watcher role
+    let pause_config = &mut ctx.accounts.pause_config;
+    let previous_watcher = pause_config.watcher;
+    pause_config.watcher = watcher;
+    pause_config.updated_at = clock.unix_timestamp;
+    
+    msg!("Watcher updated");
+    msg!("Previous: {}", previous_watcher);
+    msg!("Watcher: {}", watcher);
+    
+    Ok(())
+}
@@ -4,21 +4,22 @@ use crate::{
     state::{ZetaChainGatewayState, ProgramState},
     errors::UniversalNFTError,
     constants::*,
+    seeds::*,
 };
 
 #[derive(Accounts)]
 pub struct SetupGateway<'info> {
     #[account(
         mut,
-        seeds = [b"program_state"],
+        seeds = [PROGRAM_STATE_SEED],
         bump = program_state.bump,
-        has_one = authority
+        has_one = authority @ UniversalNFTError::Unauthorized
     )]
     pub program_state: Account<'info, ProgramState>,
     
     #[account(
         mut,
-        seeds = [b"gateway_state"],
+        seeds = [GATEWAY_STATE_SEED],
         bump = gateway_state.bump
     )]
     pub gateway_state: Account<'info, ZetaChainGatewayState>,
@@ -26,19 +27,20 @@ pub struct SetupGateway<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
     ctx: Context<SetupGateway>,
     gateway_address: [u8; 20],
-    supported_chains: Vec<u64>,
+    gateway_authority: Pubkey,
     version: u8,
 ) -> Result<()> {
-    // Validate supported chains count
-    if supported_chains.len() > MAX_SUPPORTED_CHAINS {
-        return err!(UniversalNFTError::InvalidZetaChainID);
-    }
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
     
     // Validate version
     if version < GATEWAY_VERSION {
@@ -56,13 +58,17 @@ pub fn handler(
     // Update gateway state
     let gateway_state = &mut ctx.accounts.gateway_state;
     gateway_state.gateway_address = gateway_address;
-    gateway_state.supported_chains = supported_chains;
+    gateway_state.gateway_authority = gateway_authority;
+    gateway_state.current_valid_until = i64::MAX; // Explicit configuration ends any pending migration
+    gateway_state.next_gateway_address = DEFAULT_GATEWAY_ADDRESS;
+    gateway_state.next_gateway_authority = Pubkey::default();
+    gateway_state.next_valid_from = i64::MAX;
     gateway_state.version = version;
     gateway_state.updated_at = clock.unix_timestamp;
     
     msg!("Gateway configuration updated successfully");
     msg!("Gateway address: {:?}", gateway_address);
-    msg!("Supported chains: {}", gateway_state.supported_chains.len());
+    msg!("Gateway authority: {}", gateway_authority);
     msg!("Version: {}", version);
     msg!("Updated at: {}", clock.unix_timestamp);
     
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::{
+    state::{
+        NFTMetadata, NFTOrigin, CrossChainTransferState,
+        LegacyNFTMetadata, LegacyNFTOrigin, LegacyCrossChainTransferState,
+    },
+    errors::UniversalNFTError,
+    codec::token_id_from_u64,
+    instructions::migrate_token_id::read_legacy,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SimulateMigration<'info> {
+    /// CHECK: Any program-owned account; its layout is inspected read-only
+    #[account(owner = crate::ID @ UniversalNFTError::InvalidAccountOwner)]
+    pub account: UncheckedAccount<'info>,
+}
+
+/// Account types handled by migrate_token_id
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum MigratableAccount {
+    NFTMetadata,
+    NFTOrigin,
+    CrossChainTransferState,
+    Unsupported,
+}
+
+/// Dry-run result returned to the caller
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MigrationReport {
+    pub account_kind: MigratableAccount,
+    pub current_len: u32,
+    pub migrated_len: u32,
+    pub already_migrated: bool,
+    pub legacy_token_id: u64,
+    pub token_id: [u8; 32],
+    pub migrated_address: Pubkey, // Differs from the account for records that move to new seeds
+    pub additional_rent: u64,
+    pub error_code: Option<u32>,
+}
+
+pub fn handler(ctx: Context<SimulateMigration>) -> Result<MigrationReport> {
+    let account = ctx.accounts.account.to_account_info();
+    let current_len = account.data_len();
+    let discriminator = {
+        let data = account.try_borrow_data()?;
+        if data.len() < 8 {
+            return err!(UniversalNFTError::InvalidLegacyAccount);
+        }
+        let mut discriminator = [0u8; 8];
+        discriminator.copy_from_slice(&data[..8]);
+        discriminator
+    };
+    
+    let mut report = MigrationReport {
+        account_kind: MigratableAccount::Unsupported,
+        current_len: current_len as u32,
+        migrated_len: current_len as u32,
+        already_migrated: false,
+        legacy_token_id: 0,
+        token_id: [0u8; 32],
+        migrated_address: account.key(),
+        additional_rent: 0,
+        error_code: None,
+    };
+    
+    // Parse the legacy layout for the detected account type
+    let parsed = if discriminator == NFTMetadata::discriminator() {
+        report.account_kind = MigratableAccount::NFTMetadata;
+        report.migrated_len = NFTMetadata::LEN as u32;
+        read_legacy::<LegacyNFTMetadata>(&account, &discriminator, NFTMetadata::LEN).map(|legacy| legacy.token_id)
+    } else if discriminator == NFTOrigin::discriminator() {
+        report.account_kind = MigratableAccount::NFTOrigin;
+        report.migrated_len = NFTOrigin::LEN as u32;
+        read_legacy::<LegacyNFTOrigin>(&account, &discriminator, NFTOrigin::LEN).map(|legacy| legacy.token_id)
+    } else if discriminator == CrossChainTransferState::discriminator() {
+        report.account_kind = MigratableAccount::CrossChainTransferState;
+        report.migrated_len = CrossChainTransferState::LEN as u32;
+        read_legacy::<LegacyCrossChainTransferState>(&account, &discriminator, CrossChainTransferState::LEN).map(|legacy| legacy.token_id)
+    } else {
+        err!(UniversalNFTError::InvalidLegacyAccount)
+    };
+    
+    match parsed {
+        Ok(legacy_token_id) => {
+            report.legacy_token_id = legacy_token_id;
+            report.token_id = token_id_from_u64(legacy_token_id);
+            
+            // Origin records move to an address seeded by the uint256 token ID
+            if report.account_kind == MigratableAccount::NFTOrigin {
+                let (migrated_address, _) = find_nft_origin(&report.token_id);
+                report.migrated_address = migrated_address;
+            }
+            
+            let required_lamports = Rent::get()?.minimum_balance(report.migrated_len as usize);
+            report.additional_rent = required_lamports.saturating_sub(account.lamports());
+        }
+        Err(error) => {
+            report.error_code = match error {
+                Error::AnchorError(anchor_error) => Some(anchor_error.error_code_number),
+                Error::ProgramError(_) => None,
+            };
+            report.already_migrated = report.error_code == Some(u32::from(UniversalNFTError::AccountAlreadyMigrated));
+        }
+    }
+    
+    msg!("Migration dry-run for {}", account.key());
+    msg!("Account kind: {:?}", report.account_kind);
+    msg!("Size: {} -> {}", report.current_len, report.migrated_len);
+    msg!("Already migrated: {}", report.already_migrated);
+    msg!("Error code: {:?}", report.error_code);
+    
+    Ok(report)
+}
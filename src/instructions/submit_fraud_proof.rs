@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, Burn},
+};
+
+use crate::{
+    state::{ProgramState, ZetaChainGatewayState, PendingDelivery, RelayerRecord, NFTMetadata, CrossChainTransferState, TransferStatus, ObserverSignature},
+    errors::UniversalNFTError,
+    events::FraudProofAccepted,
+    payload::CrossChainPayload,
+    sigverify::secp256k1_verified_addresses,
+    instructions::process_incoming_nft::incoming_message_digest,
+    constants::*,
+    seeds::*,
+};
+
+/// Evidence that a pending incoming delivery is fraudulent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum FraudEvidence {
+    /// The observer quorum also signed a different payload for the same ZetaChain transaction
+    ConflictingSignature {
+        payload_hash: [u8; 32],
+        observer_signatures: Vec<ObserverSignature>,
+    },
+    /// The preimage of the delivered payload hash does not describe what was minted
+    PayloadMismatch {
+        payload: CrossChainPayload,
+    },
+}
+
+#[derive(Accounts)]
+pub struct SubmitFraudProof<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    #[account(
+        mut,
+        seeds = [PENDING_DELIVERY_SEED, nft_mint.key().as_ref()],
+        bump = pending_delivery.bump,
+        close = challenger
+    )]
+    pub pending_delivery: Account<'info, PendingDelivery>,
+    
+    #[account(
+        mut,
+        seeds = [CROSS_CHAIN_TRANSFER_SEED, nft_mint.key().as_ref()],
+        bump = transfer_state.bump,
+        constraint = transfer_state.status == TransferStatus::InProgress @ UniversalNFTError::InvalidTransferStatus
+    )]
+    pub transfer_state: Account<'info, CrossChainTransferState>,
+    
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(mut)]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: PDA that holds incoming NFTs while the challenge period runs
+    #[account(
+        seeds = [ESCROW_AUTHORITY_SEED],
+        bump
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = escrow_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = challenger,
+        space = RelayerRecord::LEN,
+        seeds = [RELAYER_RECORD_SEED, pending_delivery.relayer.as_ref()],
+        bump
+    )]
+    pub relayer_record: Account<'info, RelayerRecord>,
+    
+    /// Anyone holding valid evidence can challenge; receives the pending delivery rent
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected for secp256k1 precompile signatures from observers
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(
+    ctx: Context<SubmitFraudProof>,
+    evidence: FraudEvidence,
+) -> Result<()> {
+    let pending_delivery = &ctx.accounts.pending_delivery;
+    
+    match &evidence {
+        FraudEvidence::ConflictingSignature { payload_hash, observer_signatures } => {
+            if *payload_hash == pending_delivery.payload_hash {
+                return err!(UniversalNFTError::InvalidFraudProof);
+            }
+            
+            // A quorum signing two payloads for one transaction means the delivered one cannot be trusted
+            let digest = incoming_message_digest(pending_delivery.source_chain_id, &pending_delivery.zeta_tx_hash, payload_hash);
+            let precompile_addresses = secp256k1_verified_addresses(&ctx.accounts.instructions, &digest)?;
+            ctx.accounts.gateway_state.verify_observer_signatures(&digest, observer_signatures, &precompile_addresses)?;
+        }
+        FraudEvidence::PayloadMismatch { payload } => {
+            if payload.hash()? != pending_delivery.payload_hash {
+                return err!(UniversalNFTError::InvalidFraudProof);
+            }
+            
+            // Recipients addressed through a bound identity are not comparable here
+            let message = payload.to_latest();
+            let token_id_mismatch = message.token_id != ctx.accounts.nft_metadata.token_id;
+            let recipient_mismatch = message.recipient.len() == 32
+                && message.recipient != pending_delivery.recipient.to_bytes().to_vec();
+            if !token_id_mismatch && !recipient_mismatch {
+                return err!(UniversalNFTError::InvalidFraudProof);
+            }
+        }
+    }
+    
+    let clock = Clock::get()?;
+    
+    // Cancel the pending mint
+    let escrow_bump = ctx.bumps.escrow_authority;
+    let escrow_seeds: &[&[u8]] = &[ESCROW_AUTHORITY_SEED, &[escrow_bump]];
+    let escrow_signer = &[escrow_seeds];
+    
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.nft_mint.to_account_info(),
+        from: ctx.accounts.escrow_token_account.to_account_info(),
+        authority: ctx.accounts.escrow_authority.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, escrow_signer);
+    
+    anchor_spl::token_interface::burn(cpi_ctx, 1)?;
+    
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.owner = Pubkey::default();
+    nft_metadata.updated_at = clock.unix_timestamp;
+    
+    let transfer_state = &mut ctx.accounts.transfer_state;
+    transfer_state.status = TransferStatus::Failed;
+    
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_minted = program_state.total_minted.checked_sub(1).ok_or(error!(UniversalNFTError::SupplyUnderflow))?;
+    
+    // Flag the relayer that submitted the fraudulent message
+    let relayer_record = &mut ctx.accounts.relayer_record;
+    relayer_record.relayer = ctx.accounts.pending_delivery.relayer;
+    relayer_record.fraud_count = relayer_record.fraud_count.saturating_add(1);
+    relayer_record.flagged = true;
+    relayer_record.last_fraud_at = clock.unix_timestamp;
+    relayer_record.bump = ctx.bumps.relayer_record;
+    relayer_record.account_version = ACCOUNT_VERSION;
+    
+    emit!(FraudProofAccepted {
+        mint: ctx.accounts.nft_mint.key(),
+        relayer: relayer_record.relayer,
+        challenger: ctx.accounts.challenger.key(),
+        zeta_tx_hash: ctx.accounts.pending_delivery.zeta_tx_hash,
+        fraud_count: relayer_record.fraud_count,
+    });
+    
+    msg!("Fraud proof accepted; pending delivery cancelled");
+    msg!("NFT: {}", ctx.accounts.nft_mint.key());
+    msg!("Relayer flagged: {}", relayer_record.relayer);
+    msg!("Fraud count: {}", relayer_record.fraud_count);
+    msg!("Status: Failed");
+    
+    Ok(())
+}
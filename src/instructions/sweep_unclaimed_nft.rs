@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    state::{ProgramState, ClaimableNFT, NFTMetadata, TransferHistory, TransferRecord, TransferKind},
+    errors::UniversalNFTError,
+    events::UnclaimedNFTSwept,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct SweepUnclaimedNFT<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [CLAIMABLE_NFT_SEED, nft_mint.key().as_ref()],
+        bump = claimable_nft.bump,
+        close = relayer
+    )]
+    pub claimable_nft: Account<'info, ClaimableNFT>,
+    
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: PDA that holds incoming NFTs until they are claimed
+    #[account(
+        seeds = [ESCROW_AUTHORITY_SEED],
+        bump
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = escrow_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = nft_mint,
+        associated_token::authority = recovery,
+        associated_token::token_program = token_program,
+    )]
+    pub recovery_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Recovery wallet chosen by the authority; only used to derive its token account
+    pub recovery: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the claim record rent; validated against the recorded relayer
+    #[account(
+        mut,
+        constraint = relayer.key() == claimable_nft.relayer @ UniversalNFTError::Unauthorized
+    )]
+    pub relayer: UncheckedAccount<'info>,
+    
+    /// Latest ownership changes of the NFT
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TransferHistory::LEN,
+        seeds = [TRANSFER_HISTORY_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_history: Account<'info, TransferHistory>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<SweepUnclaimedNFT>) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let clock = Clock::get()?;
+    
+    // The recipient keeps the NFT's claim to itself until the claim window has passed
+    if !ctx.accounts.claimable_nft.is_expired(clock.unix_timestamp) {
+        return err!(UniversalNFTError::ClaimNotExpired);
+    }
+    
+    // Release the NFT from escrow to the recovery wallet
+    let escrow_bump = ctx.bumps.escrow_authority;
+    let escrow_seeds: &[&[u8]] = &[ESCROW_AUTHORITY_SEED, &[escrow_bump]];
+    let escrow_signer = &[escrow_seeds];
+    
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.escrow_token_account.to_account_info(),
+        mint: ctx.accounts.nft_mint.to_account_info(),
+        to: ctx.accounts.recovery_token_account.to_account_info(),
+        authority: ctx.accounts.escrow_authority.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, escrow_signer);
+    
+    anchor_spl::token_interface::transfer_checked(cpi_ctx, 1, SOLANA_DECIMALS)?;
+    
+    // Record the ownership change for activity feeds
+    let transfer_history = &mut ctx.accounts.transfer_history;
+    transfer_history.mint = ctx.accounts.nft_mint.key();
+    transfer_history.bump = ctx.bumps.transfer_history;
+    transfer_history.account_version = ACCOUNT_VERSION;
+    transfer_history.push(TransferRecord {
+        owner: ctx.accounts.recovery.key(),
+        timestamp: clock.unix_timestamp,
+        kind: TransferKind::CrossChain,
+    });
+    
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.owner = ctx.accounts.recovery.key();
+    nft_metadata.locked = false;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    
+    let claimable_nft = &ctx.accounts.claimable_nft;
+    emit!(UnclaimedNFTSwept {
+        mint: claimable_nft.mint,
+        recipient: claimable_nft.recipient,
+        recovery: ctx.accounts.recovery.key(),
+        token_id: claimable_nft.token_id,
+        swept_at: clock.unix_timestamp,
+    });
+    
+    msg!("Unclaimed incoming NFT swept");
+    msg!("NFT: {}", claimable_nft.mint);
+    msg!("Recipient: {}", claimable_nft.recipient);
+    msg!("Recovery: {}", ctx.accounts.recovery.key());
+    msg!("Delivered at: {}", claimable_nft.delivered_at);
+    
+    Ok(())
+}
@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::{ProgramState, PauseConfig, Denylist, NFTMetadata, CollectionConfig, RoyaltyPolicy, TokenApproval, OperatorApproval, TransferHistory, TransferRecord, TransferKind},
+    errors::UniversalNFTError,
+    transfer_hook,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(new_owner_key: Pubkey)]
+pub struct TransferFrom<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+    
+    #[account(
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump,
+        has_one = owner @ UniversalNFTError::NotNFTOwner
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        mut,
+        constraint = nft_mint.key() == nft_metadata.mint @ UniversalNFTError::MintMismatch
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+        constraint = owner_token_account.amount == 1 @ UniversalNFTError::TokenNotHeld,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = new_owner,
+        associated_token::token_program = token_program,
+    )]
+    pub new_owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Current NFT owner, checked against the NFT metadata; does not sign
+    pub owner: UncheckedAccount<'info>,
+    
+    /// CHECK: Recipient wallet; only used to derive its token account, so it need not sign
+    #[account(address = new_owner_key @ UniversalNFTError::InvalidRecipientAddress)]
+    pub new_owner: UncheckedAccount<'info>,
+    
+    /// Latest ownership changes of the NFT
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TransferHistory::LEN,
+        seeds = [TRANSFER_HISTORY_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_history: Account<'info, TransferHistory>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Approved address or operator moving the NFT
+    pub operator: Signer<'info>,
+    
+    /// Single-NFT approval; cleared by the transfer
+    #[account(
+        mut,
+        seeds = [TOKEN_APPROVAL_SEED, nft_mint.key().as_ref()],
+        bump = token_approval.bump
+    )]
+    pub token_approval: Option<Account<'info, TokenApproval>>,
+    
+    /// Operator approval for all of the owner's NFTs
+    #[account(
+        seeds = [OPERATOR_APPROVAL_SEED, owner.key().as_ref(), operator.key().as_ref()],
+        bump = operator_approval.bump
+    )]
+    pub operator_approval: Option<Account<'info, OperatorApproval>>,
+    
+    /// CHECK: PDA the owner's token account is delegated to by `approve` or `set_program_delegation`
+    #[account(
+        seeds = [TRANSFER_AUTHORITY_SEED],
+        bump
+    )]
+    pub transfer_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Transfer hook authority PDA, required for Token-2022 NFTs that use this program's hook
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: Option<UncheckedAccount<'info>>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<TransferFrom>,
+    _new_owner_key: Pubkey,
+) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.program_state.require_feature(FEATURE_MARKETPLACE)?;
+    ctx.accounts.pause_config.require_active(PAUSE_TRANSFER)?;
+    ctx.accounts.denylist.require_allowed(ctx.accounts.new_owner.key().as_ref())?;
+    ctx.accounts.nft_metadata.require_unlocked()?;
+    
+    // A plain SPL transfer pays no royalties; enforced collections must use the royalty-enforcing path
+    if ctx.accounts.collection_config.royalty_policy == RoyaltyPolicy::Enforced {
+        return err!(UniversalNFTError::RoyaltyEnforced);
+    }
+    
+    // The operator needs the NFT's approval or an operator approval from its current owner
+    let owner = ctx.accounts.owner.key();
+    let operator = ctx.accounts.operator.key();
+    let approved_for_token = ctx.accounts.token_approval.as_ref().is_some_and(|approval| approval.allows(&owner, &operator));
+    let approved_for_all = ctx.accounts.operator_approval.as_ref().is_some_and(|approval| approval.approved);
+    if !approved_for_token && !approved_for_all {
+        return err!(UniversalNFTError::NotApproved);
+    }
+    
+    // The program can only move the token while the owner's token account delegates it, via approve or set_program_delegation
+    let transfer_authority = ctx.accounts.transfer_authority.key();
+    if Option::<Pubkey>::from(ctx.accounts.owner_token_account.delegate) != Some(transfer_authority)
+        || ctx.accounts.owner_token_account.delegated_amount < 1
+    {
+        return err!(UniversalNFTError::NotDelegatedToProgram);
+    }
+    
+    let clock = Clock::get()?;
+    
+    // Transfer NFT from current owner to new owner, signed by the delegate PDA
+    let transfer_authority_bump = ctx.bumps.transfer_authority;
+    let transfer_authority_seeds: &[&[u8]] = &[TRANSFER_AUTHORITY_SEED, &[transfer_authority_bump]];
+    transfer_hook::transfer_nft(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.owner_token_account.to_account_info(),
+        &ctx.accounts.nft_mint.to_account_info(),
+        &ctx.accounts.new_owner_token_account.to_account_info(),
+        &ctx.accounts.transfer_authority.to_account_info(),
+        ctx.accounts.mint_authority.as_ref().map(|a| a.to_account_info()),
+        &[transfer_authority_seeds],
+    )?;
+    
+    // Approvals don't carry over to the new owner, as in ERC-721
+    if let Some(token_approval) = ctx.accounts.token_approval.as_mut() {
+        token_approval.approved = Pubkey::default();
+    }
+    
+    // Record the ownership change for activity feeds
+    let transfer_history = &mut ctx.accounts.transfer_history;
+    transfer_history.mint = ctx.accounts.nft_mint.key();
+    transfer_history.bump = ctx.bumps.transfer_history;
+    transfer_history.account_version = ACCOUNT_VERSION;
+    transfer_history.push(TransferRecord {
+        owner: ctx.accounts.new_owner.key(),
+        timestamp: clock.unix_timestamp,
+        kind: TransferKind::Local,
+    });
+    
+    // Update NFT metadata
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.owner = ctx.accounts.new_owner.key();
+    nft_metadata.updated_at = clock.unix_timestamp;
+    
+    msg!("NFT transferred by operator");
+    msg!("From: {}", owner);
+    msg!("To: {}", ctx.accounts.new_owner.key());
+    msg!("Operator: {}", operator);
+    msg!("NFT: {}", ctx.accounts.nft_mint.key());
+    
+    Ok(())
+}
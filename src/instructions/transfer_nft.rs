@@ -1,83 +1,214 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::{Mint, Token, TokenAccount, Transfer},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{
-    state::{NFTMetadata},
+    state::{ProgramState, PauseConfig, Denylist, NFTMetadata, CollectionConfig, RoyaltyPolicy, TransferHistory, TransferRecord, TransferKind},
     errors::UniversalNFTError,
+    events::NFTTransferred,
+    instructions::sell_nft::settle_sale,
+    transfer_hook,
+    constants::*,
+    seeds::*,
 };
 
 #[derive(Accounts)]
+#[instruction(new_owner_key: Pubkey)]
 pub struct TransferNFT<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+    
+    #[account(
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
     #[account(
         mut,
-        seeds = [b"nft_metadata", nft_mint.key().as_ref()],
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
         bump = nft_metadata.bump,
-        has_one = owner
+        has_one = owner @ UniversalNFTError::NotNFTOwner
     )]
     pub nft_metadata: Account<'info, NFTMetadata>,
     
     #[account(
         mut,
-        constraint = nft_mint.key() == nft_metadata.mint
+        constraint = nft_mint.key() == nft_metadata.mint @ UniversalNFTError::MintMismatch
     )]
-    pub nft_mint: Account<'info, Mint>,
+    pub nft_mint: InterfaceAccount<'info, Mint>,
     
     #[account(
         mut,
         associated_token::mint = nft_mint,
         associated_token::authority = owner,
+        associated_token::token_program = token_program,
+        constraint = owner_token_account.amount >= 1 @ UniversalNFTError::TokenNotHeld,
     )]
-    pub owner_token_account: Account<'info, TokenAccount>,
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
     
     #[account(
         init_if_needed,
-        payer = new_owner,
+        payer = payer,
         associated_token::mint = nft_mint,
         associated_token::authority = new_owner,
+        associated_token::token_program = token_program,
+    )]
+    pub new_owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Latest ownership changes of the NFT
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TransferHistory::LEN,
+        seeds = [TRANSFER_HISTORY_SEED, nft_mint.key().as_ref()],
+        bump
     )]
-    pub new_owner_token_account: Account<'info, TokenAccount>,
+    pub transfer_history: Account<'info, TransferHistory>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
     
     #[account(mut)]
     pub owner: Signer<'info>,
     
+    /// CHECK: Recipient wallet; only used to derive its token account, so it need not sign
+    #[account(address = new_owner_key @ UniversalNFTError::InvalidRecipientAddress)]
+    pub new_owner: UncheckedAccount<'info>,
+    
+    /// The recipient signing to pay `sale_price`; required when the transfer is a sale
+    #[account(mut)]
+    pub buyer: Option<Signer<'info>>,
+    
+    /// CHECK: Creator paid the royalty on `sale_price`, checked against the NFT metadata
     #[account(mut)]
-    pub new_owner: Signer<'info>,
+    pub royalty_recipient: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Transfer hook authority PDA, required for Token-2022 NFTs that use this program's hook
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: Option<UncheckedAccount<'info>>,
     
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
 }
 
 pub fn handler(
     ctx: Context<TransferNFT>,
-    _new_owner: Pubkey,
+    _new_owner_key: Pubkey,
+    memo: Option<String>,
+    sale_price: u64,
+    amount: u64,
 ) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_TRANSFER)?;
+    ctx.accounts.denylist.require_allowed(ctx.accounts.new_owner.key().as_ref())?;
+    ctx.accounts.nft_metadata.require_unlocked()?;
+    
+    let memo = memo.unwrap_or_default();
+    if memo.len() > MAX_MEMO_LENGTH {
+        return err!(UniversalNFTError::InvalidMemo);
+    }
+    
+    // Without a sale price the transfer pays no royalties, and a gift looks the same as a sale settled
+    // elsewhere, so enforced collections only move through transfer_nft as a royalty-paying sale
+    if ctx.accounts.collection_config.royalty_policy == RoyaltyPolicy::Enforced && sale_price == 0 {
+        return err!(UniversalNFTError::RoyaltyEnforced);
+    }
+    
+    // Semi-fungible holders may move part of their balance
+    let held = ctx.accounts.owner_token_account.amount;
+    if amount == 0 || amount > held {
+        return err!(UniversalNFTError::InvalidAmount);
+    }
+    
     let clock = Clock::get()?;
     
-    // Transfer NFT from current owner to new owner
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.owner_token_account.to_account_info(),
-        to: ctx.accounts.new_owner_token_account.to_account_info(),
-        authority: ctx.accounts.owner.to_account_info(),
+    // A sale attached to the transfer is paid by the recipient: the creator's royalty, then the rest to the sender
+    let royalty = if sale_price > 0 {
+        let buyer = ctx.accounts.buyer.as_ref().ok_or(error!(UniversalNFTError::InvalidBuyer))?;
+        if buyer.key() != ctx.accounts.new_owner.key() {
+            return err!(UniversalNFTError::InvalidBuyer);
+        }
+        settle_sale(
+            &ctx.accounts.nft_metadata,
+            ctx.accounts.royalty_recipient.as_ref(),
+            &buyer.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            sale_price,
+        )?
+    } else {
+        0
     };
     
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    // Transfer NFT from current owner to new owner
+    transfer_hook::transfer_amount(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.owner_token_account.to_account_info(),
+        &ctx.accounts.nft_mint.to_account_info(),
+        &ctx.accounts.new_owner_token_account.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        ctx.accounts.mint_authority.as_ref().map(|a| a.to_account_info()),
+        &[],
+        amount,
+    )?;
     
-    anchor_spl::token::transfer(cpi_ctx, 1)?;
+    // Record the ownership change for activity feeds
+    let transfer_history = &mut ctx.accounts.transfer_history;
+    transfer_history.mint = ctx.accounts.nft_mint.key();
+    transfer_history.bump = ctx.bumps.transfer_history;
+    transfer_history.account_version = ACCOUNT_VERSION;
+    transfer_history.push(TransferRecord {
+        owner: ctx.accounts.new_owner.key(),
+        timestamp: clock.unix_timestamp,
+        kind: TransferKind::Local,
+    });
     
-    // Update NFT metadata
+    // Update NFT metadata; the owner of record only changes once the whole balance has moved
     let nft_metadata = &mut ctx.accounts.nft_metadata;
-    nft_metadata.owner = ctx.accounts.new_owner.key();
+    if amount == held {
+        nft_metadata.owner = ctx.accounts.new_owner.key();
+    }
     nft_metadata.updated_at = clock.unix_timestamp;
     
+    emit!(NFTTransferred {
+        mint: ctx.accounts.nft_mint.key(),
+        from: ctx.accounts.owner.key(),
+        to: ctx.accounts.new_owner.key(),
+        memo,
+        transferred_at: clock.unix_timestamp,
+    });
+    
     msg!("NFT transferred successfully");
     msg!("From: {}", ctx.accounts.owner.key());
     msg!("To: {}", ctx.accounts.new_owner.key());
     msg!("NFT: {}", ctx.accounts.nft_mint.key());
+    msg!("Amount: {}", amount);
+    if sale_price > 0 {
+        msg!("Sale price: {} lamports", sale_price);
+        msg!("Royalty: {} lamports", royalty);
+    }
     
     Ok(())
 }
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::IdentityBinding,
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct UnbindIdentity<'info> {
+    #[account(
+        mut,
+        close = wallet,
+        seeds = [IDENTITY_SEED, identity_binding.remote_address.as_ref()],
+        bump = identity_binding.bump,
+        has_one = wallet @ UniversalNFTError::Unauthorized
+    )]
+    pub identity_binding: Account<'info, IdentityBinding>,
+    
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnbindIdentity>) -> Result<()> {
+    msg!("Identity unbound");
+    msg!("Wallet: {}", ctx.accounts.wallet.key());
+    msg!("Remote address: {:?}", ctx.accounts.identity_binding.remote_address);
+    
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, FrozenAsset},
+    errors::UniversalNFTError,
+    events::AssetFreezeChanged,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct UnfreezeAsset<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [FROZEN_ASSET_SEED, mint.as_ref()],
+        bump = frozen_asset.bump
+    )]
+    pub frozen_asset: Account<'info, FrozenAsset>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UnfreezeAsset>,
+    mint: Pubkey,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let clock = Clock::get()?;
+    
+    emit!(AssetFreezeChanged {
+        mint,
+        frozen: false,
+        changed_by: ctx.accounts.authority.key(),
+        changed_at: clock.unix_timestamp,
+    });
+    
+    msg!("Asset cleared after review");
+    msg!("Mint: {}", mint);
+    msg!("Frozen by: {}", ctx.accounts.frozen_asset.frozen_by);
+    
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, NFTMetadata},
+    errors::UniversalNFTError,
+    events::NFTLockChanged,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct UnlockNFT<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [NFT_METADATA_SEED, nft_metadata.mint.as_ref()],
+        bump = nft_metadata.bump,
+        constraint = caller.key() == nft_metadata.owner || caller.key() == program_state.authority @ UniversalNFTError::Unauthorized
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    /// The NFT owner, or the program authority overriding an owner lock
+    pub caller: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<UnlockNFT>) -> Result<()> {
+    // Authority overrides go through the same checks as other admin calls
+    if ctx.accounts.caller.key() != ctx.accounts.nft_metadata.owner {
+        ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    }
+    
+    let clock = Clock::get()?;
+    
+    // Only the owner lock is cleared; cross-chain locks are released by their own flows
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    if !nft_metadata.owner_locked {
+        return err!(UniversalNFTError::NFTNotLocked);
+    }
+    
+    nft_metadata.owner_locked = false;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    
+    emit!(NFTLockChanged {
+        mint: nft_metadata.mint,
+        locked: false,
+        changed_by: ctx.accounts.caller.key(),
+        changed_at: clock.unix_timestamp,
+    });
+    
+    msg!("NFT unlocked");
+    msg!("NFT: {}", nft_metadata.mint);
+    msg!("Unlocked by: {}", ctx.accounts.caller.key());
+    
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::NotificationRegistration,
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct UnregisterNotification<'info> {
+    #[account(
+        mut,
+        close = wallet,
+        seeds = [NOTIFICATION_SEED, wallet.key().as_ref()],
+        bump = registration.bump,
+        has_one = wallet @ UniversalNFTError::Unauthorized
+    )]
+    pub registration: Account<'info, NotificationRegistration>,
+    
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnregisterNotification>) -> Result<()> {
+    msg!("Notification registration removed");
+    msg!("Wallet: {}", ctx.accounts.wallet.key());
+    
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, ZetaChainGatewayState, ChainConfig, AddressFormat},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct UpdateChain<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [GATEWAY_STATE_SEED],
+        bump = gateway_state.bump
+    )]
+    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    
+    #[account(
+        mut,
+        seeds = [CHAIN_CONFIG_SEED, &chain_id.to_le_bytes()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<UpdateChain>,
+    _chain_id: u64,
+    name: String,
+    address_format: AddressFormat,
+    required_confirmations: u32,
+    default_gas_limit: u64,
+    max_gas_limit: u64,
+    enabled: bool,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // Validate chain name
+    if name.is_empty() || name.len() > MAX_CHAIN_NAME_LENGTH {
+        return err!(UniversalNFTError::InvalidChainName);
+    }
+    
+    // The default must itself be a valid gas limit
+    if max_gas_limit == 0 || default_gas_limit > max_gas_limit {
+        return err!(UniversalNFTError::InvalidGasLimit);
+    }
+    
+    let clock = Clock::get()?;
+    
+    let chain_config = &mut ctx.accounts.chain_config;
+    chain_config.name = name;
+    chain_config.address_format = address_format;
+    chain_config.required_confirmations = required_confirmations;
+    chain_config.default_gas_limit = default_gas_limit;
+    chain_config.max_gas_limit = max_gas_limit;
+    chain_config.enabled = enabled;
+    chain_config.updated_at = clock.unix_timestamp;
+    
+    let gateway_state = &mut ctx.accounts.gateway_state;
+    gateway_state.set_chain_supported(chain_config.chain_index, enabled);
+    gateway_state.updated_at = clock.unix_timestamp;
+    
+    msg!("Chain configuration updated");
+    msg!("Chain ID: {}", chain_config.chain_id);
+    msg!("Name: {}", chain_config.name);
+    msg!("Required confirmations: {}", required_confirmations);
+    msg!("Default gas limit: {}", default_gas_limit);
+    msg!("Max gas limit: {}", max_gas_limit);
+    msg!("Enabled: {}", enabled);
+    
+    Ok(())
+}
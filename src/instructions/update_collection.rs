@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{CollectionState, AssetBackend},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct UpdateCollection<'info> {
+    #[account(
+        mut,
+        seeds = [COLLECTION_STATE_SEED, &collection_state.collection_id.to_le_bytes()],
+        bump = collection_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateCollection>,
+    new_authority: Pubkey,
+    base_uri: String,
+    max_supply: u64,
+    allowed_chain_mask: u128,
+    asset_backend: AssetBackend,
+) -> Result<()> {
+    // Validate base URI length
+    if base_uri.len() > MAX_METADATA_URI_LENGTH {
+        return err!(UniversalNFTError::InvalidMetadataURILength);
+    }
+    
+    // Supply can't drop below what has already been issued
+    let collection_state = &mut ctx.accounts.collection_state;
+    if max_supply == 0 || max_supply < collection_state.total_minted {
+        return err!(UniversalNFTError::MaxSupplyExceeded);
+    }
+    
+    collection_state.authority = new_authority;
+    collection_state.base_uri = base_uri;
+    collection_state.max_supply = max_supply;
+    collection_state.allowed_chain_mask = allowed_chain_mask;
+    collection_state.asset_backend = asset_backend;
+    collection_state.updated_at = Clock::get()?.unix_timestamp;
+    
+    msg!("Collection updated");
+    msg!("Collection ID: {}", collection_state.collection_id);
+    msg!("Authority: {}", new_authority);
+    msg!("Max supply: {}", max_supply);
+    msg!("Core assets: {}", asset_backend == AssetBackend::Core);
+    
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, Denylist},
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct UpdateDenylist<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [DENYLIST_SEED],
+        bump = denylist.bump
+    )]
+    pub denylist: Account<'info, Denylist>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateDenylist>,
+    address_hash: [u8; 32],
+    denied: bool,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    let clock = Clock::get()?;
+    
+    // Entries are keccak(address bytes) so Solana keys and remote-chain addresses share one list
+    let denylist = &mut ctx.accounts.denylist;
+    let position = denylist.entries.iter().position(|entry| *entry == address_hash);
+    match (denied, position) {
+        (true, None) => {
+            if denylist.entries.len() >= MAX_DENYLIST_ENTRIES {
+                return err!(UniversalNFTError::DenylistFull);
+            }
+            denylist.entries.push(address_hash);
+        }
+        (false, Some(index)) => {
+            denylist.entries.swap_remove(index);
+        }
+        _ => {} // Already in the requested state
+    }
+    denylist.updated_at = clock.unix_timestamp;
+    
+    msg!("Denylist updated");
+    msg!("Address hash: {:?}", address_hash);
+    msg!("Denied: {}", denied);
+    msg!("Entries: {}", denylist.entries.len());
+    
+    Ok(())
+}
@@ -1,44 +1,117 @@
 use anchor_lang::prelude::*;
+use anchor_spl::metadata::{
+    mpl_token_metadata::{accounts::Metadata as MetaplexMetadata, types::DataV2},
+    update_metadata_accounts_v2,
+    Metadata,
+    UpdateMetadataAccountsV2,
+};
 
 use crate::{
-    state::{NFTMetadata},
+    state::{NFTMetadata, CollectionConfig},
     errors::UniversalNFTError,
-    constants::*,
+    seeds::*,
 };
 
 #[derive(Accounts)]
+#[instruction(new_metadata_uri: String)]
 pub struct UpdateMetadata<'info> {
+    #[account(
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
     #[account(
         mut,
-        seeds = [b"nft_metadata", nft_mint.key().as_ref()],
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
         bump = nft_metadata.bump,
-        has_one = owner
+        has_one = owner @ UniversalNFTError::NotNFTOwner,
+        realloc = NFTMetadata::space(new_metadata_uri.len()).max(nft_metadata.to_account_info().data_len()),
+        realloc::payer = owner,
+        realloc::zero = false
     )]
     pub nft_metadata: Account<'info, NFTMetadata>,
     
     #[account(mut)]
     pub nft_mint: Account<'info, anchor_spl::token::Mint>,
     
+    /// CHECK: Metaplex metadata PDA, updated by the token metadata program
+    #[account(
+        mut,
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), nft_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA that is the Metaplex update authority of program-minted NFTs
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub update_authority: UncheckedAccount<'info>,
+    
     #[account(mut)]
     pub owner: Signer<'info>,
     
     pub system_program: Program<'info, System>,
+    pub token_metadata_program: Program<'info, Metadata>,
 }
 
 pub fn handler(
     ctx: Context<UpdateMetadata>,
     new_metadata_uri: String,
+    new_metadata_hash: [u8; 32],
 ) -> Result<()> {
-    // Validate metadata URI length
-    if new_metadata_uri.len() > MAX_METADATA_URI_LENGTH {
-        return err!(UniversalNFTError::InvalidMetadataURILength);
-    }
+    ctx.accounts.nft_metadata.require_unlocked()?;
+    ctx.accounts.nft_metadata.require_mutable()?;
+    
+    // Validate the new URI; the account was already grown to fit it
+    ctx.accounts.collection_config.require_uri_length(&new_metadata_uri)?;
+    ctx.accounts.collection_config.require_allowed_uri(&new_metadata_uri)?;
     
     let clock = Clock::get()?;
     
+    // Point the Metaplex metadata at the new URI, keeping every other field
+    let current = {
+        let data = ctx.accounts.metadata.try_borrow_data()?;
+        MetaplexMetadata::safe_deserialize(&data).map_err(|_| error!(UniversalNFTError::InvalidMetaplexMetadata))?
+    };
+    if current.update_authority != ctx.accounts.update_authority.key() {
+        return err!(UniversalNFTError::InvalidMetaplexMetadata);
+    }
+    let data_v2 = DataV2 {
+        name: current.name.trim_end_matches('\0').to_string(),
+        symbol: current.symbol.trim_end_matches('\0').to_string(),
+        uri: new_metadata_uri.clone(),
+        seller_fee_basis_points: current.seller_fee_basis_points,
+        creators: current.creators,
+        collection: current.collection,
+        uses: current.uses,
+    };
+    
+    let update_authority_bump = ctx.bumps.update_authority;
+    let update_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[update_authority_bump]];
+    
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                update_authority: ctx.accounts.update_authority.to_account_info(),
+            },
+            &[update_authority_seeds],
+        ),
+        None, // Keep the update authority
+        Some(data_v2),
+        None,
+        None,
+    )?;
+    
     // Update NFT metadata
     let nft_metadata = &mut ctx.accounts.nft_metadata;
     nft_metadata.metadata_uri = new_metadata_uri.clone();
+    nft_metadata.metadata_hash = new_metadata_hash; // Zeros clear the commitment
     nft_metadata.updated_at = clock.unix_timestamp;
     
     msg!("NFT metadata updated successfully");
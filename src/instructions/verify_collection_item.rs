@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{
+    verify_sized_collection_item,
+    Metadata,
+    VerifySizedCollectionItem,
+};
+
+use crate::{
+    state::{NFTMetadata, VerifiedCollection},
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct VerifyCollectionItem<'info> {
+    #[account(
+        seeds = [VERIFIED_COLLECTION_SEED],
+        bump = verified_collection.bump,
+        constraint = verified_collection.mint == collection_mint.key() @ UniversalNFTError::MintMismatch
+    )]
+    pub verified_collection: Account<'info, VerifiedCollection>,
+    
+    /// Only NFTs tracked by this program can join the collection
+    #[account(
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump = nft_metadata.bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    /// CHECK: Mint of the NFT being verified, tied to nft_metadata by its seeds
+    pub nft_mint: UncheckedAccount<'info>,
+    
+    /// CHECK: Metaplex metadata PDA of the NFT, updated by the token metadata program
+    #[account(
+        mut,
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), nft_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA used as mint and freeze authority for program-minted assets
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Collection mint, checked against verified_collection
+    pub collection_mint: UncheckedAccount<'info>,
+    
+    /// CHECK: Metaplex metadata PDA of the collection, size updated by the token metadata program
+    #[account(
+        mut,
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), collection_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Metaplex master edition PDA of the collection
+    #[account(
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), collection_mint.key().as_ref(), METAPLEX_EDITION_SEED],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+pub fn handler(ctx: Context<VerifyCollectionItem>) -> Result<()> {
+    let mint_authority_bump = ctx.bumps.mint_authority;
+    let mint_authority_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[mint_authority_bump]];
+    let mint_authority_signer = &[mint_authority_seeds];
+    
+    // The PDA is the collection's update authority, so it can verify any program NFT into it
+    verify_sized_collection_item(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            VerifySizedCollectionItem {
+                payer: ctx.accounts.payer.to_account_info(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+                collection_authority: ctx.accounts.mint_authority.to_account_info(),
+                collection_mint: ctx.accounts.collection_mint.to_account_info(),
+                collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+                collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+            },
+            mint_authority_signer,
+        ),
+        None,
+    )?;
+    
+    msg!("Collection item verified");
+    msg!("NFT: {}", ctx.accounts.nft_metadata.mint);
+    msg!("Collection: {}", ctx.accounts.verified_collection.mint);
+    
+    Ok(())
+}
@@ -1,31 +1,40 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
 
 use crate::{
-    state::{NFTMetadata, OwnershipVerificationState, ZetaChainGatewayState},
+    state::{NFTMetadata, OwnershipVerificationState, OwnershipRoot, ChainConfig},
     errors::UniversalNFTError,
+    merkle,
     constants::*,
+    seeds::*,
 };
 
 #[derive(Accounts)]
+#[instruction(chain_id: u64)]
 pub struct VerifyCrossChainOwnership<'info> {
     #[account(
-        seeds = [b"gateway_state"],
-        bump = gateway_state.bump
+        seeds = [CHAIN_CONFIG_SEED, &chain_id.to_le_bytes()],
+        bump = chain_config.bump
     )]
-    pub gateway_state: Account<'info, ZetaChainGatewayState>,
+    pub chain_config: Account<'info, ChainConfig>,
     
     #[account(
-        seeds = [b"nft_metadata", nft_mint.key().as_ref()],
+        seeds = [OWNERSHIP_ROOT_SEED, &chain_id.to_le_bytes()],
+        bump = ownership_root.bump
+    )]
+    pub ownership_root: Account<'info, OwnershipRoot>,
+    
+    #[account(
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
         bump = nft_metadata.bump
     )]
     pub nft_metadata: Account<'info, NFTMetadata>,
     
     #[account(
-        mut,
         init_if_needed,
-        payer = verifier,
+        payer = payer,
         space = OwnershipVerificationState::LEN,
-        seeds = [b"ownership_verification", nft_mint.key().as_ref()],
+        seeds = [OWNERSHIP_VERIFICATION_SEED, nft_mint.key().as_ref()],
         bump
     )]
     pub verification_state: Account<'info, OwnershipVerificationState>,
@@ -34,6 +43,8 @@ pub struct VerifyCrossChainOwnership<'info> {
     pub nft_mint: Account<'info, anchor_spl::token::Mint>,
     
     #[account(mut)]
+    pub payer: Signer<'info>,
+    
     pub verifier: Signer<'info>,
     
     pub system_program: Program<'info, System>,
@@ -42,34 +53,53 @@ pub struct VerifyCrossChainOwnership<'info> {
 
 pub fn handler(
     ctx: Context<VerifyCrossChainOwnership>,
-    proof_data: Vec<u8>,
+    chain_id: u64,
+    owner: Vec<u8>,
+    proof: Vec<[u8; 32]>,
 ) -> Result<()> {
-    // Validate proof data length
-    if proof_data.len() == 0 {
+    // The claimed owner must be a well-formed address on the remote chain
+    if !ctx.accounts.chain_config.address_format.is_valid_address(&owner) {
+        return err!(UniversalNFTError::InvalidRecipientAddress);
+    }
+    
+    // Validate proof depth
+    if proof.len() > MAX_MERKLE_PROOF_DEPTH {
         return err!(UniversalNFTError::InvalidProofData);
     }
     
     let clock = Clock::get()?;
     
-    // Verify proof data hash matches the stored cross-chain data hash
-    let proof_hash = anchor_lang::solana_program::keccak::hash(&proof_data).to_bytes();
-    if proof_hash != ctx.accounts.nft_metadata.cross_chain_data_hash {
-        return err!(UniversalNFTError::CrossChainDataHashMismatch);
+    // The (token ID, owner) leaf must be included in the gateway's published root
+    let root = ctx.accounts.ownership_root.root;
+    let leaf = ownership_leaf(&ctx.accounts.nft_metadata.token_id, &owner);
+    if !merkle::verify(&proof, &root, &leaf) {
+        return err!(UniversalNFTError::InvalidMerkleProof);
     }
     
     // Update verification state
     let verification_state = &mut ctx.accounts.verification_state;
     verification_state.nft_mint = ctx.accounts.nft_mint.key();
-    verification_state.zeta_owner = vec![0u8; 100]; // Placeholder for ZetaChain owner
-    verification_state.proof_hash = proof_hash;
+    verification_state.zeta_owner = owner;
+    verification_state.owner_chain_id = chain_id;
+    verification_state.proof_hash = root;
     verification_state.verified = true;
     verification_state.verified_at = clock.unix_timestamp;
-    verification_state.bump = *ctx.bumps.get("verification_state").unwrap();
+    verification_state.bump = ctx.bumps.verification_state;
+    verification_state.account_version = ACCOUNT_VERSION;
     
     msg!("Cross-chain ownership verified successfully");
     msg!("NFT: {}", ctx.accounts.nft_mint.key());
-    msg!("Proof hash: {:?}", proof_hash);
+    msg!("Owner: {:?}", verification_state.zeta_owner);
+    msg!("Chain ID: {}", chain_id);
+    msg!("Ownership root: {:?}", root);
     msg!("Verified at: {}", clock.unix_timestamp);
     
     Ok(())
 }
+
+/// Ownership tree leaf: keccak(keccak(token_id || owner)), double-hashed so a leaf can never be
+/// read as an inner node
+pub fn ownership_leaf(token_id: &[u8; 32], owner: &[u8]) -> [u8; 32] {
+    let inner = keccak::hashv(&[token_id, owner]).to_bytes();
+    keccak::hash(&inner).to_bytes()
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramState, Treasury},
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: Any account chosen by the authority to receive the proceeds
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawTreasury>,
+    amount: u64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    // The treasury keeps its rent-exempt minimum so it stays open for later mints
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    let rent_minimum = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let available = treasury_info.lamports().saturating_sub(rent_minimum);
+    if amount == 0 || amount > available {
+        return err!(UniversalNFTError::InsufficientTreasuryBalance);
+    }
+    
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.destination.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_withdrawn = treasury.total_withdrawn.saturating_add(amount);
+    
+    msg!("Treasury withdrawal");
+    msg!("Amount: {} lamports", amount);
+    msg!("Destination: {}", ctx.accounts.destination.key());
+    msg!("Remaining: {} lamports", available - amount);
+    
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{
+    state::{ProgramState, Treasury},
+    errors::UniversalNFTError,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawTreasuryTokens<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNFTError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = treasury,
+    )]
+    pub treasury_payment_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = payment_mint,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar, inspected when the authority is a multisig
+    #[account(address = solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawTreasuryTokens>,
+    amount: u64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_admin_call(&ctx.accounts.instructions)?;
+    
+    if amount == 0 || amount > ctx.accounts.treasury_payment_account.amount {
+        return err!(UniversalNFTError::InsufficientTreasuryBalance);
+    }
+    
+    let treasury_seeds: &[&[u8]] = &[TREASURY_SEED, &[ctx.accounts.treasury.bump]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.treasury_payment_account.to_account_info(),
+                mint: ctx.accounts.payment_mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            &[treasury_seeds],
+        ),
+        amount,
+        ctx.accounts.payment_mint.decimals,
+    )?;
+    
+    msg!("Treasury token withdrawal");
+    msg!("Mint: {}", ctx.accounts.payment_mint.key());
+    msg!("Amount: {}", amount);
+    msg!("Destination: {}", ctx.accounts.destination.key());
+    
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TokenInterface},
+    metadata::{Metadata, MetadataAccount},
+};
+
+use crate::{
+    state::{ProgramState, PauseConfig, CollectionConfig, NFTMetadata, NFTOrigin, WrappedNFT},
+    errors::UniversalNFTError,
+    codec::derive_token_id,
+    constants::*,
+    seeds::*,
+};
+
+#[derive(Accounts)]
+#[instruction(token_id_slot: u64)]
+pub struct WrapExternalNFT<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [PAUSE_CONFIG_SEED],
+        bump = pause_config.bump
+    )]
+    pub pause_config: Account<'info, PauseConfig>,
+    
+    #[account(
+        seeds = [COLLECTION_CONFIG_SEED],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    /// Existing one-of-one mint; program-minted NFTs already have NFT metadata and cannot be wrapped
+    #[account(
+        constraint = nft_mint.decimals == 0 && nft_mint.supply == 1 @ UniversalNFTError::InvalidExternalNFT
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Metaplex metadata of the external NFT, supplying its URI and royalties
+    #[account(
+        seeds = [METAPLEX_METADATA_SEED, token_metadata_program.key().as_ref(), nft_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata: Account<'info, MetadataAccount>,
+    
+    #[account(
+        associated_token::mint = nft_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+        constraint = holder_token_account.amount == 1 @ UniversalNFTError::TokenNotHeld,
+    )]
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = NFTMetadata::space(collection_config.max_uri_length()),
+        seeds = [NFT_METADATA_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = NFTOrigin::space(collection_config.max_uri_length()),
+        seeds = [TOKEN_ID_SEED, derive_token_id(&nft_mint.key(), token_id_slot, program_state.next_token_id).as_ref()],
+        bump
+    )]
+    pub nft_origin: Account<'info, NFTOrigin>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = WrappedNFT::LEN,
+        seeds = [WRAPPED_NFT_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub wrapped_nft: Account<'info, WrappedNFT>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub holder: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+pub fn handler(
+    ctx: Context<WrapExternalNFT>,
+    token_id_slot: u64,
+) -> Result<()> {
+    ctx.accounts.program_state.require_not_paused()?;
+    ctx.accounts.pause_config.require_active(PAUSE_MINT)?;
+    
+    let clock = Clock::get()?;
+    
+    // The slot must be recent so token IDs track the block the NFT was wrapped in
+    if token_id_slot > clock.slot || clock.slot - token_id_slot > MAX_TOKEN_ID_SLOT_AGE {
+        return err!(UniversalNFTError::InvalidTokenIdSlot);
+    }
+    
+    // Metaplex pads URIs with NUL bytes
+    let metadata = &ctx.accounts.metadata;
+    let metadata_uri = metadata.uri.trim_end_matches('\0').to_string();
+    ctx.accounts.collection_config.require_uri_length(&metadata_uri)?;
+    
+    let token_id = derive_token_id(&ctx.accounts.nft_mint.key(), token_id_slot, ctx.accounts.program_state.next_token_id);
+    
+    // Track the NFT like a native one; it is escrowed by cross_chain_transfer when it leaves Solana
+    let nft_metadata = &mut ctx.accounts.nft_metadata;
+    nft_metadata.mint = ctx.accounts.nft_mint.key();
+    nft_metadata.owner = ctx.accounts.holder.key();
+    nft_metadata.metadata_uri = metadata_uri.clone();
+    nft_metadata.zeta_chain_id = ZETA_CHAIN_ID_SOLANA;
+    nft_metadata.cross_chain_data_hash = [0u8; 32];
+    nft_metadata.token_id = token_id;
+    nft_metadata.locked = false;
+    nft_metadata.owner_locked = false;
+    nft_metadata.created_at = clock.unix_timestamp;
+    nft_metadata.updated_at = clock.unix_timestamp;
+    nft_metadata.bump = ctx.bumps.nft_metadata;
+    nft_metadata.account_version = ACCOUNT_VERSION;
+    nft_metadata.collection = Pubkey::default();
+    nft_metadata.metadata_immutable = !metadata.is_mutable;
+    nft_metadata.metadata_hash = [0u8; 32];
+    nft_metadata.royalty_basis_points = metadata.seller_fee_basis_points;
+    nft_metadata.royalty_recipient = metadata.creators.as_ref().and_then(|creators| creators.first()).map(|creator| creator.address).unwrap_or_default();
+    
+    let nft_origin = &mut ctx.accounts.nft_origin;
+    nft_origin.token_id = token_id;
+    nft_origin.original_mint = ctx.accounts.nft_mint.key();
+    nft_origin.original_metadata_uri = metadata_uri;
+    nft_origin.source_chain_id = ZETA_CHAIN_ID_SOLANA;
+    nft_origin.created_at = clock.unix_timestamp;
+    nft_origin.bump = ctx.bumps.nft_origin;
+    nft_origin.account_version = ACCOUNT_VERSION;
+    
+    let wrapped_nft = &mut ctx.accounts.wrapped_nft;
+    wrapped_nft.mint = ctx.accounts.nft_mint.key();
+    wrapped_nft.token_id = token_id;
+    wrapped_nft.wrapper = ctx.accounts.holder.key();
+    wrapped_nft.wrapped_at = clock.unix_timestamp;
+    wrapped_nft.bump = ctx.bumps.wrapped_nft;
+    wrapped_nft.account_version = ACCOUNT_VERSION;
+    
+    // Wrapped NFTs count as on Solana, so sending them out balances the supply; they don't consume max_supply
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_minted = program_state.total_minted.checked_add(1).ok_or(error!(UniversalNFTError::SupplyOverflow))?;
+    program_state.next_token_id = program_state.next_token_id.checked_add(1).ok_or(error!(UniversalNFTError::TokenIdOverflow))?;
+    
+    msg!("External NFT wrapped");
+    msg!("Mint address: {}", ctx.accounts.nft_mint.key());
+    msg!("Holder: {}", ctx.accounts.holder.key());
+    msg!("Token ID: {:?}", token_id);
+    
+    Ok(())
+}
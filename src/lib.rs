@@ -1,24 +1,34 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
-};
 
 pub mod instructions;
 pub mod state;
 pub mod errors;
 pub mod constants;
+pub mod events;
+pub mod codec;
+pub mod payload;
+pub mod wsol;
+pub mod sigverify;
+pub mod merkle;
+pub mod metaplex;
+pub mod programmable;
+pub mod transfer_hook;
+pub mod seeds;
 
 use instructions::*;
 use state::*;
-use errors::*;
+use payload::{CrossChainPayload, RemoteBurnPayload, IncomingBatchPayload, AttestationPayload};
+use metaplex::NFTCreator;
+use spl_transfer_hook_interface::instruction::TransferHookInstruction;
+
+pub use seeds::*;
 
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
 #[program]
 pub mod zetachain_universal_nft {
     use super::*;
-
+    
     /// Initialize the universal NFT program
     pub fn initialize(
         ctx: Context<Initialize>,
@@ -27,74 +37,804 @@ pub mod zetachain_universal_nft {
     ) -> Result<()> {
         instructions::initialize::handler(ctx, metadata_uri, max_supply)
     }
-
+    
     /// Mint a new NFT on Solana with Universal NFT Protocol support
+    #[allow(clippy::too_many_arguments)]
     pub fn mint_nft(
         ctx: Context<MintNFT>,
         metadata_uri: String,
         zeta_chain_id: u64,
-        cross_chain_data: Vec<u8>,
+        payload: CrossChainPayload,
+        token_id_slot: u64,
+        name: String,
+        symbol: String,
+        seller_fee_basis_points: u16,
+        creators: Vec<NFTCreator>,
+        allowlist_proof: Option<AllowlistProof>,
+        coupon: Option<MintCoupon>,
     ) -> Result<()> {
-        instructions::mint_nft::handler(ctx, metadata_uri, zeta_chain_id, cross_chain_data)
+        instructions::mint_nft::handler(ctx, metadata_uri, zeta_chain_id, payload, token_id_slot, name, symbol, seller_fee_basis_points, creators, allowlist_proof, coupon)
     }
-
+    
     /// Transfer NFT ownership locally on Solana
     pub fn transfer_nft(
         ctx: Context<TransferNFT>,
         new_owner: Pubkey,
+        memo: Option<String>,
+        sale_price: u64,
+        amount: u64,
     ) -> Result<()> {
-        instructions::transfer_nft::handler(ctx, new_owner)
+        instructions::transfer_nft::handler(ctx, new_owner, memo, sale_price, amount)
     }
-
+    
     /// Initiate cross-chain transfer to another chain via ZetaChain
-    pub fn cross_chain_transfer(
-        ctx: Context<CrossChainTransfer>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn cross_chain_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, CrossChainTransfer<'info>>,
         target_chain_id: u64,
         recipient: Vec<u8>,
-        zeta_chain_data: Vec<u8>,
+        lane: MessageLane,
+        gas_amount: u64,
+        gas_limit: u64,
+        memo: Option<String>,
+        amount: u64,
     ) -> Result<()> {
-        instructions::cross_chain_transfer::handler(ctx, target_chain_id, recipient, zeta_chain_data)
+        instructions::cross_chain_transfer::handler(ctx, target_chain_id, recipient, lane, gas_amount, gas_limit, memo, amount)
     }
-
+    
     /// Process incoming NFT from another chain via ZetaChain
     pub fn process_incoming_nft(
         ctx: Context<ProcessIncomingNFT>,
-        metadata_uri: String,
         source_chain_id: u64,
-        cross_chain_data: Vec<u8>,
+        payload: CrossChainPayload,
         zeta_tx_hash: [u8; 32],
+        observer_signatures: Vec<ObserverSignature>,
+        hold_for_claim: bool,
     ) -> Result<()> {
-        instructions::process_incoming_nft::handler(ctx, metadata_uri, source_chain_id, cross_chain_data, zeta_tx_hash)
+        instructions::process_incoming_nft::handler(ctx, source_chain_id, payload, zeta_tx_hash, observer_signatures, hold_for_claim)
     }
-
-    /// Verify cross-chain ownership using cryptographic proof
+    
+    /// Verify cross-chain ownership with a Merkle proof against the gateway's published root
     pub fn verify_cross_chain_ownership(
         ctx: Context<VerifyCrossChainOwnership>,
-        proof_data: Vec<u8>,
+        chain_id: u64,
+        owner: Vec<u8>,
+        proof: Vec<[u8; 32]>,
     ) -> Result<()> {
-        instructions::verify_cross_chain_ownership::handler(ctx, proof_data)
+        instructions::verify_cross_chain_ownership::handler(ctx, chain_id, owner, proof)
     }
-
+    
     /// Update NFT metadata (owner only)
     pub fn update_metadata(
         ctx: Context<UpdateMetadata>,
         new_metadata_uri: String,
+        new_metadata_hash: [u8; 32],
     ) -> Result<()> {
-        instructions::update_metadata::handler(ctx, new_metadata_uri)
+        instructions::update_metadata::handler(ctx, new_metadata_uri, new_metadata_hash)
     }
-
+    
     /// Burn NFT and update program state
     pub fn burn_nft(ctx: Context<BurnNFT>) -> Result<()> {
         instructions::burn_nft::handler(ctx)
     }
-
+    
     /// Setup ZetaChain gateway configuration (authority only)
     pub fn setup_gateway(
         ctx: Context<SetupGateway>,
         gateway_address: [u8; 20],
-        supported_chains: Vec<u64>,
+        gateway_authority: Pubkey,
         version: u8,
     ) -> Result<()> {
-        instructions::setup_gateway::handler(ctx, gateway_address, supported_chains, version)
+        instructions::setup_gateway::handler(ctx, gateway_address, gateway_authority, version)
+    }
+    
+    /// Revert a failed cross-chain transfer and return the escrowed NFT (gateway only)
+    pub fn revert_cross_chain_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevertCrossChainTransfer<'info>>,
+        revert_tx_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::revert_cross_chain_transfer::handler(ctx, revert_tx_hash)
+    }
+    
+    /// Rebuild the token ID registry entry from the NFT's metadata (authority only)
+    pub fn rebuild_registry_entry(
+        ctx: Context<RebuildRegistryEntry>,
+        token_id: [u8; 32],
+        proof_data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::rebuild_registry_entry::handler(ctx, token_id, proof_data)
+    }
+    
+    /// Cancel an unconfirmed cross-chain transfer after the timeout (owner only)
+    pub fn cancel_cross_chain_transfer<'info>(ctx: Context<'_, '_, '_, 'info, CancelCrossChainTransfer<'info>>) -> Result<()> {
+        instructions::cancel_cross_chain_transfer::handler(ctx)
+    }
+    
+    /// Set the timeout after which pending transfers can be cancelled (authority only)
+    pub fn set_transfer_timeout(
+        ctx: Context<SetTransferTimeout>,
+        transfer_timeout: i64,
+    ) -> Result<()> {
+        instructions::set_transfer_timeout::handler(ctx, transfer_timeout)
+    }
+    
+    /// Enable or disable instruction families (authority only)
+    pub fn set_feature_flags(
+        ctx: Context<SetFeatureFlags>,
+        feature_flags: u64,
+    ) -> Result<()> {
+        instructions::set_feature_flags::handler(ctx, feature_flags)
+    }
+    
+    /// Confirm an outbound transfer with its ZetaChain transaction hash (gateway only)
+    pub fn confirm_outbound_transfer(
+        ctx: Context<ConfirmOutboundTransfer>,
+        zeta_tx_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::confirm_outbound_transfer::handler(ctx, zeta_tx_hash)
+    }
+    
+    /// Register a notification commitment for incoming NFT alerts
+    pub fn register_notification(
+        ctx: Context<RegisterNotification>,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::register_notification::handler(ctx, commitment)
+    }
+    
+    /// Remove a notification registration and reclaim its rent
+    pub fn unregister_notification(ctx: Context<UnregisterNotification>) -> Result<()> {
+        instructions::unregister_notification::handler(ctx)
+    }
+    
+    /// Import a soulbound attestation from another chain as a non-transferable Token-2022 asset (gateway only)
+    pub fn process_incoming_attestation(
+        ctx: Context<ProcessIncomingAttestation>,
+        source_chain_id: u64,
+        payload: AttestationPayload,
+        zeta_tx_hash: [u8; 32],
+        observer_signatures: Vec<ObserverSignature>,
+    ) -> Result<()> {
+        instructions::process_incoming_attestation::handler(ctx, source_chain_id, payload, zeta_tx_hash, observer_signatures)
+    }
+    
+    /// Bind a verified address on another chain to the signing wallet
+    pub fn bind_identity(
+        ctx: Context<BindIdentity>,
+        chain_id: u64,
+        remote_address: [u8; 20],
+        signature: [u8; 64],
+        recovery_id: u8,
+    ) -> Result<()> {
+        instructions::bind_identity::handler(ctx, chain_id, remote_address, signature, recovery_id)
+    }
+    
+    /// Remove an identity binding and reclaim its rent
+    pub fn unbind_identity(ctx: Context<UnbindIdentity>) -> Result<()> {
+        instructions::unbind_identity::handler(ctx)
+    }
+    
+    /// Migrate an NFT's accounts from u64 to uint256 token IDs (authority only)
+    pub fn migrate_token_id(
+        ctx: Context<MigrateTokenId>,
+        legacy_token_id: u64,
+    ) -> Result<()> {
+        instructions::migrate_token_id::handler(ctx, legacy_token_id)
+    }
+    
+    /// Configure the bridge rewards schedule and redemption hook (authority only)
+    pub fn configure_rewards(
+        ctx: Context<ConfigureRewards>,
+        points_per_round_trip: u64,
+        redemption_hook: Pubkey,
+    ) -> Result<()> {
+        instructions::configure_rewards::handler(ctx, points_per_round_trip, redemption_hook)
+    }
+    
+    /// Open a rewards points ledger for the signing wallet
+    pub fn open_points_ledger(ctx: Context<OpenPointsLedger>) -> Result<()> {
+        instructions::open_points_ledger::handler(ctx)
+    }
+    
+    /// Redeem points through the configured redemption hook
+    pub fn redeem_points<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemPoints<'info>>,
+        points: u64,
+    ) -> Result<()> {
+        instructions::redeem_points::handler(ctx, points)
+    }
+    
+    /// Report how migrate_token_id would transform an account without mutating it
+    pub fn simulate_migration(ctx: Context<SimulateMigration>) -> Result<MigrationReport> {
+        instructions::simulate_migration::handler(ctx)
+    }
+    
+    /// Release a Solana-origin NFT returning from another chain to its original mint (gateway only)
+    pub fn process_returning_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProcessReturningNFT<'info>>,
+        source_chain_id: u64,
+        payload: CrossChainPayload,
+        zeta_tx_hash: [u8; 32],
+        observer_signatures: Vec<ObserverSignature>,
+    ) -> Result<()> {
+        instructions::process_returning_nft::handler(ctx, source_chain_id, payload, zeta_tx_hash, observer_signatures)
+    }
+    
+    /// Schedule the next gateway so both are accepted during a migration (authority only)
+    pub fn set_next_gateway(
+        ctx: Context<SetNextGateway>,
+        gateway_address: [u8; 20],
+        gateway_authority: Pubkey,
+        valid_from: i64,
+        current_valid_until: i64,
+    ) -> Result<()> {
+        instructions::set_next_gateway::handler(ctx, gateway_address, gateway_authority, valid_from, current_valid_until)
+    }
+    
+    /// Make the next gateway the current one once its window has opened (authority only)
+    pub fn promote_next_gateway(ctx: Context<PromoteNextGateway>) -> Result<()> {
+        instructions::promote_next_gateway::handler(ctx)
+    }
+    
+    /// Register a connected chain and its routing configuration (authority only)
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_chain(
+        ctx: Context<RegisterChain>,
+        chain_id: u64,
+        chain_index: u8,
+        name: String,
+        address_format: AddressFormat,
+        required_confirmations: u32,
+        default_gas_limit: u64,
+        max_gas_limit: u64,
+    ) -> Result<()> {
+        instructions::register_chain::handler(ctx, chain_id, chain_index, name, address_format, required_confirmations, default_gas_limit, max_gas_limit)
+    }
+    
+    /// Update a registered chain's routing configuration (authority only)
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_chain(
+        ctx: Context<UpdateChain>,
+        chain_id: u64,
+        name: String,
+        address_format: AddressFormat,
+        required_confirmations: u32,
+        default_gas_limit: u64,
+        max_gas_limit: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::update_chain::handler(ctx, chain_id, name, address_format, required_confirmations, default_gas_limit, max_gas_limit, enabled)
+    }
+    
+    /// Remove a chain from the registry (authority only)
+    pub fn remove_chain(ctx: Context<RemoveChain>, chain_id: u64) -> Result<()> {
+        instructions::remove_chain::handler(ctx, chain_id)
+    }
+    
+    /// Quote the total lamport cost of an outbound transfer; returned via return data
+    pub fn quote_cross_chain_fee(
+        ctx: Context<QuoteCrossChainFee>,
+        target_chain_id: u64,
+        lane: MessageLane,
+        gas_amount: u64,
+    ) -> Result<FeeQuote> {
+        instructions::quote_cross_chain_fee::handler(ctx, target_chain_id, lane, gas_amount)
+    }
+    
+    /// Set the collection's royalty enforcement policy (authority only)
+    pub fn set_royalty_policy(ctx: Context<SetRoyaltyPolicy>, royalty_policy: RoyaltyPolicy) -> Result<()> {
+        instructions::set_royalty_policy::handler(ctx, royalty_policy)
+    }
+    
+    /// Set how long inbound cross-chain messages stay valid after they were sent (authority only)
+    pub fn set_message_expiry_window(ctx: Context<SetMessageExpiryWindow>, message_expiry_window: i64) -> Result<()> {
+        instructions::set_message_expiry_window::handler(ctx, message_expiry_window)
+    }
+    
+    /// Configure the mint emission schedule; a zero period removes it (authority only)
+    pub fn set_emission_schedule(
+        ctx: Context<SetEmissionSchedule>,
+        emission_start: i64,
+        emission_period: i64,
+        emission_per_period: u64,
+    ) -> Result<()> {
+        instructions::set_emission_schedule::handler(ctx, emission_start, emission_period, emission_per_period)
+    }
+    
+    /// Mint a sample NFT to the caller for integration testing (devnet builds only)
+    pub fn faucet_mint(ctx: Context<FaucetMint>, genesis_hash: [u8; 32], token_id_slot: u64) -> Result<()> {
+        instructions::faucet_mint::handler(ctx, genesis_hash, token_id_slot)
+    }
+    
+    /// Propose a new program authority; takes effect once accepted (authority only)
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+    
+    /// Accept a pending authority rotation (pending authority only)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+    
+    /// Assign a chain index to a pre-bitmask chain config and upgrade the gateway state (authority only)
+    pub fn migrate_chain_index(ctx: Context<MigrateChainIndex>, chain_id: u64, chain_index: u8) -> Result<()> {
+        instructions::migrate_chain_index::handler(ctx, chain_id, chain_index)
+    }
+    
+    /// Require admin calls to be relayed by a multisig program, or clear it with the default key (authority only)
+    pub fn set_authority_program(ctx: Context<SetAuthorityProgram>, authority_program: Pubkey) -> Result<()> {
+        instructions::set_authority_program::handler(ctx, authority_program)
+    }
+    
+    /// Halt mints, transfers and bridge traffic during an incident (authority only)
+    pub fn pause(ctx: Context<Pause>) -> Result<()> {
+        instructions::pause::handler(ctx)
+    }
+    
+    /// Resume normal operation after a pause (authority only)
+    pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
+        instructions::unpause::handler(ctx)
+    }
+    
+    /// Pause or resume individual operations with PAUSE_* bits (authority only)
+    pub fn set_paused_operations(ctx: Context<SetPausedOperations>, paused_operations: u64) -> Result<()> {
+        instructions::set_paused_operations::handler(ctx, paused_operations)
+    }
+    
+    /// Halt or resume traffic to a single chain without removing it from the registry (authority only)
+    pub fn set_chain_enabled(ctx: Context<SetChainEnabled>, chain_id: u64, enabled: bool) -> Result<()> {
+        instructions::set_chain_enabled::handler(ctx, chain_id, enabled)
+    }
+    
+    /// Configure the bridge rate limiter; 0 disables a limit (authority only)
+    pub fn set_rate_limit(ctx: Context<SetRateLimit>, max_transfers: u64, max_value: u64, window: i64) -> Result<()> {
+        instructions::set_rate_limit::handler(ctx, max_transfers, max_value, window)
+    }
+    
+    /// Set the daily outbound transfer cap per wallet; 0 disables it (authority only)
+    pub fn set_velocity_limit(ctx: Context<SetVelocityLimit>, max_owner_transfers_per_day: u64) -> Result<()> {
+        instructions::set_velocity_limit::handler(ctx, max_owner_transfers_per_day)
+    }
+    
+    /// Force-fail a long-pending transfer and return the escrowed NFT to its owner (authority only)
+    pub fn resolve_stuck_transfer<'info>(ctx: Context<'_, '_, '_, 'info, ResolveStuckTransfer<'info>>) -> Result<()> {
+        instructions::resolve_stuck_transfer::handler(ctx)
+    }
+    
+    /// Rotate the gateway's TSS signer, keeping the previous key valid for a grace period (authority only)
+    pub fn rotate_tss_address(
+        ctx: Context<RotateTssAddress>,
+        tss_authority: Pubkey,
+        activation_time: i64,
+        grace_period: i64,
+    ) -> Result<()> {
+        instructions::rotate_tss_address::handler(ctx, tss_authority, activation_time, grace_period)
+    }
+    
+    /// Replace the observer signer set and its signature threshold (authority only)
+    pub fn set_observers(
+        ctx: Context<SetObservers>,
+        observers: Vec<[u8; 20]>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_observers::handler(ctx, observers, threshold)
+    }
+    
+    /// Publish the Merkle root of NFT ownership on a remote chain (gateway only)
+    pub fn publish_ownership_root(
+        ctx: Context<PublishOwnershipRoot>,
+        chain_id: u64,
+        root: [u8; 32],
+    ) -> Result<()> {
+        instructions::publish_ownership_root::handler(ctx, chain_id, root)
+    }
+    
+    /// Read the verified owner of an NFT on a remote chain; returned via return data
+    pub fn get_cross_chain_owner(ctx: Context<GetCrossChainOwner>) -> Result<CrossChainOwner> {
+        instructions::get_cross_chain_owner::handler(ctx)
+    }
+    
+    /// Set the watcher allowed to freeze suspicious assets (authority only)
+    pub fn set_watcher(ctx: Context<SetWatcher>, watcher: Pubkey) -> Result<()> {
+        instructions::set_watcher::handler(ctx, watcher)
+    }
+    
+    /// Freeze a mint and its cross-chain transfer pending review (watcher only)
+    pub fn freeze_asset(ctx: Context<FreezeAsset>, mint: Pubkey) -> Result<()> {
+        instructions::freeze_asset::handler(ctx, mint)
+    }
+    
+    /// Clear a frozen asset after review (authority only)
+    pub fn unfreeze_asset(ctx: Context<UnfreezeAsset>, mint: Pubkey) -> Result<()> {
+        instructions::unfreeze_asset::handler(ctx, mint)
+    }
+    
+    /// Add or remove a keccak(address) hash on the recipient denylist (authority only)
+    pub fn update_denylist(
+        ctx: Context<UpdateDenylist>,
+        address_hash: [u8; 32],
+        denied: bool,
+    ) -> Result<()> {
+        instructions::update_denylist::handler(ctx, address_hash, denied)
+    }
+    
+    /// Allow a wallet to mint while allowlist-gated minting is enabled (authority only)
+    pub fn add_to_allowlist(ctx: Context<AddToAllowlist>, wallet: Pubkey) -> Result<()> {
+        instructions::add_to_allowlist::handler(ctx, wallet)
+    }
+    
+    /// Remove a wallet from the mint allowlist (authority only)
+    pub fn remove_from_allowlist(ctx: Context<RemoveFromAllowlist>, wallet: Pubkey) -> Result<()> {
+        instructions::remove_from_allowlist::handler(ctx, wallet)
+    }
+    
+    /// Set how long incoming NFTs wait in escrow before release; 0 finalizes instantly (authority only)
+    pub fn set_challenge_period(ctx: Context<SetChallengePeriod>, challenge_period: i64) -> Result<()> {
+        instructions::set_challenge_period::handler(ctx, challenge_period)
+    }
+    
+    /// Release an escrowed incoming NFT to its recipient once the challenge period has passed
+    pub fn finalize_incoming_nft(ctx: Context<FinalizeIncomingNFT>) -> Result<()> {
+        instructions::finalize_incoming_nft::handler(ctx)
+    }
+    
+    /// Dispute a pending incoming delivery, blocking its release (watcher only)
+    pub fn dispute_incoming_nft(ctx: Context<DisputeIncomingNFT>) -> Result<()> {
+        instructions::dispute_incoming_nft::handler(ctx)
+    }
+    
+    /// Clear a dispute on a pending incoming delivery after review (authority only)
+    pub fn clear_delivery_dispute(ctx: Context<ClearDeliveryDispute>) -> Result<()> {
+        instructions::clear_delivery_dispute::handler(ctx)
+    }
+    
+    /// Cancel a pending incoming delivery with evidence of fraud and flag its relayer
+    pub fn submit_fraud_proof(ctx: Context<SubmitFraudProof>, evidence: FraudEvidence) -> Result<()> {
+        instructions::submit_fraud_proof::handler(ctx, evidence)
+    }
+    
+    /// Lock an NFT against transfers, burns and metadata updates (owner only)
+    pub fn lock_nft(ctx: Context<LockNFT>) -> Result<()> {
+        instructions::lock_nft::handler(ctx)
+    }
+    
+    /// Clear an owner lock (owner, or authority as an override)
+    pub fn unlock_nft(ctx: Context<UnlockNFT>) -> Result<()> {
+        instructions::unlock_nft::handler(ctx)
+    }
+    
+    /// Upgrade any program account to the current layout version in place (authority only)
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler(ctx)
+    }
+    
+    /// Create the sized collection NFT held by the program's mint authority PDA (authority only)
+    pub fn create_collection(
+        ctx: Context<CreateCollection>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::create_collection::handler(ctx, name, symbol, uri)
+    }
+    
+    /// Verify a program NFT as a member of the collection
+    pub fn verify_collection_item(ctx: Context<VerifyCollectionItem>) -> Result<()> {
+        instructions::verify_collection_item::handler(ctx)
+    }
+    
+    /// Register an independent collection with its own authority, supply, chain routes and asset backend (authority only)
+    pub fn register_collection(
+        ctx: Context<RegisterCollection>,
+        collection_id: u64,
+        collection_authority: Pubkey,
+        base_uri: String,
+        max_supply: u64,
+        allowed_chain_mask: u128,
+        asset_backend: AssetBackend,
+    ) -> Result<()> {
+        instructions::register_collection::handler(ctx, collection_id, collection_authority, base_uri, max_supply, allowed_chain_mask, asset_backend)
+    }
+    
+    /// Update a collection's authority, base URI, supply, chain routes and asset backend (collection authority only)
+    pub fn update_collection(
+        ctx: Context<UpdateCollection>,
+        new_authority: Pubkey,
+        base_uri: String,
+        max_supply: u64,
+        allowed_chain_mask: u128,
+        asset_backend: AssetBackend,
+    ) -> Result<()> {
+        instructions::update_collection::handler(ctx, new_authority, base_uri, max_supply, allowed_chain_mask, asset_backend)
+    }
+    
+    /// Mint programmable NFTs bound to a token auth rule set, or standard NFTs again with the default pubkey (authority only)
+    pub fn set_rule_set(ctx: Context<SetRuleSet>) -> Result<()> {
+        instructions::set_rule_set::handler(ctx)
+    }
+    
+    /// Process incoming NFT from another chain as a single mpl-core asset, for collections using the Core backend
+    pub fn process_incoming_core_nft(
+        ctx: Context<ProcessIncomingCoreNFT>,
+        source_chain_id: u64,
+        payload: CrossChainPayload,
+        zeta_tx_hash: [u8; 32],
+        observer_signatures: Vec<ObserverSignature>,
+    ) -> Result<()> {
+        instructions::process_incoming_core_nft::handler(ctx, source_chain_id, payload, zeta_tx_hash, observer_signatures)
+    }
+    
+    /// Mint an NFT as a Token-2022 mint with metadata-pointer and permanent-delegate extensions (collection authority only)
+    pub fn mint_token_2022_nft(
+        ctx: Context<MintToken2022NFT>,
+        metadata_uri: String,
+        token_id_slot: u64,
+        name: String,
+        symbol: String,
+    ) -> Result<()> {
+        instructions::mint_token_2022_nft::handler(ctx, metadata_uri, token_id_slot, name, symbol)
+    }
+    
+    /// Keep NFTMetadata.owner in sync when Token-2022 moves a hooked NFT (Token-2022 only)
+    pub fn execute_transfer_hook(
+        ctx: Context<ExecuteTransferHook>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::execute_transfer_hook::handler(ctx, amount)
+    }
+    
+    /// Create the Bubblegum merkle tree a compressed collection mints into (collection authority only)
+    pub fn create_collection_tree(
+        ctx: Context<CreateCollectionTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        instructions::create_collection_tree::handler(ctx, max_depth, max_buffer_size)
+    }
+    
+    /// Process incoming NFT from another chain as a compressed NFT in the collection's merkle tree, for collections using the Compressed backend
+    pub fn process_incoming_compressed_nft(
+        ctx: Context<ProcessIncomingCompressedNFT>,
+        source_chain_id: u64,
+        payload: CrossChainPayload,
+        zeta_tx_hash: [u8; 32],
+        observer_signatures: Vec<ObserverSignature>,
+    ) -> Result<()> {
+        instructions::process_incoming_compressed_nft::handler(ctx, source_chain_id, payload, zeta_tx_hash, observer_signatures)
+    }
+    
+    /// Make an NFT's metadata permanently immutable, here and on Metaplex (owner only)
+    pub fn lock_metadata(ctx: Context<LockMetadata>) -> Result<()> {
+        instructions::lock_metadata::handler(ctx)
+    }
+    
+    /// Choose which URI schemes (URI_SCHEME_* bits) metadata URIs may use (authority only)
+    pub fn set_uri_schemes(
+        ctx: Context<SetUriSchemes>,
+        allowed_uri_schemes: u8,
+    ) -> Result<()> {
+        instructions::set_uri_schemes::handler(ctx, allowed_uri_schemes)
+    }
+    
+    /// Set the longest metadata URI new NFTs and metadata updates accept (authority only)
+    pub fn set_max_uri_length(
+        ctx: Context<SetMaxUriLength>,
+        max_uri_length: u16,
+    ) -> Result<()> {
+        instructions::set_max_uri_length::handler(ctx, max_uri_length)
+    }
+    
+    /// Set the name and symbol given to NFTs minted without their own (authority only)
+    pub fn set_collection_branding(
+        ctx: Context<SetCollectionBranding>,
+        default_name: String,
+        default_symbol: String,
+    ) -> Result<()> {
+        instructions::set_collection_branding::handler(ctx, default_name, default_symbol)
+    }
+    
+    /// Set the lamport price of a public mint, creating the treasury on first use (authority only)
+    pub fn set_mint_price(
+        ctx: Context<SetMintPrice>,
+        mint_price: u64,
+    ) -> Result<()> {
+        instructions::set_mint_price::handler(ctx, mint_price)
+    }
+    
+    /// Withdraw mint proceeds above the treasury's rent-exempt minimum (authority only)
+    pub fn withdraw_treasury(
+        ctx: Context<WithdrawTreasury>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_treasury::handler(ctx, amount)
+    }
+    
+    /// Price mints into a collection in an SPL token, opening it to public buyers, or make them free and authority-only with a zero amount (collection authority only)
+    pub fn set_collection_price(
+        ctx: Context<SetCollectionPrice>,
+        price_mint: Pubkey,
+        price_amount: u64,
+    ) -> Result<()> {
+        instructions::set_collection_price::handler(ctx, price_mint, price_amount)
+    }
+    
+    /// Withdraw SPL token mint proceeds from a treasury token account (authority only)
+    pub fn withdraw_treasury_tokens(
+        ctx: Context<WithdrawTreasuryTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_treasury_tokens::handler(ctx, amount)
+    }
+    
+    /// Set the Merkle root of (wallet, max_mints) allowlist leaves, or zeros to disable it (authority only)
+    pub fn set_allowlist_root(
+        ctx: Context<SetAllowlistRoot>,
+        allowlist_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::set_allowlist_root::handler(ctx, allowlist_root)
+    }
+    
+    /// Set the launch schedule public mints follow, or clear it with all zeros (authority only)
+    pub fn set_mint_phases(
+        ctx: Context<SetMintPhases>,
+        mint_phases: MintPhases,
+    ) -> Result<()> {
+        instructions::set_mint_phases::handler(ctx, mint_phases)
+    }
+    
+    /// Mint several NFTs to the authority in one transaction, with per-NFT accounts passed as remaining accounts (authority only)
+    pub fn batch_mint<'info>(
+        ctx: Context<'_, '_, '_, 'info, BatchMint<'info>>,
+        items: Vec<BatchMintItem>,
+        token_id_slot: u64,
+    ) -> Result<()> {
+        instructions::batch_mint::handler(ctx, items, token_id_slot)
+    }
+    
+    /// Create a Merkle airdrop whose leaves each let one wallet claim one NFT (authority only)
+    pub fn create_airdrop(
+        ctx: Context<CreateAirdrop>,
+        merkle_root: [u8; 32],
+        total_count: u32,
+    ) -> Result<()> {
+        instructions::create_airdrop::handler(ctx, merkle_root, total_count)
+    }
+    
+    /// Claim an airdrop leaf, minting its NFT to the claimer
+    pub fn claim_airdrop(
+        ctx: Context<ClaimAirdrop>,
+        index: u32,
+        metadata_uri: String,
+        proof: Vec<[u8; 32]>,
+        token_id_slot: u64,
+    ) -> Result<()> {
+        instructions::claim_airdrop::handler(ctx, index, metadata_uri, proof, token_id_slot)
+    }
+    
+    /// Approve an address to move one NFT on the owner's behalf, or revoke with the default address
+    pub fn approve(
+        ctx: Context<ApproveNFT>,
+        approved: Pubkey,
+    ) -> Result<()> {
+        instructions::approve::handler(ctx, approved)
+    }
+    
+    /// Grant or revoke an operator for all of the owner's NFTs; it can move those delegated with set_program_delegation
+    pub fn set_approval_for_all(
+        ctx: Context<SetApprovalForAll>,
+        operator: Pubkey,
+        approved: bool,
+    ) -> Result<()> {
+        instructions::set_approval_for_all::handler(ctx, operator, approved)
+    }
+    
+    /// Delegate an NFT's token account to the program so approved operators and permits can move it, or revoke
+    pub fn set_program_delegation(
+        ctx: Context<SetProgramDelegation>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_program_delegation::handler(ctx, enabled)
+    }
+    
+    /// Transfer an NFT on the owner's behalf as an approved address or operator
+    pub fn transfer_from(
+        ctx: Context<TransferFrom>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        instructions::transfer_from::handler(ctx, new_owner)
+    }
+    
+    /// Transfer an NFT with the owner's off-chain permit signature, submitted and paid for by a relayer.
+    /// The owner must have delegated the NFT's token account to the program with set_program_delegation
+    pub fn permit_transfer(
+        ctx: Context<PermitTransfer>,
+        new_owner: Pubkey,
+        nonce: u64,
+        expiry: i64,
+    ) -> Result<()> {
+        instructions::permit_transfer::handler(ctx, new_owner, nonce, expiry)
+    }
+    
+    /// Sell an NFT for lamports, paying the creator's royalty out of the price
+    pub fn sell_nft(
+        ctx: Context<SellNFT>,
+        price: u64,
+    ) -> Result<()> {
+        instructions::sell_nft::handler(ctx, price)
+    }
+    
+    /// Close the metadata and origin records of an NFT burned before burn_nft closed them, refunding the rent
+    pub fn close_orphaned_metadata(ctx: Context<CloseOrphanedMetadata>) -> Result<()> {
+        instructions::close_orphaned_metadata::handler(ctx)
+    }
+    
+    /// Burn the escrowed token of a Solana-origin NFT at its holder's request on another chain (gateway only)
+    pub fn process_remote_burn(
+        ctx: Context<ProcessRemoteBurn>,
+        source_chain_id: u64,
+        payload: RemoteBurnPayload,
+        zeta_tx_hash: [u8; 32],
+        observer_signatures: Vec<ObserverSignature>,
+    ) -> Result<()> {
+        instructions::process_remote_burn::handler(ctx, source_chain_id, payload, zeta_tx_hash, observer_signatures)
+    }
+    
+    /// Register a pre-existing Metaplex NFT with a universal token ID so it can be sent cross-chain
+    pub fn wrap_external_nft(
+        ctx: Context<WrapExternalNFT>,
+        token_id_slot: u64,
+    ) -> Result<()> {
+        instructions::wrap_external_nft::handler(ctx, token_id_slot)
+    }
+    
+    /// Release a returning wrapped NFT from escrow to the recipient and remove its wrapper records (gateway only)
+    pub fn unwrap_external_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnwrapExternalNFT<'info>>,
+        source_chain_id: u64,
+        payload: CrossChainPayload,
+        zeta_tx_hash: [u8; 32],
+        observer_signatures: Vec<ObserverSignature>,
+    ) -> Result<()> {
+        instructions::unwrap_external_nft::handler(ctx, source_chain_id, payload, zeta_tx_hash, observer_signatures)
+    }
+    
+    /// Deliver the next NFTs of a multi-asset incoming message, opening its session on the first call
+    pub fn process_incoming_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessIncomingBatch<'info>>,
+        source_chain_id: u64,
+        payload: IncomingBatchPayload,
+        zeta_tx_hash: [u8; 32],
+        observer_signatures: Vec<ObserverSignature>,
+    ) -> Result<()> {
+        instructions::process_incoming_batch::handler(ctx, source_chain_id, payload, zeta_tx_hash, observer_signatures)
+    }
+    
+    /// Claim an incoming NFT that was delivered into escrow for the signing recipient
+    pub fn claim_incoming_nft(ctx: Context<ClaimIncomingNFT>) -> Result<()> {
+        instructions::claim_incoming_nft::handler(ctx)
+    }
+    
+    /// Move an incoming NFT left unclaimed past its claim window to a recovery wallet (authority only)
+    pub fn sweep_unclaimed_nft(ctx: Context<SweepUnclaimedNFT>) -> Result<()> {
+        instructions::sweep_unclaimed_nft::handler(ctx)
+    }
+    
+    /// Drop the program's mint authority on an NFT minted before supplies were fixed at mint time
+    pub fn revoke_mint_authority(ctx: Context<RevokeMintAuthority>) -> Result<()> {
+        instructions::revoke_mint_authority::handler(ctx)
+    }
+    
+    /// Route the transfer hook interface's Execute instruction to execute_transfer_hook
+    pub fn fallback<'info>(
+        program_id: &Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+        data: &[u8],
+    ) -> Result<()> {
+        match TransferHookInstruction::unpack(data)? {
+            TransferHookInstruction::Execute { amount } => {
+                __private::__global::execute_transfer_hook(program_id, accounts, &amount.to_le_bytes())
+            }
+            _ => Err(ProgramError::InvalidInstructionData.into()),
+        }
     }
 }
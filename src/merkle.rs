@@ -0,0 +1,62 @@
+use anchor_lang::solana_program::keccak;
+
+// Merkle inclusion proofs
+//
+// Trees hash sibling pairs in sorted order, matching OpenZeppelin's `MerkleProof.verify`, so roots
+// built for EVM contracts can be checked here without a left/right flag per proof step.
+
+/// Hash two nodes in sorted order
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    if a <= b {
+        keccak::hashv(&[a, b]).to_bytes()
+    } else {
+        keccak::hashv(&[b, a]).to_bytes()
+    }
+}
+
+/// Whether `leaf` is included in the tree with `root` via `proof`
+pub fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: &[u8; 32]) -> bool {
+    let computed = proof.iter().fold(*leaf, |node, sibling| hash_pair(&node, sibling));
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn leaf(byte: u8) -> [u8; 32] {
+        keccak::hash(&[byte]).to_bytes()
+    }
+    
+    #[test]
+    fn verifies_every_leaf_of_a_four_leaf_tree() {
+        let leaves = [leaf(1), leaf(2), leaf(3), leaf(4)];
+        let left = hash_pair(&leaves[0], &leaves[1]);
+        let right = hash_pair(&leaves[2], &leaves[3]);
+        let root = hash_pair(&left, &right);
+        
+        assert!(verify(&[leaves[1], right], &root, &leaves[0]));
+        assert!(verify(&[leaves[0], right], &root, &leaves[1]));
+        assert!(verify(&[leaves[3], left], &root, &leaves[2]));
+        assert!(verify(&[leaves[2], left], &root, &leaves[3]));
+    }
+    
+    #[test]
+    fn hashes_pairs_independently_of_order() {
+        assert_eq!(hash_pair(&leaf(1), &leaf(2)), hash_pair(&leaf(2), &leaf(1)));
+    }
+    
+    #[test]
+    fn rejects_a_leaf_outside_the_tree() {
+        let right = hash_pair(&leaf(3), &leaf(4));
+        let root = hash_pair(&hash_pair(&leaf(1), &leaf(2)), &right);
+        
+        assert!(!verify(&[leaf(2), right], &root, &leaf(5)));
+        assert!(!verify(&[leaf(2)], &root, &leaf(1)));
+    }
+    
+    #[test]
+    fn a_single_leaf_tree_needs_no_proof() {
+        assert!(verify(&[], &leaf(1), &leaf(1)));
+    }
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::mpl_token_metadata::types::{Collection, Creator, DataV2};
+
+use crate::{
+    errors::UniversalNFTError,
+    constants::*,
+};
+
+// Metaplex display data
+//
+// Names, symbols, royalties and creators supplied by minters or carried in incoming payloads are
+// checked against the token metadata program's limits here, before any account is created.
+
+/// Creator listed in the Metaplex metadata; shares across all creators sum to 100
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NFTCreator {
+    pub address: Pubkey,
+    pub share: u8,
+}
+
+/// Validate display data against the token metadata program's limits
+pub fn validate_display_data(name: &str, symbol: &str, seller_fee_basis_points: u16, creators: &[NFTCreator]) -> Result<()> {
+    if name.len() > MAX_NAME_LENGTH {
+        return err!(UniversalNFTError::InvalidMetadataName);
+    }
+    if symbol.len() > MAX_SYMBOL_LENGTH {
+        return err!(UniversalNFTError::InvalidMetadataSymbol);
+    }
+    if seller_fee_basis_points > MAX_ROYALTY_BASIS_POINTS {
+        return err!(UniversalNFTError::InvalidRoyaltyBasisPoints);
+    }
+    
+    // Creators are optional, but when present must be distinct and split the royalties fully
+    if creators.len() > MAX_CREATORS {
+        return err!(UniversalNFTError::InvalidCreators);
+    }
+    if !creators.is_empty() {
+        let total_share: u32 = creators.iter().map(|creator| creator.share as u32).sum();
+        if total_share != 100 {
+            return err!(UniversalNFTError::InvalidCreators);
+        }
+        for (i, creator) in creators.iter().enumerate() {
+            if creators[..i].iter().any(|other| other.address == creator.address) {
+                return err!(UniversalNFTError::InvalidCreators);
+            }
+        }
+    }
+    
+    Ok(())
+}
+
+/// Build the Metaplex data from a name and symbol already resolved with `ProgramState`'s defaults.
+/// Only the update authority, which signs the CPI, can be listed as a verified creator. The
+/// collection starts unverified until `verify_collection_item` runs.
+pub fn build_data_v2(
+    name: &str,
+    symbol: &str,
+    uri: String,
+    seller_fee_basis_points: u16,
+    creators: &[NFTCreator],
+    update_authority: &Pubkey,
+    collection: Option<Pubkey>,
+) -> DataV2 {
+    let creators = if creators.is_empty() {
+        None
+    } else {
+        Some(
+            creators
+                .iter()
+                .map(|creator| Creator {
+                    address: creator.address,
+                    verified: creator.address == *update_authority,
+                    share: creator.share,
+                })
+                .collect(),
+        )
+    };
+    
+    DataV2 {
+        name: name.to_string(),
+        symbol: symbol.to_string(),
+        uri,
+        seller_fee_basis_points,
+        creators,
+        collection: collection.map(|key| Collection { verified: false, key }),
+        uses: None,
+    }
+}
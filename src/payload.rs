@@ -0,0 +1,454 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::UniversalNFTError,
+    metaplex::{NFTCreator, validate_display_data},
+    constants::*,
+};
+
+// Versioned cross-chain payloads
+//
+// Instructions take a typed payload instead of raw bytes so malformed messages are rejected
+// with a specific error before any account is touched. New versions are added as new variants.
+
+/// Versioned payload carried with mints and incoming transfers
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum CrossChainPayload {
+    V1(PayloadV1),
+    V2(PayloadV2),
+    V3(PayloadV3),
+    V4(PayloadV4),
+    V5(PayloadV5),
+}
+
+/// Version 1 payload fields
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PayloadV1 {
+    pub token_id: [u8; 32],
+    pub uri: String,
+    pub recipient: Vec<u8>,
+    pub sender: Vec<u8>,
+    pub royalty_basis_points: u16,
+    pub data: Vec<u8>, // Application-specific extension data
+}
+
+/// Version 2 payload fields: version 1 plus the time the message was sent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PayloadV2 {
+    pub token_id: [u8; 32],
+    pub uri: String,
+    pub recipient: Vec<u8>,
+    pub sender: Vec<u8>,
+    pub royalty_basis_points: u16,
+    pub data: Vec<u8>, // Application-specific extension data
+    pub timestamp: i64, // Unix time the message was sent on the source chain
+}
+
+/// Version 3 payload fields: version 2 plus Metaplex display data. An empty name or symbol
+/// falls back to the collection defaults; `royalty_basis_points` is the seller fee.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PayloadV3 {
+    pub token_id: [u8; 32],
+    pub uri: String,
+    pub recipient: Vec<u8>,
+    pub sender: Vec<u8>,
+    pub royalty_basis_points: u16,
+    pub data: Vec<u8>, // Application-specific extension data
+    pub timestamp: i64, // Unix time the message was sent on the source chain
+    pub name: String,
+    pub symbol: String,
+    pub creators: Vec<NFTCreator>,
+}
+
+/// Version 4 payload fields: version 3 plus a keccak256 commitment to the off-chain metadata JSON
+/// behind `uri`. All zeros means no commitment.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PayloadV4 {
+    pub token_id: [u8; 32],
+    pub uri: String,
+    pub recipient: Vec<u8>,
+    pub sender: Vec<u8>,
+    pub royalty_basis_points: u16,
+    pub data: Vec<u8>, // Application-specific extension data
+    pub timestamp: i64, // Unix time the message was sent on the source chain
+    pub name: String,
+    pub symbol: String,
+    pub creators: Vec<NFTCreator>,
+    pub metadata_hash: [u8; 32],
+}
+
+/// Version 5 payload fields: version 4 plus the number of units moved, for semi-fungible tokens
+/// with a supply above one. Older versions carry a single NFT.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PayloadV5 {
+    pub token_id: [u8; 32],
+    pub uri: String,
+    pub recipient: Vec<u8>,
+    pub sender: Vec<u8>,
+    pub royalty_basis_points: u16,
+    pub data: Vec<u8>, // Application-specific extension data
+    pub timestamp: i64, // Unix time the message was sent on the source chain
+    pub name: String,
+    pub symbol: String,
+    pub creators: Vec<NFTCreator>,
+    pub metadata_hash: [u8; 32],
+    pub amount: u64,
+}
+
+impl CrossChainPayload {
+    /// Validate the payload regardless of version
+    pub fn validate(&self) -> Result<()> {
+        self.to_latest().validate()
+    }
+    
+    /// Latest payload layout, upgrading older versions where needed
+    pub fn to_latest(&self) -> PayloadV5 {
+        match self {
+            CrossChainPayload::V1(payload) => PayloadV5::from(PayloadV4::from(PayloadV3::from(PayloadV2::from(payload.clone())))),
+            CrossChainPayload::V2(payload) => PayloadV5::from(PayloadV4::from(PayloadV3::from(payload.clone()))),
+            CrossChainPayload::V3(payload) => PayloadV5::from(PayloadV4::from(payload.clone())),
+            CrossChainPayload::V4(payload) => PayloadV5::from(payload.clone()),
+            CrossChainPayload::V5(payload) => payload.clone(),
+        }
+    }
+    
+    /// Reject messages sent more than `window` seconds from `now`. Version 1 payloads carry no
+    /// timestamp and are always rejected.
+    pub fn require_fresh(&self, now: i64, window: i64) -> Result<()> {
+        let timestamp = self.to_latest().timestamp;
+        if timestamp <= 0 || now.saturating_sub(timestamp) > window || timestamp.saturating_sub(now) > window {
+            return err!(UniversalNFTError::MessageExpired);
+        }
+        Ok(())
+    }
+    
+    /// Hash committed to on-chain for later verification
+    pub fn hash(&self) -> Result<[u8; 32]> {
+        let bytes = self.try_to_vec()?;
+        Ok(anchor_lang::solana_program::keccak::hash(&bytes).to_bytes())
+    }
+}
+
+impl From<PayloadV1> for PayloadV2 {
+    fn from(payload: PayloadV1) -> Self {
+        PayloadV2 {
+            token_id: payload.token_id,
+            uri: payload.uri,
+            recipient: payload.recipient,
+            sender: payload.sender,
+            royalty_basis_points: payload.royalty_basis_points,
+            data: payload.data,
+            timestamp: 0,
+        }
+    }
+}
+
+impl From<PayloadV2> for PayloadV3 {
+    fn from(payload: PayloadV2) -> Self {
+        PayloadV3 {
+            token_id: payload.token_id,
+            uri: payload.uri,
+            recipient: payload.recipient,
+            sender: payload.sender,
+            royalty_basis_points: payload.royalty_basis_points,
+            data: payload.data,
+            timestamp: payload.timestamp,
+            name: String::new(),
+            symbol: String::new(),
+            creators: Vec::new(),
+        }
+    }
+}
+
+impl From<PayloadV3> for PayloadV4 {
+    fn from(payload: PayloadV3) -> Self {
+        PayloadV4 {
+            token_id: payload.token_id,
+            uri: payload.uri,
+            recipient: payload.recipient,
+            sender: payload.sender,
+            royalty_basis_points: payload.royalty_basis_points,
+            data: payload.data,
+            timestamp: payload.timestamp,
+            name: payload.name,
+            symbol: payload.symbol,
+            creators: payload.creators,
+            metadata_hash: [0u8; 32],
+        }
+    }
+}
+
+impl From<PayloadV4> for PayloadV5 {
+    fn from(payload: PayloadV4) -> Self {
+        PayloadV5 {
+            token_id: payload.token_id,
+            uri: payload.uri,
+            recipient: payload.recipient,
+            sender: payload.sender,
+            royalty_basis_points: payload.royalty_basis_points,
+            data: payload.data,
+            timestamp: payload.timestamp,
+            name: payload.name,
+            symbol: payload.symbol,
+            creators: payload.creators,
+            metadata_hash: payload.metadata_hash,
+            amount: 1,
+        }
+    }
+}
+
+impl PayloadV5 {
+    pub fn validate(&self) -> Result<()> {
+        // Validate metadata URI length
+        if self.uri.len() > MAX_METADATA_URI_LENGTH_LIMIT {
+            return err!(UniversalNFTError::InvalidMetadataURILength);
+        }
+        
+        // Validate addresses
+        if self.recipient.len() > MAX_RECIPIENT_ADDRESS_LENGTH {
+            return err!(UniversalNFTError::InvalidRecipientAddress);
+        }
+        if self.sender.len() > MAX_RECIPIENT_ADDRESS_LENGTH {
+            return err!(UniversalNFTError::InvalidCrossChainData);
+        }
+        
+        // Validate royalties
+        if self.royalty_basis_points > MAX_ROYALTY_BASIS_POINTS {
+            return err!(UniversalNFTError::InvalidRoyaltyBasisPoints);
+        }
+        
+        // Validate extension data length
+        if self.data.len() > MAX_CROSS_CHAIN_DATA_LENGTH {
+            return err!(UniversalNFTError::InvalidCrossChainData);
+        }
+        
+        // Validate display data
+        validate_display_data(&self.name, &self.symbol, self.royalty_basis_points, &self.creators)?;
+        
+        // Validate amount
+        if self.amount == 0 {
+            return err!(UniversalNFTError::InvalidAmount);
+        }
+        
+        Ok(())
+    }
+}
+
+/// Instruction from another chain to burn the escrowed Solana token of `token_id`, relayed by the
+/// gateway for `owner`, the token's holder on that chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RemoteBurnPayload {
+    pub token_id: [u8; 32],
+    pub owner: Vec<u8>, // Holder on the source chain, in that chain's address format
+    pub timestamp: i64, // Unix time the message was sent on the source chain
+}
+
+impl RemoteBurnPayload {
+    pub fn validate(&self) -> Result<()> {
+        if self.owner.is_empty() || self.owner.len() > MAX_RECIPIENT_ADDRESS_LENGTH {
+            return err!(UniversalNFTError::InvalidCrossChainData);
+        }
+        Ok(())
+    }
+    
+    /// Reject messages sent more than `window` seconds from `now`
+    pub fn require_fresh(&self, now: i64, window: i64) -> Result<()> {
+        if self.timestamp <= 0 || now.saturating_sub(self.timestamp) > window || self.timestamp.saturating_sub(now) > window {
+            return err!(UniversalNFTError::MessageExpired);
+        }
+        Ok(())
+    }
+    
+    /// Hash signed by the observers
+    pub fn hash(&self) -> [u8; 32] {
+        // Serializing into a Vec cannot fail
+        let bytes = self.try_to_vec().unwrap_or_default();
+        anchor_lang::solana_program::keccak::hash(&bytes).to_bytes()
+    }
+}
+
+/// Soulbound credential from another chain, imported by `process_incoming_attestation`. The
+/// message is the EVM `abi.encode` NFT layout, with the issuer in its sender field.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AttestationPayload {
+    pub message: Vec<u8>, // Decoded with codec::decode_nft_message
+    pub timestamp: i64, // Unix time the message was sent on the source chain
+}
+
+impl AttestationPayload {
+    /// Reject messages sent more than `window` seconds from `now`
+    pub fn require_fresh(&self, now: i64, window: i64) -> Result<()> {
+        if self.timestamp <= 0 || now.saturating_sub(self.timestamp) > window || self.timestamp.saturating_sub(now) > window {
+            return err!(UniversalNFTError::MessageExpired);
+        }
+        Ok(())
+    }
+    
+    /// Hash signed by the observers
+    pub fn hash(&self) -> [u8; 32] {
+        // Serializing into a Vec cannot fail
+        let bytes = self.try_to_vec().unwrap_or_default();
+        anchor_lang::solana_program::keccak::hash(&bytes).to_bytes()
+    }
+}
+
+/// Many incoming NFTs delivered in one message, e.g. an airdrop from another chain. Each item is
+/// minted to its Solana recipient by `process_incoming_batch`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct IncomingBatchPayload {
+    pub items: Vec<IncomingBatchItem>,
+    pub sender: Vec<u8>, // Sender on the source chain, in that chain's address format
+    pub timestamp: i64, // Unix time the message was sent on the source chain
+}
+
+/// One NFT of an incoming batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct IncomingBatchItem {
+    pub token_id: [u8; 32],
+    pub uri: String,
+    pub recipient: Pubkey,
+}
+
+impl IncomingBatchPayload {
+    pub fn validate(&self) -> Result<()> {
+        if self.items.is_empty() || self.items.len() > MAX_INCOMING_BATCH_SIZE {
+            return err!(UniversalNFTError::InvalidBatchAccounts);
+        }
+        if self.sender.len() > MAX_RECIPIENT_ADDRESS_LENGTH {
+            return err!(UniversalNFTError::InvalidCrossChainData);
+        }
+        for item in &self.items {
+            if item.uri.len() > MAX_METADATA_URI_LENGTH_LIMIT {
+                return err!(UniversalNFTError::InvalidMetadataURILength);
+            }
+            if item.recipient == Pubkey::default() {
+                return err!(UniversalNFTError::InvalidRecipientAddress);
+            }
+        }
+        Ok(())
+    }
+    
+    /// Reject messages sent more than `window` seconds from `now`
+    pub fn require_fresh(&self, now: i64, window: i64) -> Result<()> {
+        if self.timestamp <= 0 || now.saturating_sub(self.timestamp) > window || self.timestamp.saturating_sub(now) > window {
+            return err!(UniversalNFTError::MessageExpired);
+        }
+        Ok(())
+    }
+    
+    /// Hash signed by the observers and used to key the batch session
+    pub fn hash(&self) -> [u8; 32] {
+        // Serializing into a Vec cannot fail
+        let bytes = self.try_to_vec().unwrap_or_default();
+        anchor_lang::solana_program::keccak::hash(&bytes).to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn v1() -> PayloadV1 {
+        PayloadV1 {
+            token_id: [1u8; 32],
+            uri: "ipfs://x".to_string(),
+            recipient: vec![2u8; 32],
+            sender: vec![3u8; 20],
+            royalty_basis_points: 500,
+            data: vec![4u8],
+        }
+    }
+    
+    fn v5() -> PayloadV5 {
+        PayloadV5 {
+            token_id: [1u8; 32],
+            uri: "ipfs://x".to_string(),
+            recipient: vec![2u8; 32],
+            sender: vec![3u8; 20],
+            royalty_basis_points: 500,
+            data: vec![4u8],
+            timestamp: 1_700_000_000,
+            name: "Universal".to_string(),
+            symbol: "UNFT".to_string(),
+            creators: vec![NFTCreator { address: Pubkey::new_unique(), share: 100 }],
+            metadata_hash: [5u8; 32],
+            amount: 7,
+        }
+    }
+    
+    #[test]
+    fn upgrades_v1_with_defaults_for_every_later_field() {
+        let latest = CrossChainPayload::V1(v1()).to_latest();
+        assert_eq!(latest, PayloadV5 {
+            timestamp: 0,
+            name: String::new(),
+            symbol: String::new(),
+            creators: Vec::new(),
+            metadata_hash: [0u8; 32],
+            amount: 1,
+            ..v5()
+        });
+    }
+    
+    #[test]
+    fn upgrades_each_version_keeping_the_fields_it_carries() {
+        let full = v5();
+        let v4 = PayloadV4 {
+            token_id: full.token_id,
+            uri: full.uri.clone(),
+            recipient: full.recipient.clone(),
+            sender: full.sender.clone(),
+            royalty_basis_points: full.royalty_basis_points,
+            data: full.data.clone(),
+            timestamp: full.timestamp,
+            name: full.name.clone(),
+            symbol: full.symbol.clone(),
+            creators: full.creators.clone(),
+            metadata_hash: full.metadata_hash,
+        };
+        let v3 = PayloadV3 {
+            token_id: v4.token_id,
+            uri: v4.uri.clone(),
+            recipient: v4.recipient.clone(),
+            sender: v4.sender.clone(),
+            royalty_basis_points: v4.royalty_basis_points,
+            data: v4.data.clone(),
+            timestamp: v4.timestamp,
+            name: v4.name.clone(),
+            symbol: v4.symbol.clone(),
+            creators: v4.creators.clone(),
+        };
+        let v2 = PayloadV2 {
+            token_id: v3.token_id,
+            uri: v3.uri.clone(),
+            recipient: v3.recipient.clone(),
+            sender: v3.sender.clone(),
+            royalty_basis_points: v3.royalty_basis_points,
+            data: v3.data.clone(),
+            timestamp: v3.timestamp,
+        };
+        
+        assert_eq!(CrossChainPayload::V5(full.clone()).to_latest(), full);
+        assert_eq!(CrossChainPayload::V4(v4).to_latest(), PayloadV5 { amount: 1, ..full.clone() });
+        assert_eq!(CrossChainPayload::V3(v3).to_latest(), PayloadV5 {
+            metadata_hash: [0u8; 32],
+            amount: 1,
+            ..full.clone()
+        });
+        assert_eq!(CrossChainPayload::V2(v2).to_latest(), PayloadV5 {
+            name: String::new(),
+            symbol: String::new(),
+            creators: Vec::new(),
+            metadata_hash: [0u8; 32],
+            amount: 1,
+            ..full
+        });
+    }
+    
+    #[test]
+    fn v1_payloads_are_never_fresh() {
+        assert!(CrossChainPayload::V1(v1()).require_fresh(1_700_000_000, 3_600).is_err());
+        assert!(CrossChainPayload::V5(v5()).require_fresh(1_700_000_000, 3_600).is_ok());
+        assert!(CrossChainPayload::V5(v5()).require_fresh(1_700_003_601, 3_600).is_err());
+    }
+}
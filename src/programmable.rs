@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::mpl_token_metadata::{
+    self,
+    instructions::{CreateV1CpiBuilder, MintV1CpiBuilder, TransferV1CpiBuilder},
+    types::{DataV2, PrintSupply, TokenStandard},
+};
+
+use crate::{
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+// Programmable NFTs
+//
+// pNFT token accounts stay frozen, so they are created, minted and moved through token metadata's
+// V1 instructions, which update the token records and run the rule set on every transfer.
+
+/// Token metadata accounts shared by every pNFT operation on one mint
+pub struct ProgrammableAccounts<'info> {
+    pub token_metadata_program: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub master_edition: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub authorization_rules_program: AccountInfo<'info>,
+    pub authorization_rules: AccountInfo<'info>,
+}
+
+/// pNFT accounts for a transfer, passed as remaining accounts in this order:
+/// metadata, master edition, source token record, destination token record, token metadata
+/// program, instructions sysvar, token auth rules program, rule set
+pub struct ProgrammableTransfer<'info> {
+    pub accounts: ProgrammableAccounts<'info>,
+    pub token_record: AccountInfo<'info>,
+    pub destination_token_record: AccountInfo<'info>,
+}
+
+impl<'info> ProgrammableAccounts<'info> {
+    /// Check the accounts belong to `mint` and the real Metaplex programs
+    pub fn validate(&self, mint: &Pubkey) -> Result<()> {
+        if self.token_metadata_program.key() != mpl_token_metadata::ID
+            || self.authorization_rules_program.key() != TOKEN_AUTH_RULES_PROGRAM_ID
+            || self.sysvar_instructions.key() != solana_program::sysvar::instructions::ID
+        {
+            return err!(UniversalNFTError::InvalidRuleSet);
+        }
+        if self.metadata.key() != find_metaplex_metadata(mint).0 || self.master_edition.key() != find_master_edition(mint).0 {
+            return err!(UniversalNFTError::InvalidRuleSet);
+        }
+        Ok(())
+    }
+}
+
+impl<'info> ProgrammableTransfer<'info> {
+    /// Read the pNFT accounts from `remaining_accounts`; None when the NFT is a standard one
+    pub fn from_remaining(remaining_accounts: &[AccountInfo<'info>], mint: &Pubkey) -> Result<Option<Self>> {
+        if remaining_accounts.is_empty() {
+            return Ok(None);
+        }
+        if remaining_accounts.len() != 8 {
+            return err!(UniversalNFTError::InvalidRuleSet);
+        }
+        let transfer = Self {
+            accounts: ProgrammableAccounts {
+                metadata: remaining_accounts[0].clone(),
+                master_edition: remaining_accounts[1].clone(),
+                token_metadata_program: remaining_accounts[4].clone(),
+                sysvar_instructions: remaining_accounts[5].clone(),
+                authorization_rules_program: remaining_accounts[6].clone(),
+                authorization_rules: remaining_accounts[7].clone(),
+            },
+            token_record: remaining_accounts[2].clone(),
+            destination_token_record: remaining_accounts[3].clone(),
+        };
+        transfer.accounts.validate(mint)?;
+        Ok(Some(transfer))
+    }
+    
+    /// Move the pNFT with TransferV1; `signer_seeds` signs for a PDA authority such as the escrow
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer(
+        &self,
+        mint: &AccountInfo<'info>,
+        token: &AccountInfo<'info>,
+        token_owner: &AccountInfo<'info>,
+        destination_token: &AccountInfo<'info>,
+        destination_owner: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        token_program: &AccountInfo<'info>,
+        associated_token_program: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        TransferV1CpiBuilder::new(&self.accounts.token_metadata_program)
+            .token(token)
+            .token_owner(token_owner)
+            .destination_token(destination_token)
+            .destination_owner(destination_owner)
+            .mint(mint)
+            .metadata(&self.accounts.metadata)
+            .edition(Some(&self.accounts.master_edition))
+            .token_record(Some(&self.token_record))
+            .destination_token_record(Some(&self.destination_token_record))
+            .authority(authority)
+            .payer(payer)
+            .system_program(system_program)
+            .sysvar_instructions(&self.accounts.sysvar_instructions)
+            .spl_token_program(token_program)
+            .spl_ata_program(associated_token_program)
+            .authorization_rules_program(Some(&self.accounts.authorization_rules_program))
+            .authorization_rules(Some(&self.accounts.authorization_rules))
+            .amount(1)
+            .invoke_signed(signer_seeds)?;
+        Ok(())
+    }
+}
+
+/// Create a one-of-one pNFT bound to the rule set and mint it into `token`
+#[allow(clippy::too_many_arguments)]
+pub fn create_and_mint<'info>(
+    accounts: &ProgrammableAccounts<'info>,
+    data: DataV2,
+    mint: &AccountInfo<'info>,
+    token: &AccountInfo<'info>,
+    token_owner: &AccountInfo<'info>,
+    token_record: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    update_authority: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut create = CreateV1CpiBuilder::new(&accounts.token_metadata_program);
+    create
+        .metadata(&accounts.metadata)
+        .master_edition(Some(&accounts.master_edition))
+        .mint(mint, false)
+        .authority(authority)
+        .payer(payer)
+        .update_authority(update_authority, true)
+        .system_program(system_program)
+        .sysvar_instructions(&accounts.sysvar_instructions)
+        .spl_token_program(token_program)
+        .name(data.name)
+        .symbol(data.symbol)
+        .uri(data.uri)
+        .seller_fee_basis_points(data.seller_fee_basis_points)
+        .primary_sale_happened(false)
+        .is_mutable(true)
+        .token_standard(TokenStandard::ProgrammableNonFungible)
+        .print_supply(PrintSupply::Zero)
+        .rule_set(accounts.authorization_rules.key());
+    if let Some(creators) = data.creators {
+        create.creators(creators);
+    }
+    if let Some(collection) = data.collection {
+        create.collection(collection);
+    }
+    create.invoke_signed(signer_seeds)?;
+    
+    // The master edition holds the mint authority now, so the update authority mints
+    MintV1CpiBuilder::new(&accounts.token_metadata_program)
+        .token(token)
+        .token_owner(Some(token_owner))
+        .metadata(&accounts.metadata)
+        .master_edition(Some(&accounts.master_edition))
+        .token_record(Some(token_record))
+        .mint(mint)
+        .authority(update_authority)
+        .payer(payer)
+        .system_program(system_program)
+        .sysvar_instructions(&accounts.sysvar_instructions)
+        .spl_token_program(token_program)
+        .spl_ata_program(associated_token_program)
+        .authorization_rules_program(Some(&accounts.authorization_rules_program))
+        .authorization_rules(Some(&accounts.authorization_rules))
+        .amount(1)
+        .invoke_signed(signer_seeds)?;
+    Ok(())
+}
@@ -0,0 +1,240 @@
+use anchor_lang::prelude::*;
+
+// PDA seeds
+//
+// Every program-derived address is built from these seeds. Off-chain clients should use the
+// `find_*` helpers instead of repeating the byte strings.
+
+pub const PROGRAM_STATE_SEED: &[u8] = b"program_state";
+pub const GATEWAY_STATE_SEED: &[u8] = b"gateway_state";
+pub const CHAIN_CONFIG_SEED: &[u8] = b"chain_config";
+pub const NFT_METADATA_SEED: &[u8] = b"nft_metadata";
+pub const TOKEN_ID_SEED: &[u8] = b"nft_origin"; // NFTOrigin, keyed by token ID
+pub const CROSS_CHAIN_TRANSFER_SEED: &[u8] = b"cross_chain_transfer";
+pub const OWNERSHIP_VERIFICATION_SEED: &[u8] = b"ownership_verification";
+pub const ESCROW_AUTHORITY_SEED: &[u8] = b"escrow_authority";
+pub const MINT_AUTHORITY_SEED: &[u8] = b"mint_authority";
+pub const OUTBOX_SEED: &[u8] = b"outbox";
+pub const OUTBOX_MESSAGE_SEED: &[u8] = b"outbox_message";
+pub const NOTIFICATION_SEED: &[u8] = b"notification";
+pub const ATTESTATION_SEED: &[u8] = b"attestation";
+pub const IDENTITY_SEED: &[u8] = b"identity";
+pub const REWARDS_CONFIG_SEED: &[u8] = b"rewards_config";
+pub const POINTS_SEED: &[u8] = b"points";
+pub const COLLECTION_CONFIG_SEED: &[u8] = b"collection_config";
+pub const PAUSE_CONFIG_SEED: &[u8] = b"pause_config";
+pub const RATE_LIMITER_SEED: &[u8] = b"rate_limiter";
+pub const OWNER_VELOCITY_SEED: &[u8] = b"owner_velocity";
+pub const OWNERSHIP_ROOT_SEED: &[u8] = b"ownership_root";
+pub const FROZEN_ASSET_SEED: &[u8] = b"frozen_asset";
+pub const DENYLIST_SEED: &[u8] = b"denylist";
+pub const ALLOWLIST_SEED: &[u8] = b"allowlist";
+pub const PENDING_DELIVERY_SEED: &[u8] = b"pending_delivery";
+pub const RELAYER_RECORD_SEED: &[u8] = b"relayer_record";
+pub const VERIFIED_COLLECTION_SEED: &[u8] = b"verified_collection";
+pub const COLLECTION_STATE_SEED: &[u8] = b"collection_state";
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas"; // Seed fixed by the transfer hook interface
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const ALLOWLIST_MINT_SEED: &[u8] = b"allowlist_mint";
+pub const AIRDROP_SEED: &[u8] = b"airdrop";
+pub const AIRDROP_CLAIM_SEED: &[u8] = b"airdrop_claim";
+pub const TOKEN_APPROVAL_SEED: &[u8] = b"token_approval";
+pub const OPERATOR_APPROVAL_SEED: &[u8] = b"operator_approval";
+pub const TRANSFER_AUTHORITY_SEED: &[u8] = b"transfer_authority";
+pub const PERMIT_NONCE_SEED: &[u8] = b"permit_nonce";
+pub const WRAPPED_NFT_SEED: &[u8] = b"wrapped_nft";
+pub const TOKEN_BALANCE_SEED: &[u8] = b"token_balance";
+pub const INCOMING_BATCH_SEED: &[u8] = b"incoming_batch";
+pub const CLAIMABLE_NFT_SEED: &[u8] = b"claimable_nft";
+pub const PROVENANCE_SEED: &[u8] = b"provenance";
+pub const TRANSFER_HISTORY_SEED: &[u8] = b"transfer_history";
+
+// Metaplex token metadata PDAs, derived under the token metadata program
+pub const METAPLEX_METADATA_SEED: &[u8] = b"metadata";
+pub const METAPLEX_EDITION_SEED: &[u8] = b"edition";
+
+pub fn find_program_state() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROGRAM_STATE_SEED], &crate::ID)
+}
+
+pub fn find_gateway_state() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GATEWAY_STATE_SEED], &crate::ID)
+}
+
+pub fn find_chain_config(chain_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CHAIN_CONFIG_SEED, &chain_id.to_le_bytes()], &crate::ID)
+}
+
+pub fn find_nft_metadata(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[NFT_METADATA_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn find_nft_origin(token_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TOKEN_ID_SEED, token_id.as_ref()], &crate::ID)
+}
+
+pub fn find_cross_chain_transfer(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CROSS_CHAIN_TRANSFER_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn find_ownership_verification(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[OWNERSHIP_VERIFICATION_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn find_escrow_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ESCROW_AUTHORITY_SEED], &crate::ID)
+}
+
+pub fn find_mint_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MINT_AUTHORITY_SEED], &crate::ID)
+}
+
+pub fn find_outbox() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[OUTBOX_SEED], &crate::ID)
+}
+
+pub fn find_outbox_message(sequence: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[OUTBOX_MESSAGE_SEED, &sequence.to_le_bytes()], &crate::ID)
+}
+
+pub fn find_notification(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[NOTIFICATION_SEED, wallet.as_ref()], &crate::ID)
+}
+
+pub fn find_attestation(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ATTESTATION_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn find_identity(remote_address: &[u8; 20]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[IDENTITY_SEED, remote_address.as_ref()], &crate::ID)
+}
+
+pub fn find_rewards_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REWARDS_CONFIG_SEED], &crate::ID)
+}
+
+pub fn find_collection_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COLLECTION_CONFIG_SEED], &crate::ID)
+}
+
+pub fn find_points(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POINTS_SEED, wallet.as_ref()], &crate::ID)
+}
+
+pub fn find_pause_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PAUSE_CONFIG_SEED], &crate::ID)
+}
+
+pub fn find_rate_limiter() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RATE_LIMITER_SEED], &crate::ID)
+}
+
+pub fn find_owner_velocity(owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[OWNER_VELOCITY_SEED, owner.as_ref()], &crate::ID)
+}
+
+pub fn find_ownership_root(chain_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[OWNERSHIP_ROOT_SEED, &chain_id.to_le_bytes()], &crate::ID)
+}
+
+pub fn find_frozen_asset(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FROZEN_ASSET_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn find_denylist() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DENYLIST_SEED], &crate::ID)
+}
+
+pub fn find_allowlist_entry(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ALLOWLIST_SEED, wallet.as_ref()], &crate::ID)
+}
+
+pub fn find_pending_delivery(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PENDING_DELIVERY_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn find_relayer_record(relayer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RELAYER_RECORD_SEED, relayer.as_ref()], &crate::ID)
+}
+
+pub fn find_verified_collection() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VERIFIED_COLLECTION_SEED], &crate::ID)
+}
+
+pub fn find_collection_state(collection_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COLLECTION_STATE_SEED, &collection_id.to_le_bytes()], &crate::ID)
+}
+
+pub fn find_extra_account_metas(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EXTRA_ACCOUNT_METAS_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn find_treasury() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TREASURY_SEED], &crate::ID)
+}
+
+pub fn find_allowlist_mint_record(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ALLOWLIST_MINT_SEED, wallet.as_ref()], &crate::ID)
+}
+
+pub fn find_airdrop(merkle_root: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AIRDROP_SEED, merkle_root], &crate::ID)
+}
+
+pub fn find_airdrop_claim(airdrop: &Pubkey, index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AIRDROP_CLAIM_SEED, airdrop.as_ref(), &index.to_le_bytes()], &crate::ID)
+}
+
+pub fn find_token_approval(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TOKEN_APPROVAL_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn find_operator_approval(owner: &Pubkey, operator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[OPERATOR_APPROVAL_SEED, owner.as_ref(), operator.as_ref()], &crate::ID)
+}
+
+pub fn find_transfer_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TRANSFER_AUTHORITY_SEED], &crate::ID)
+}
+
+pub fn find_permit_nonce(owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PERMIT_NONCE_SEED, owner.as_ref()], &crate::ID)
+}
+
+pub fn find_wrapped_nft(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[WRAPPED_NFT_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn find_token_balance(token_id: &[u8; 32], chain_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TOKEN_BALANCE_SEED, token_id.as_ref(), &chain_id.to_le_bytes()], &crate::ID)
+}
+
+pub fn find_incoming_batch(payload_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[INCOMING_BATCH_SEED, payload_hash.as_ref()], &crate::ID)
+}
+
+pub fn find_claimable_nft(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CLAIMABLE_NFT_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn find_provenance(token_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROVENANCE_SEED, token_id.as_ref()], &crate::ID)
+}
+
+pub fn find_transfer_history(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TRANSFER_HISTORY_SEED, mint.as_ref()], &crate::ID)
+}
+
+pub fn find_metaplex_metadata(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[METAPLEX_METADATA_SEED, mpl_token_metadata::ID.as_ref(), mint.as_ref()],
+        &mpl_token_metadata::ID,
+    )
+}
+
+pub fn find_master_edition(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[METAPLEX_METADATA_SEED, mpl_token_metadata::ID.as_ref(), mint.as_ref(), METAPLEX_EDITION_SEED],
+        &mpl_token_metadata::ID,
+    )
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    secp256k1_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+use crate::{
+    errors::UniversalNFTError,
+    constants::*,
+};
+
+// Signature precompile introspection
+//
+// Relayers can attach signatures as native ed25519/secp256k1 program instructions earlier in the
+// same transaction. The runtime verifies those before the program runs, so here we only read the
+// instructions sysvar and check that each verified signature covers the expected message.
+
+const SECP256K1_OFFSETS_START: usize = 1;
+const SECP256K1_OFFSETS_LEN: usize = 11;
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Ethereum addresses whose secp256k1 signatures over `message` were verified by preceding
+/// secp256k1 program instructions. The precompile signs `keccak(message)`.
+pub fn secp256k1_verified_addresses(instructions: &AccountInfo, message: &[u8]) -> Result<Vec<[u8; 20]>> {
+    let mut addresses = Vec::new();
+    let current_index = load_current_index_checked(instructions)?;
+    
+    for index in 0..current_index {
+        let ix = load_instruction_at_checked(index as usize, instructions)?;
+        if ix.program_id != secp256k1_program::ID {
+            continue;
+        }
+        
+        let data = &ix.data;
+        let count = *data.first().ok_or(error!(UniversalNFTError::InvalidPrecompileInstruction))? as usize;
+        for i in 0..count {
+            let offsets = slice(data, SECP256K1_OFFSETS_START + i * SECP256K1_OFFSETS_LEN, SECP256K1_OFFSETS_LEN)?;
+            let signature_ix = offsets[2];
+            let address_offset = read_u16(offsets, 3) as usize;
+            let address_ix = offsets[5];
+            let message_offset = read_u16(offsets, 6) as usize;
+            let message_size = read_u16(offsets, 8) as usize;
+            let message_ix = offsets[10];
+            
+            // Every field must live in this instruction so the message we compare is the one verified
+            if [signature_ix, address_ix, message_ix].iter().any(|ix_index| *ix_index as u16 != index) {
+                return err!(UniversalNFTError::InvalidPrecompileInstruction);
+            }
+            if slice(data, message_offset, message_size)? != message {
+                continue;
+            }
+            
+            let mut address = [0u8; EVM_ADDRESS_LENGTH];
+            address.copy_from_slice(slice(data, address_offset, EVM_ADDRESS_LENGTH)?);
+            addresses.push(address);
+        }
+    }
+    
+    Ok(addresses)
+}
+
+/// Public keys whose ed25519 signatures over `message` were verified by preceding ed25519
+/// program instructions
+pub fn ed25519_verified_signers(instructions: &AccountInfo, message: &[u8]) -> Result<Vec<Pubkey>> {
+    let mut signers = Vec::new();
+    let current_index = load_current_index_checked(instructions)?;
+    
+    for index in 0..current_index {
+        let ix = load_instruction_at_checked(index as usize, instructions)?;
+        if ix.program_id != ed25519_program::ID {
+            continue;
+        }
+        
+        let data = &ix.data;
+        let count = *data.first().ok_or(error!(UniversalNFTError::InvalidPrecompileInstruction))? as usize;
+        for i in 0..count {
+            let offsets = slice(data, ED25519_OFFSETS_START + i * ED25519_OFFSETS_LEN, ED25519_OFFSETS_LEN)?;
+            let signature_ix = read_u16(offsets, 2);
+            let public_key_offset = read_u16(offsets, 4) as usize;
+            let public_key_ix = read_u16(offsets, 6);
+            let message_offset = read_u16(offsets, 8) as usize;
+            let message_size = read_u16(offsets, 10) as usize;
+            let message_ix = read_u16(offsets, 12);
+            
+            // Every field must live in this instruction so the message we compare is the one verified
+            if [signature_ix, public_key_ix, message_ix].iter().any(|ix_index| *ix_index != ED25519_CURRENT_INSTRUCTION && *ix_index != index) {
+                return err!(UniversalNFTError::InvalidPrecompileInstruction);
+            }
+            if slice(data, message_offset, message_size)? != message {
+                continue;
+            }
+            
+            let public_key = Pubkey::try_from(slice(data, public_key_offset, 32)?)
+                .map_err(|_| error!(UniversalNFTError::InvalidPrecompileInstruction))?;
+            signers.push(public_key);
+        }
+    }
+    
+    Ok(signers)
+}
+
+fn slice(data: &[u8], offset: usize, len: usize) -> Result<&[u8]> {
+    let end = offset.checked_add(len).ok_or(error!(UniversalNFTError::InvalidPrecompileInstruction))?;
+    data.get(offset..end).ok_or(error!(UniversalNFTError::InvalidPrecompileInstruction))
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
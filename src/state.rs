@@ -1,25 +1,52 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
+use anchor_lang::solana_program::{keccak, secp256k1_recover::secp256k1_recover};
+use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
+
+use crate::{
+    errors::UniversalNFTError,
+    events::ProvenanceRecorded,
+    constants::{MAX_RECIPIENT_ADDRESS_LENGTH, MAX_SUPPORTED_CHAINS, SECONDS_PER_DAY, MAX_OBSERVERS, MAX_DENYLIST_ENTRIES, EVM_ADDRESS_LENGTH, DEFAULT_GATEWAY_ADDRESS, GATEWAY_FRESHNESS_WINDOW, MAX_METADATA_URI_LENGTH, MAX_METADATA_URI_LENGTH_LIMIT, URI_SCHEME_ALL, URI_SCHEME_PREFIXES, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, DEFAULT_METADATA_NAME, DEFAULT_METADATA_SYMBOL, MAX_MEMO_LENGTH, MAX_ROYALTY_BASIS_POINTS, CLAIM_EXPIRY_PERIOD, MAX_PROVENANCE_HOPS, TRANSFER_HISTORY_LEN, ACCOUNT_VERSION},
+};
 
 /// Program state for the Universal NFT program
 #[account]
 pub struct ProgramState {
     pub authority: Pubkey,
+    pub pending_authority: Pubkey, // Proposed authority awaiting acceptance (default = none)
+    pub authority_program: Pubkey, // Multisig program that relays admin calls (default = direct signer)
     pub total_minted: u64,
     pub max_supply: u64,
     pub next_token_id: u64, // Added: Unique token ID counter
+    pub feature_flags: u64, // Enabled instruction families (FEATURE_* bits)
+    pub paused: bool, // Circuit breaker halting mints, transfers and bridge traffic
     pub bump: u8,
     pub created_at: i64,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+    pub default_name: String, // Metaplex name for NFTs minted without one; empty predates branding (layout 10)
+    pub default_symbol: String, // Metaplex symbol for NFTs minted without one; empty predates branding (layout 10)
 }
 
 /// ZetaChain gateway configuration
 #[account]
 pub struct ZetaChainGatewayState {
     pub gateway_address: [u8; 20],
-    pub supported_chains: Vec<u64>,
+    pub gateway_authority: Pubkey, // Solana signer used by the ZetaChain gateway/TSS
+    pub current_valid_until: i64, // Last timestamp the current gateway is accepted (i64::MAX when open-ended)
+    pub next_gateway_address: [u8; 20],
+    pub next_gateway_authority: Pubkey, // Incoming gateway signer during a migration
+    pub next_valid_from: i64, // First timestamp the next gateway is accepted
+    pub registered_chain_mask: u128, // Bit i set when chain index i is assigned to a ChainConfig
+    pub supported_chain_mask: u128, // Bit i set when the chain at index i is enabled
+    pub observers: [[u8; 20]; MAX_OBSERVERS], // Ethereum addresses of the observer/TSS signers
+    pub observer_count: u8,
+    pub observer_threshold: u8, // Signatures required on every incoming message
     pub version: u8,
+    pub transfer_timeout: i64, // Seconds before an unconfirmed transfer can be cancelled
+    pub message_expiry_window: i64, // Seconds an inbound message stays valid after it was sent
+    pub challenge_period: i64, // Seconds incoming NFTs stay in escrow before release (0 finalizes instantly)
     pub updated_at: i64,
     pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
 }
 
 /// NFT metadata and cross-chain information
@@ -30,46 +57,453 @@ pub struct NFTMetadata {
     pub metadata_uri: String,
     pub zeta_chain_id: u64,
     pub cross_chain_data_hash: [u8; 32],
-    pub token_id: u64, // Added: Universal token ID
+    pub token_id: [u8; 32], // Universal token ID (uint256, big-endian)
+    pub locked: bool, // Set while a cross-chain operation holds the NFT; blocks local mutations
+    pub owner_locked: bool, // Set by the owner via lock_nft; cleared by the owner or the authority
     pub created_at: i64,
     pub updated_at: i64,
     pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+    pub collection: Pubkey, // CollectionState the NFT belongs to; default for the global collection (layout 2)
+    pub metadata_immutable: bool, // Set for good by lock_metadata; blocks metadata updates (layout 6)
+    pub metadata_hash: [u8; 32], // keccak256 of the off-chain metadata JSON; all zeros when not committed (layout 8)
+    pub royalty_basis_points: u16, // Creator's share of sales made through the program (layout 16)
+    pub royalty_recipient: Pubkey, // Creator paid the royalty; default when none is due (layout 16)
 }
 
 /// NFT origin tracking for Universal NFT Protocol
 #[account]
 pub struct NFTOrigin {
-    pub token_id: u64,
+    pub token_id: [u8; 32],
     pub original_mint: Pubkey, // Original mint key from source chain
     pub original_metadata_uri: String,
     pub source_chain_id: u64,
     pub created_at: i64,
     pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
 }
 
 /// Cross-chain transfer state
 #[account]
 pub struct CrossChainTransferState {
     pub nft_mint: Pubkey,
-    pub token_id: u64, // Added: Universal NFT token ID
+    pub token_id: [u8; 32], // Universal NFT token ID (uint256, big-endian)
+    pub sender: Pubkey, // Original Solana owner, used to restore the NFT on revert
     pub source_chain_id: u64,
     pub target_chain_id: u64,
     pub recipient: Vec<u8>,
     pub status: TransferStatus,
     pub zeta_tx_hash: [u8; 32],
+    pub gas_amount: u64, // Lamports held for destination gas, paid to the gateway on confirm
+    pub gas_limit: u64, // Execution gas limit on the target chain
     pub created_at: i64,
     pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+    pub memo: String, // Sender's reference for reconciliation, e.g. an order ID; empty when none (layout 15)
+    pub amount: u64, // Units escrowed; zero in older layouts, which always moved one NFT (layout 17)
 }
 
 /// Ownership verification state
 #[account]
 pub struct OwnershipVerificationState {
     pub nft_mint: Pubkey,
-    pub zeta_owner: Vec<u8>,
-    pub proof_hash: [u8; 32],
+    pub zeta_owner: Vec<u8>, // Owner address on the remote chain, in that chain's address format
+    pub owner_chain_id: u64, // Chain the owner was proven on
+    pub proof_hash: [u8; 32], // Ownership root the proof was checked against
     pub verified: bool,
     pub verified_at: i64,
     pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Outbound message queue tracking the next sequence number
+#[account]
+pub struct Outbox {
+    pub next_sequence: u64,
+    pub standard_lane: LaneStats,
+    pub priority_lane: LaneStats,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Outbound message awaiting pickup by ZetaChain relayers
+#[account]
+pub struct OutboxMessage {
+    pub sequence: u64,
+    pub nft_mint: Pubkey,
+    pub token_id: [u8; 32],
+    pub sender: Pubkey,
+    pub target_chain_id: u64,
+    pub recipient: Vec<u8>,
+    pub payload: Vec<u8>, // Encoded message bytes for the gateway
+    pub lane: MessageLane, // Relayer cranks drain the priority lane first
+    pub fee_paid: u64, // Lane fee in lamports
+    pub gas_amount: u64, // Destination gas funding in lamports
+    pub created_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Wallet opt-in for off-chain arrival notifications
+#[account]
+pub struct NotificationRegistration {
+    pub wallet: Pubkey,
+    pub commitment: [u8; 32], // Hash of the webhook URL or notifier pubkey
+    pub registered_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Soulbound credential imported from another chain
+#[account]
+pub struct AttestationRecord {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub issuer: Vec<u8>, // Issuer address on the source chain
+    pub token_id: [u8; 32],
+    pub source_chain_id: u64,
+    pub metadata_uri: String,
+    pub zeta_tx_hash: [u8; 32],
+    pub created_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Verified link between a Solana wallet and an address on another chain
+#[account]
+pub struct IdentityBinding {
+    pub wallet: Pubkey,
+    pub remote_address: [u8; 20],
+    pub chain_id: u64, // Chain the proof was produced for
+    pub bound_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Bridge rewards schedule and redemption hook
+#[account]
+pub struct RewardsConfig {
+    pub points_per_round_trip: u64,
+    pub redemption_hook: Pubkey, // Program invoked on redemption (default = none)
+    pub updated_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Per-wallet rewards points balance
+#[account]
+pub struct PointsLedger {
+    pub wallet: Pubkey,
+    pub balance: u64,
+    pub lifetime_earned: u64,
+    pub round_trips: u64,
+    pub last_credited_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Per-chain routing configuration
+#[account]
+pub struct ChainConfig {
+    pub chain_id: u64,
+    pub chain_index: u8, // Bit position in the gateway's chain masks
+    pub name: String,
+    pub address_format: AddressFormat,
+    pub required_confirmations: u32, // Source-chain confirmations observers wait for
+    pub default_gas_limit: u64, // Gas limit used when a transfer doesn't specify one
+    pub max_gas_limit: u64, // Upper bound for caller-specified gas limits
+    pub enabled: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Collection-wide policy configuration
+#[account]
+pub struct CollectionConfig {
+    pub royalty_policy: RoyaltyPolicy,
+    pub emission_start: i64, // Start of the first emission period
+    pub emission_period: i64, // Period length in seconds (0 = no emission schedule)
+    pub emission_per_period: u64, // Supply released each period
+    pub updated_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+    pub rule_set: Pubkey, // Token auth rule set minted pNFTs are bound to; default mints standard NFTs (layout 3)
+    pub allowed_uri_schemes: u8, // URI_SCHEME_* bits metadata URIs may use; 0 predates the allowlist and allows all (layout 7)
+    pub max_uri_length: u16, // Longest NFT metadata URI accepted; 0 predates the setting and means MAX_METADATA_URI_LENGTH (layout 9)
+    pub mint_price: u64, // Lamports a public mint pays into the treasury; 0 = free (layout 11)
+    pub allowlist_root: [u8; 32], // Merkle root of (wallet, max_mints) allowlist leaves; zeros = none (layout 13)
+    pub mint_phases: MintPhases, // Launch schedule public mints follow, set by set_mint_phases (layout 14)
+    pub allowlist_phase_minted: u64, // Mints made in the allowlist phase of the current schedule (layout 14)
+    pub public_phase_minted: u64, // Mints made in the public phase of the current schedule (layout 14)
+}
+
+/// Operations the authority has individually paused
+#[account]
+pub struct PauseConfig {
+    pub paused_operations: u64, // PAUSE_* bits
+    pub watcher: Pubkey, // May freeze individual assets pending review (default: none)
+    pub updated_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Watcher flag holding a mint and its cross-chain transfer for review; exists only while frozen
+#[account]
+pub struct FrozenAsset {
+    pub mint: Pubkey,
+    pub frozen_by: Pubkey,
+    pub frozen_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Compliance denylist of recipient address hashes
+#[account]
+pub struct Denylist {
+    pub entries: Vec<[u8; 32]>, // keccak(address bytes) of each denied address
+    pub updated_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Wallet allowed to mint while allowlist-gated minting is enabled
+#[account]
+pub struct AllowlistEntry {
+    pub wallet: Pubkey,
+    pub added_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Mints a wallet has made through the Merkle allowlist or mint coupons, checked against their limit
+#[account]
+pub struct AllowlistMintRecord {
+    pub wallet: Pubkey,
+    pub minted: u32,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Merkle airdrop: each leaf entitles one wallet to claim one NFT with a given URI
+#[account]
+pub struct Airdrop {
+    pub authority: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub total_count: u32, // Leaves in the tree
+    pub claimed_count: u32,
+    pub created_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Claimed airdrop leaf; its existence keeps the leaf from being claimed again
+#[account]
+pub struct AirdropClaim {
+    pub airdrop: Pubkey,
+    pub index: u32,
+    pub claimer: Pubkey,
+    pub mint: Pubkey,
+    pub claimed_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Single-NFT approval, like ERC-721 `approve`; only valid while `owner` still owns the NFT
+#[account]
+pub struct TokenApproval {
+    pub mint: Pubkey,
+    pub owner: Pubkey, // Owner that granted the approval
+    pub approved: Pubkey, // Default when revoked
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Operator allowed to move all of an owner's NFTs, like ERC-721 `setApprovalForAll`
+#[account]
+pub struct OperatorApproval {
+    pub owner: Pubkey,
+    pub operator: Pubkey,
+    pub approved: bool,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Next permit nonce of an owner; each signed permit can be used once
+#[account]
+pub struct PermitNonce {
+    pub owner: Pubkey,
+    pub nonce: u64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Pre-existing Metaplex NFT registered with `wrap_external_nft` so it can travel cross-chain
+#[account]
+pub struct WrappedNFT {
+    pub mint: Pubkey,
+    pub token_id: [u8; 32],
+    pub wrapper: Pubkey, // Holder that wrapped it
+    pub wrapped_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Units of a semi-fungible token held on one chain, keyed by token ID and ZetaChain chain ID
+#[account]
+pub struct TokenBalance {
+    pub token_id: [u8; 32],
+    pub chain_id: u64,
+    pub amount: u64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Delivery session of an incoming batch, keyed by the payload hash. Created once the observers'
+/// signatures are verified; each `process_incoming_batch` call mints the next items.
+#[account]
+pub struct IncomingBatch {
+    pub payload_hash: [u8; 32],
+    pub source_chain_id: u64,
+    pub zeta_tx_hash: [u8; 32],
+    pub total_items: u16,
+    pub processed_items: u16,
+    pub created_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Multichain history of a token ID, appended by every send confirmed and every NFT received on Solana
+#[account]
+pub struct Provenance {
+    pub token_id: [u8; 32],
+    pub hops: Vec<ProvenanceHop>, // Oldest first, up to MAX_PROVENANCE_HOPS
+    pub total_hops: u32, // Every hop recorded, including any past the stored ones
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Latest ownership changes of an NFT, kept in a fixed-size ring buffer for activity feeds
+#[account]
+pub struct TransferHistory {
+    pub mint: Pubkey,
+    pub entries: Vec<TransferRecord>, // Up to TRANSFER_HISTORY_LEN, filled in order and then overwritten from the oldest
+    pub head: u8, // Index of the oldest record once the buffer is full; the next one overwritten
+    pub total_transfers: u32, // Every record written, including those overwritten
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Incoming NFT held in escrow until the recipient claims it with `claim_incoming_nft`
+#[account]
+pub struct ClaimableNFT {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub relayer: Pubkey, // Payer that submitted the message; receives the rent back on claim
+    pub token_id: [u8; 32],
+    pub source_chain_id: u64,
+    pub delivered_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Incoming NFT held in escrow until its challenge period ends
+#[account]
+pub struct PendingDelivery {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub relayer: Pubkey, // Payer that submitted the message; receives the rent back
+    pub source_chain_id: u64,
+    pub zeta_tx_hash: [u8; 32],
+    pub payload_hash: [u8; 32],
+    pub delivered_at: i64,
+    pub available_at: i64, // First timestamp the NFT can be released to the recipient
+    pub disputed: bool, // Set by the watcher; blocks release until cleared by the authority
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Fraud history of a relayer that submits incoming messages
+#[account]
+pub struct RelayerRecord {
+    pub relayer: Pubkey,
+    pub fraud_count: u32, // Accepted fraud proofs against deliveries this relayer submitted
+    pub flagged: bool,
+    pub last_fraud_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Sliding-window limits on bridge traffic in each direction
+#[account]
+pub struct RateLimiter {
+    pub max_transfers: u64, // Transfers allowed per window (0 = unlimited)
+    pub max_value: u64, // Destination gas lamports bridged per window (0 = unlimited)
+    pub window: i64, // Window length in seconds
+    pub outbound: RateWindow,
+    pub inbound: RateWindow,
+    pub max_owner_transfers_per_day: u64, // Outbound transfers per wallet per day (0 = unlimited)
+    pub updated_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Per-wallet outbound transfer count for the current day
+#[account]
+pub struct OwnerVelocity {
+    pub owner: Pubkey,
+    pub day: i64, // Day index (unix time / 86400) the count applies to
+    pub transfers: u64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Merkle root of remote-chain NFT ownership published by the gateway
+#[account]
+pub struct OwnershipRoot {
+    pub chain_id: u64,
+    pub root: [u8; 32], // Root over keccak(keccak(token_id || owner)) leaves
+    pub published_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Independent collection hosted by this deployment, with its own supply and chain routes
+#[account]
+pub struct CollectionState {
+    pub collection_id: u64,
+    pub authority: Pubkey, // Mints into and manages the collection
+    pub base_uri: String, // Prefixed to the metadata URI of every NFT minted into the collection
+    pub max_supply: u64,
+    pub total_minted: u64, // NFTs issued into the collection on Solana
+    pub allowed_chain_mask: u128, // Bit per chain_index this collection may travel to or from
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+    pub asset_backend: AssetBackend, // How NFTs of this collection are minted (layout 4)
+    pub merkle_tree: Pubkey, // Bubblegum tree compressed NFTs are minted into, default until create_collection_tree (layout 5)
+    pub price_mint: Pubkey, // SPL token mints into the collection are paid in (layout 12)
+    pub price_amount: u64, // Base units of price_mint paid per mint into the treasury; 0 = free (layout 12)
+}
+
+/// Sized Metaplex collection NFT held by the program's mint authority PDA
+#[account]
+pub struct VerifiedCollection {
+    pub mint: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
+}
+
+/// Program treasury collecting mint proceeds; lamports above rent are withdrawable by the authority
+#[account]
+pub struct Treasury {
+    pub total_collected: u64, // Lamports received from paid mints
+    pub total_withdrawn: u64,
+    pub bump: u8,
+    pub account_version: u16, // Layout version, upgraded by migrate_account
 }
 
 /// Transfer status enum
@@ -81,67 +515,1395 @@ pub enum TransferStatus {
     Failed = 3,
 }
 
+/// How strictly creator royalties are enforced on Solana. The program cannot tell a gift from a sale
+/// settled off-chain, so `Enforced` rejects every transfer that pays no royalty, gifts included
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RoyaltyPolicy {
+    None = 0, // No royalties expected
+    BestEffort = 1, // Royalties advertised in metadata, marketplaces decide
+    Enforced = 2, // Only royalty-paying paths: sell_nft, transfer_nft with a sale price, pNFT rule sets or the transfer hook
+}
+
+/// Asset program a collection's NFTs are minted with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AssetBackend {
+    SplToken = 0, // SPL mint with token metadata and a master edition
+    Core = 1, // Single mpl-core asset account, delivered by process_incoming_core_nft
+    Token2022 = 2, // Token-2022 mint with metadata-pointer and permanent-delegate extensions, minted by mint_token_2022_nft
+    Compressed = 3, // Bubblegum compressed NFT in the collection's merkle tree, delivered by process_incoming_compressed_nft
+}
+
+/// Address encoding used by a connected chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AddressFormat {
+    Evm = 0, // 20-byte address
+    Solana = 1, // 32-byte public key
+    Bitcoin = 2, // Encoded address string bytes
+}
+
+/// Outbox lane a message is queued on
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MessageLane {
+    Standard = 0,
+    Priority = 1, // Time-sensitive transfers, e.g. marketplace settlements
+}
+
+/// Per-lane outbox statistics
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct LaneStats {
+    pub enqueued: u64,
+    pub fees_collected: u64,
+}
+
+/// Traffic counted in the current and previous rate-limit windows
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct RateWindow {
+    pub window_start: i64,
+    pub count: u64,
+    pub value: u64,
+    pub previous_count: u64,
+    pub previous_value: u64,
+}
+
+/// Launch schedule: an allowlist phase, then a public phase, until the end time. All zeros = no schedule
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct MintPhases {
+    pub allowlist_start: i64, // Allowlisted wallets may mint from here
+    pub public_start: i64, // Any wallet may mint from here
+    pub end: i64, // Minting closes here (0 = open-ended)
+    pub allowlist_price: u64, // Lamports per allowlist-phase mint
+    pub public_price: u64, // Lamports per public-phase mint
+    pub allowlist_limit: u64, // Mints available in the allowlist phase (0 = unlimited)
+    pub public_limit: u64, // Mints available in the public phase (0 = unlimited)
+}
+
+/// Phase of a scheduled launch a mint falls in
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MintPhase {
+    Allowlist = 0,
+    Public = 1,
+}
+
+/// How an NFT changed owner
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferKind {
+    Local = 0, // Solana transfer between wallets
+    CrossChain = 1, // Sent to or received from another chain
+}
+
+/// One ownership change in an NFT's transfer history
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferRecord {
+    pub owner: Pubkey, // New owner, or the Solana sender for an NFT leaving for another chain
+    pub timestamp: i64,
+    pub kind: TransferKind,
+}
+
+/// One cross-chain hop of a Universal NFT, seen from Solana
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProvenanceHop {
+    pub chain_id: u64, // Chain the NFT left Solana for, or arrived from
+    pub outbound: bool,
+    pub tx_hash: [u8; 32], // ZetaChain transaction hash of the message
+    pub timestamp: i64,
+}
+
+/// secp256k1 signature from an observer over an incoming message digest
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ObserverSignature {
+    pub signature: [u8; 64],
+    pub recovery_id: u8,
+}
+
+/// Proof that a wallet is in the collection's Merkle allowlist with a per-wallet mint limit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AllowlistProof {
+    pub max_mints: u32, // Mints the wallet's leaf allows in total
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// Mint authorization signed off-chain by the program authority with ed25519
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MintCoupon {
+    pub max_quantity: u32, // Mints the coupon allows the wallet in total
+    pub expiry: i64, // Unix time after which the coupon is no longer accepted
+}
+
+/// One NFT of a batch_mint call
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BatchMintItem {
+    pub metadata_uri: String,
+    pub name: String, // Empty = the deployment's default name
+}
+
+impl RateWindow {
+    pub const LEN: usize = 8 + // window_start
+        8 + // count
+        8 + // value
+        8 + // previous_count
+        8; // previous_value
+}
+
+impl MintPhases {
+    pub const LEN: usize = 8 + // allowlist_start
+        8 + // public_start
+        8 + // end
+        8 + // allowlist_price
+        8 + // public_price
+        8 + // allowlist_limit
+        8; // public_limit
+    
+    /// Phase active at `now`, None when no schedule is set; fails outside the schedule's window
+    pub fn current(&self, now: i64) -> Result<Option<MintPhase>> {
+        if *self == MintPhases::default() {
+            return Ok(None);
+        }
+        if now < self.allowlist_start || (self.end > 0 && now >= self.end) {
+            return err!(UniversalNFTError::MintPhaseNotActive);
+        }
+        Ok(Some(if now >= self.public_start { MintPhase::Public } else { MintPhase::Allowlist }))
+    }
+    
+    /// Lamport price of a mint in `phase`
+    pub fn price(&self, phase: MintPhase) -> u64 {
+        match phase {
+            MintPhase::Allowlist => self.allowlist_price,
+            MintPhase::Public => self.public_price,
+        }
+    }
+}
+
+impl LaneStats {
+    pub const LEN: usize = 8 + // enqueued
+        8; // fees_collected
+}
+
+impl AddressFormat {
+    /// Whether `address` is a well-formed address for this format
+    pub fn is_valid_address(&self, address: &[u8]) -> bool {
+        match self {
+            AddressFormat::Evm => address.len() == 20,
+            AddressFormat::Solana => address.len() == 32,
+            AddressFormat::Bitcoin => !address.is_empty() && address.len() <= MAX_RECIPIENT_ADDRESS_LENGTH,
+        }
+    }
+}
+
 impl ProgramState {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
+        32 + // pending_authority
+        32 + // authority_program
         8 + // total_minted
         8 + // max_supply
         8 + // next_token_id
+        8 + // feature_flags
+        1 + // paused
         1 + // bump
-        8; // created_at
+        8 + // created_at
+        2 + // account_version
+        4 + MAX_NAME_LENGTH + // default_name
+        4 + MAX_SYMBOL_LENGTH; // default_symbol
+    
+    /// The given Metaplex name, or the deployment's default when it is empty
+    pub fn name_or_default<'a>(&'a self, name: &'a str) -> &'a str {
+        match (name.is_empty(), self.default_name.is_empty()) {
+            (false, _) => name,
+            (true, false) => &self.default_name,
+            (true, true) => DEFAULT_METADATA_NAME,
+        }
+    }
+    
+    /// The given Metaplex symbol, or the deployment's default when it is empty
+    pub fn symbol_or_default<'a>(&'a self, symbol: &'a str) -> &'a str {
+        match (symbol.is_empty(), self.default_symbol.is_empty()) {
+            (false, _) => symbol,
+            (true, false) => &self.default_symbol,
+            (true, true) => DEFAULT_METADATA_SYMBOL,
+        }
+    }
+    
+    /// Check whether an instruction family is enabled
+    pub fn is_feature_enabled(&self, feature: u64) -> bool {
+        self.feature_flags & feature == feature
+    }
+    
+    /// Fail with FeatureDisabled unless the instruction family is enabled
+    pub fn require_feature(&self, feature: u64) -> Result<()> {
+        if !self.is_feature_enabled(feature) {
+            return err!(UniversalNFTError::FeatureDisabled);
+        }
+        Ok(())
+    }
+    
+    /// Fail with ProgramPaused while the circuit breaker is engaged
+    pub fn require_not_paused(&self) -> Result<()> {
+        if self.paused {
+            return err!(UniversalNFTError::ProgramPaused);
+        }
+        Ok(())
+    }
+    
+    /// Fail unless an admin call was signed directly by the authority or, when a multisig program
+    /// is configured, relayed by that program (the authority PDA signs through its CPI)
+    pub fn require_admin_call(&self, instructions: &AccountInfo) -> Result<()> {
+        if self.authority_program == Pubkey::default() {
+            return Ok(());
+        }
+        let current_instruction = get_instruction_relative(0, instructions)?;
+        if current_instruction.program_id != self.authority_program {
+            return err!(UniversalNFTError::Unauthorized);
+        }
+        Ok(())
+    }
 }
 
 impl ZetaChainGatewayState {
     pub const LEN: usize = 8 + // discriminator
         20 + // gateway_address
-        4 + 13 * 8 + // supported_chains (max 13 chains)
+        32 + // gateway_authority
+        8 + // current_valid_until
+        20 + // next_gateway_address
+        32 + // next_gateway_authority
+        8 + // next_valid_from
+        16 + // registered_chain_mask
+        16 + // supported_chain_mask
+        20 * MAX_OBSERVERS + // observers
+        1 + // observer_count
+        1 + // observer_threshold
         1 + // version
+        8 + // transfer_timeout
+        8 + // message_expiry_window
+        8 + // challenge_period
         8 + // updated_at
-        1; // bump
+        1 + // bump
+        2; // account_version
+    
+    /// Whether any gateway signer has been configured
+    pub fn is_configured(&self) -> bool {
+        self.gateway_authority != Pubkey::default() || self.next_gateway_authority != Pubkey::default()
+    }
+    
+    /// Reject traffic while the gateway address is unset or the configuration has not been
+    /// touched within `GATEWAY_FRESHNESS_WINDOW`
+    pub fn require_live(&self, now: i64) -> Result<()> {
+        if !self.is_configured() || self.gateway_address == DEFAULT_GATEWAY_ADDRESS {
+            return err!(UniversalNFTError::GatewayNotConfigured);
+        }
+        if now.saturating_sub(self.updated_at) > GATEWAY_FRESHNESS_WINDOW {
+            return err!(UniversalNFTError::GatewayNotConfigured);
+        }
+        Ok(())
+    }
+    
+    /// Whether `key` is a gateway signer accepted at `now`; during a migration both the current
+    /// and next gateways are accepted within their validity windows
+    pub fn is_gateway_authority(&self, key: &Pubkey, now: i64) -> bool {
+        let current = self.gateway_authority != Pubkey::default()
+            && *key == self.gateway_authority
+            && now <= self.current_valid_until;
+        let next = self.next_gateway_authority != Pubkey::default()
+            && *key == self.next_gateway_authority
+            && now >= self.next_valid_from;
+        current || next
+    }
+    
+    /// Require at least `observer_threshold` distinct observers to have signed `message_hash`,
+    /// either in `signatures` or as `precompile_addresses` already verified by the secp256k1
+    /// program; signatures from keys outside the set are ignored
+    pub fn verify_observer_signatures(
+        &self,
+        message_hash: &[u8; 32],
+        signatures: &[ObserverSignature],
+        precompile_addresses: &[[u8; 20]],
+    ) -> Result<()> {
+        if self.observer_threshold == 0 {
+            return err!(UniversalNFTError::ObserverSetNotConfigured);
+        }
+        
+        let observers = &self.observers[..self.observer_count as usize];
+        let mut signed_mask: u16 = 0;
+        for signature in signatures {
+            let public_key = secp256k1_recover(message_hash, signature.recovery_id, &signature.signature)
+                .map_err(|_| error!(UniversalNFTError::InvalidObserverSignature))?;
+            let address = &keccak::hash(&public_key.to_bytes()).to_bytes()[32 - EVM_ADDRESS_LENGTH..];
+            if let Some(position) = observers.iter().position(|observer| observer[..] == *address) {
+                signed_mask |= 1 << position;
+            }
+        }
+        for address in precompile_addresses {
+            if let Some(position) = observers.iter().position(|observer| observer == address) {
+                signed_mask |= 1 << position;
+            }
+        }
+        
+        if signed_mask.count_ones() < self.observer_threshold as u32 {
+            return err!(UniversalNFTError::InsufficientObserverSignatures);
+        }
+        Ok(())
+    }
+    
+    /// Whether the chain at `chain_index` is registered and enabled
+    pub fn is_chain_supported(&self, chain_index: u8) -> bool {
+        (chain_index as usize) < MAX_SUPPORTED_CHAINS && self.supported_chain_mask & (1u128 << chain_index) != 0
+    }
+    
+    /// Reserve `chain_index` for a newly registered chain
+    pub fn assign_chain_index(&mut self, chain_index: u8) -> Result<()> {
+        if chain_index as usize >= MAX_SUPPORTED_CHAINS {
+            return err!(UniversalNFTError::InvalidChainIndex);
+        }
+        if self.registered_chain_mask & (1u128 << chain_index) != 0 {
+            return err!(UniversalNFTError::ChainIndexInUse);
+        }
+        self.registered_chain_mask |= 1u128 << chain_index;
+        Ok(())
+    }
+    
+    /// Free `chain_index` when its chain is removed
+    pub fn release_chain_index(&mut self, chain_index: u8) {
+        self.registered_chain_mask &= !(1u128 << chain_index);
+        self.supported_chain_mask &= !(1u128 << chain_index);
+    }
+    
+    /// Mark the chain at `chain_index` as enabled or disabled
+    pub fn set_chain_supported(&mut self, chain_index: u8, enabled: bool) {
+        if enabled {
+            self.supported_chain_mask |= 1u128 << chain_index;
+        } else {
+            self.supported_chain_mask &= !(1u128 << chain_index);
+        }
+    }
 }
 
-impl NFTMetadata {
+impl ChainConfig {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // chain_id
+        1 + // chain_index
+        4 + 32 + // name (max 32 chars)
+        1 + // address_format
+        4 + // required_confirmations
+        8 + // default_gas_limit
+        8 + // max_gas_limit
+        1 + // enabled
+        8 + // created_at
+        8 + // updated_at
+        1 + // bump
+        2; // account_version
+}
+
+impl CollectionConfig {
+    pub const LEN: usize = 8 + // discriminator
+        1 + // royalty_policy
+        8 + // emission_start
+        8 + // emission_period
+        8 + // emission_per_period
+        8 + // updated_at
+        1 + // bump
+        2 + // account_version
+        32 + // rule_set
+        1 + // allowed_uri_schemes
+        2 + // max_uri_length
+        8 + // mint_price
+        32 + // allowlist_root
+        MintPhases::LEN + // mint_phases
+        8 + // allowlist_phase_minted
+        8; // public_phase_minted
+    
+    /// Count one mint in `phase`, failing with MintPhaseSoldOut once the phase's limit is reached
+    pub fn record_phase_mint(&mut self, phase: MintPhase) -> Result<()> {
+        let (minted, limit) = match phase {
+            MintPhase::Allowlist => (&mut self.allowlist_phase_minted, self.mint_phases.allowlist_limit),
+            MintPhase::Public => (&mut self.public_phase_minted, self.mint_phases.public_limit),
+        };
+        if limit > 0 && *minted >= limit {
+            return err!(UniversalNFTError::MintPhaseSoldOut);
+        }
+        *minted += 1;
+        Ok(())
+    }
+    
+    /// Longest NFT metadata URI currently accepted, which also sizes new NFT accounts
+    pub fn max_uri_length(&self) -> usize {
+        if self.max_uri_length == 0 { MAX_METADATA_URI_LENGTH } else { self.max_uri_length as usize }
+    }
+    
+    /// Reject metadata URIs longer than the configured maximum
+    pub fn require_uri_length(&self, uri: &str) -> Result<()> {
+        if uri.len() > self.max_uri_length() {
+            return err!(UniversalNFTError::InvalidMetadataURILength);
+        }
+        Ok(())
+    }
+    
+    /// Reject metadata URIs that don't start with an allowed scheme, e.g. javascript: or data: URIs
+    pub fn require_allowed_uri(&self, uri: &str) -> Result<()> {
+        let allowed = if self.allowed_uri_schemes == 0 { URI_SCHEME_ALL } else { self.allowed_uri_schemes };
+        let permitted = URI_SCHEME_PREFIXES
+            .iter()
+            .any(|(scheme, prefix)| allowed & scheme != 0 && uri.starts_with(prefix));
+        if !permitted {
+            return err!(UniversalNFTError::DisallowedUriScheme);
+        }
+        Ok(())
+    }
+    
+    /// Maximum cumulative supply released by `now`, or None when no schedule is set
+    pub fn emission_cap(&self, now: i64) -> Option<u64> {
+        if self.emission_period == 0 {
+            return None;
+        }
+        if now < self.emission_start {
+            return Some(0);
+        }
+        let periods = ((now - self.emission_start) / self.emission_period) as u64 + 1;
+        Some(periods.saturating_mul(self.emission_per_period))
+    }
+}
+
+impl PauseConfig {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // paused_operations
+        32 + // watcher
+        8 + // updated_at
+        1 + // bump
+        2; // account_version
+    
+    /// Fail with OperationPaused if `operation` is paused
+    pub fn require_active(&self, operation: u64) -> Result<()> {
+        if self.paused_operations & operation != 0 {
+            return err!(UniversalNFTError::OperationPaused);
+        }
+        Ok(())
+    }
+}
+
+impl FrozenAsset {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // frozen_by
+        8 + // frozen_at
+        1 + // bump
+        2; // account_version
+    
+    /// Fail with AssetFrozen if the FrozenAsset PDA `account` has been created
+    pub fn require_not_frozen(account: &AccountInfo) -> Result<()> {
+        if account.lamports() > 0 && !account.data_is_empty() {
+            return err!(UniversalNFTError::AssetFrozen);
+        }
+        Ok(())
+    }
+}
+
+impl Denylist {
+    pub const LEN: usize = 8 + // discriminator
+        4 + 32 * MAX_DENYLIST_ENTRIES + // entries
+        8 + // updated_at
+        1 + // bump
+        2; // account_version
+    
+    /// keccak hash identifying `address` in the denylist
+    pub fn address_hash(address: &[u8]) -> [u8; 32] {
+        keccak::hash(address).to_bytes()
+    }
+    
+    /// Fail with RecipientDenied if `address` (raw Solana or remote-chain bytes) is denied
+    pub fn require_allowed(&self, address: &[u8]) -> Result<()> {
+        if self.entries.contains(&Self::address_hash(address)) {
+            return err!(UniversalNFTError::RecipientDenied);
+        }
+        Ok(())
+    }
+}
+
+impl AllowlistEntry {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // wallet
+        8 + // added_at
+        1 + // bump
+        2; // account_version
+}
+
+impl AllowlistMintRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // wallet
+        4 + // minted
+        1 + // bump
+        2; // account_version
+    
+    /// Count one allowlist mint, failing with AllowlistMintLimitReached past the wallet's limit
+    pub fn record(&mut self, max_mints: u32) -> Result<()> {
+        if self.minted >= max_mints {
+            return err!(UniversalNFTError::AllowlistMintLimitReached);
+        }
+        self.minted += 1;
+        Ok(())
+    }
+}
+
+impl Airdrop {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        32 + // merkle_root
+        4 + // total_count
+        4 + // claimed_count
+        8 + // created_at
+        1 + // bump
+        2; // account_version
+    
+    /// Count one claim, failing with AirdropFullyClaimed once every leaf is claimed
+    pub fn record_claim(&mut self) -> Result<()> {
+        if self.claimed_count >= self.total_count {
+            return err!(UniversalNFTError::AirdropFullyClaimed);
+        }
+        self.claimed_count += 1;
+        Ok(())
+    }
+}
+
+impl AirdropClaim {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // airdrop
+        4 + // index
+        32 + // claimer
+        32 + // mint
+        8 + // claimed_at
+        1 + // bump
+        2; // account_version
+}
+
+impl TokenApproval {
     pub const LEN: usize = 8 + // discriminator
         32 + // mint
         32 + // owner
-        4 + 200 + // metadata_uri (max 200 chars)
+        32 + // approved
+        1 + // bump
+        2; // account_version
+    
+    /// Whether `operator` may move the NFT for its current `owner`
+    pub fn allows(&self, owner: &Pubkey, operator: &Pubkey) -> bool {
+        self.owner == *owner && self.approved == *operator && *operator != Pubkey::default()
+    }
+}
+
+impl OperatorApproval {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        32 + // operator
+        1 + // approved
+        1 + // bump
+        2; // account_version
+}
+
+impl PermitNonce {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        8 + // nonce
+        1 + // bump
+        2; // account_version
+}
+
+impl WrappedNFT {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // token_id
+        32 + // wrapper
+        8 + // wrapped_at
+        1 + // bump
+        2; // account_version
+}
+
+impl TokenBalance {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // token_id
+        8 + // chain_id
+        8 + // amount
+        1 + // bump
+        2; // account_version
+    
+    pub fn credit(&mut self, amount: u64) -> Result<()> {
+        self.amount = self.amount.checked_add(amount).ok_or(error!(UniversalNFTError::SupplyOverflow))?;
+        Ok(())
+    }
+    
+    /// Remove `amount` units, failing with InvalidAmount when the chain holds fewer
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        self.amount = self.amount.checked_sub(amount).ok_or(error!(UniversalNFTError::InvalidAmount))?;
+        Ok(())
+    }
+}
+
+impl IncomingBatch {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // payload_hash
+        8 + // source_chain_id
+        32 + // zeta_tx_hash
+        2 + // total_items
+        2 + // processed_items
+        8 + // created_at
+        1 + // bump
+        2; // account_version
+    
+    pub fn is_complete(&self) -> bool {
+        self.total_items > 0 && self.processed_items >= self.total_items
+    }
+}
+
+impl TransferRecord {
+    pub const LEN: usize = 32 + // owner
+        8 + // timestamp
+        1; // kind
+}
+
+impl TransferHistory {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        4 + TransferRecord::LEN * TRANSFER_HISTORY_LEN + // entries
+        1 + // head
+        4 + // total_transfers
+        1 + // bump
+        2; // account_version
+    
+    /// Append `record`, overwriting the oldest entry once the buffer is full
+    pub fn push(&mut self, record: TransferRecord) {
+        if self.entries.len() < TRANSFER_HISTORY_LEN {
+            self.entries.push(record);
+        } else {
+            self.entries[self.head as usize] = record;
+            self.head = ((self.head as usize + 1) % TRANSFER_HISTORY_LEN) as u8;
+        }
+        self.total_transfers = self.total_transfers.saturating_add(1);
+    }
+}
+
+impl ProvenanceHop {
+    pub const LEN: usize = 8 + // chain_id
+        1 + // outbound
+        32 + // tx_hash
+        8; // timestamp
+}
+
+impl Provenance {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // token_id
+        4 + ProvenanceHop::LEN * MAX_PROVENANCE_HOPS + // hops
+        4 + // total_hops
+        1 + // bump
+        2; // account_version
+    
+    /// Append a hop, filling in the record when it was just created
+    pub fn record(&mut self, token_id: [u8; 32], bump: u8, hop: ProvenanceHop) {
+        if self.total_hops == 0 {
+            self.token_id = token_id;
+            self.bump = bump;
+            self.account_version = ACCOUNT_VERSION;
+        }
+        if self.hops.len() < MAX_PROVENANCE_HOPS {
+            self.hops.push(hop);
+        }
+        self.total_hops = self.total_hops.saturating_add(1);
+        
+        emit!(ProvenanceRecorded {
+            token_id,
+            chain_id: hop.chain_id,
+            outbound: hop.outbound,
+            tx_hash: hop.tx_hash,
+            total_hops: self.total_hops,
+            recorded_at: hop.timestamp,
+        });
+    }
+}
+
+impl ClaimableNFT {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // recipient
+        32 + // relayer
+        32 + // token_id
+        8 + // source_chain_id
+        8 + // delivered_at
+        1 + // bump
+        2; // account_version
+    
+    /// Whether the claim window has passed and the NFT may be swept to a recovery address
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.delivered_at.saturating_add(CLAIM_EXPIRY_PERIOD)
+    }
+}
+
+impl PendingDelivery {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // recipient
+        32 + // relayer
+        8 + // source_chain_id
+        32 + // zeta_tx_hash
+        32 + // payload_hash
+        8 + // delivered_at
+        8 + // available_at
+        1 + // disputed
+        1 + // bump
+        2; // account_version
+}
+
+impl RelayerRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // relayer
+        4 + // fraud_count
+        1 + // flagged
+        8 + // last_fraud_at
+        1 + // bump
+        2; // account_version
+}
+
+impl RateLimiter {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // max_transfers
+        8 + // max_value
+        8 + // window
+        RateWindow::LEN + // outbound
+        RateWindow::LEN + // inbound
+        8 + // max_owner_transfers_per_day
+        8 + // updated_at
+        1 + // bump
+        2; // account_version
+    
+    /// Count one transfer carrying `value` lamports, failing with RateLimitExceeded when the
+    /// sliding-window estimate would pass either limit
+    pub fn record(&mut self, inbound: bool, now: i64, value: u64) -> Result<()> {
+        let window = self.window;
+        let (max_transfers, max_value) = (self.max_transfers, self.max_value);
+        let traffic = if inbound { &mut self.inbound } else { &mut self.outbound };
+        
+        // Roll the window forward; anything older than the previous window no longer counts
+        let elapsed = now.saturating_sub(traffic.window_start);
+        if elapsed >= window {
+            let windows_passed = elapsed / window;
+            if windows_passed == 1 {
+                traffic.previous_count = traffic.count;
+                traffic.previous_value = traffic.value;
+            } else {
+                traffic.previous_count = 0;
+                traffic.previous_value = 0;
+            }
+            traffic.count = 0;
+            traffic.value = 0;
+            traffic.window_start += windows_passed * window;
+        }
+        
+        // Weight the previous window by how much of it still overlaps the sliding window
+        let overlap = (window - (now - traffic.window_start)) as u128;
+        let weighted = |previous: u64, current: u64| previous as u128 * overlap / window as u128 + current as u128;
+        
+        let count = traffic.count.saturating_add(1);
+        let total_value = traffic.value.saturating_add(value);
+        if max_transfers > 0 && weighted(traffic.previous_count, count) > max_transfers as u128 {
+            return err!(UniversalNFTError::RateLimitExceeded);
+        }
+        if max_value > 0 && weighted(traffic.previous_value, total_value) > max_value as u128 {
+            return err!(UniversalNFTError::RateLimitExceeded);
+        }
+        
+        traffic.count = count;
+        traffic.value = total_value;
+        Ok(())
+    }
+}
+
+impl NFTMetadata {
+    /// Size of an account created before URI lengths were configurable
+    pub const LEN: usize = Self::space(MAX_METADATA_URI_LENGTH);
+    
+    /// Size of an account holding a metadata URI of up to `uri_len` bytes
+    pub const fn space(uri_len: usize) -> usize {
+        8 + // discriminator
+        32 + // mint
+        32 + // owner
+        4 + uri_len + // metadata_uri
         8 + // zeta_chain_id
         32 + // cross_chain_data_hash
-        8 + // token_id
+        32 + // token_id
+        1 + // locked
+        1 + // owner_locked
         8 + // created_at
         8 + // updated_at
-        1; // bump
+        1 + // bump
+        2 + // account_version
+        32 + // collection
+        1 + // metadata_immutable
+        32 + // metadata_hash
+        2 + // royalty_basis_points
+        32 // royalty_recipient
+    }
+    
+    /// Royalty owed to the creator on a payment of `price`
+    pub fn royalty_for(&self, price: u64) -> u64 {
+        if self.royalty_recipient == Pubkey::default() {
+            return 0;
+        }
+        (price as u128 * self.royalty_basis_points as u128 / MAX_ROYALTY_BASIS_POINTS as u128) as u64
+    }
+    
+    /// Reject local mutations while a cross-chain operation or the owner holds the NFT
+    pub fn require_unlocked(&self) -> Result<()> {
+        if self.locked || self.owner_locked {
+            return err!(UniversalNFTError::NFTLocked);
+        }
+        Ok(())
+    }
+    
+    /// Reject metadata changes once the owner has locked the metadata
+    pub fn require_mutable(&self) -> Result<()> {
+        if self.metadata_immutable {
+            return err!(UniversalNFTError::MetadataImmutable);
+        }
+        Ok(())
+    }
 }
 
 impl NFTOrigin {
-    pub const LEN: usize = 8 + // discriminator
-        8 + // token_id
+    /// Size of an account created before URI lengths were configurable
+    pub const LEN: usize = Self::space(MAX_METADATA_URI_LENGTH);
+    
+    /// Size of an account holding an original metadata URI of up to `uri_len` bytes
+    pub const fn space(uri_len: usize) -> usize {
+        8 + // discriminator
+        32 + // token_id
         32 + // original_mint
-        4 + 200 + // original_metadata_uri (max 200 chars)
+        4 + uri_len + // original_metadata_uri
         8 + // source_chain_id
         8 + // created_at
-        1; // bump
+        1 + // bump
+        2 // account_version
+    }
 }
 
 impl CrossChainTransferState {
     pub const LEN: usize = 8 + // discriminator
         32 + // nft_mint
-        8 + // token_id
+        32 + // token_id
+        32 + // sender
         8 + // source_chain_id
         8 + // target_chain_id
         4 + 100 + // recipient (max 100 bytes)
         1 + // status
         32 + // zeta_tx_hash
+        8 + // gas_amount
+        8 + // gas_limit
         8 + // created_at
-        1; // bump
+        1 + // bump
+        2 + // account_version
+        4 + MAX_MEMO_LENGTH + // memo
+        8; // amount
+    
+    /// Units held in escrow for this transfer
+    pub fn amount(&self) -> u64 {
+        self.amount.max(1)
+    }
 }
 
 impl OwnershipVerificationState {
     pub const LEN: usize = 8 + // discriminator
         32 + // nft_mint
         4 + 100 + // zeta_owner (max 100 bytes)
+        8 + // owner_chain_id
         32 + // proof_hash
         1 + // verified
         8 + // verified_at
-        1; // bump
+        1 + // bump
+        2; // account_version
+}
+
+impl Outbox {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // next_sequence
+        LaneStats::LEN + // standard_lane
+        LaneStats::LEN + // priority_lane
+        1 + // bump
+        2; // account_version
+    
+    /// Statistics for a lane
+    pub fn lane_stats_mut(&mut self, lane: MessageLane) -> &mut LaneStats {
+        match lane {
+            MessageLane::Standard => &mut self.standard_lane,
+            MessageLane::Priority => &mut self.priority_lane,
+        }
+    }
+}
+
+impl OutboxMessage {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // sequence
+        32 + // nft_mint
+        32 + // token_id
+        32 + // sender
+        8 + // target_chain_id
+        4 + 100 + // recipient (max 100 bytes)
+        4 + 1000 + // payload (max 1000 bytes)
+        1 + // lane
+        8 + // fee_paid
+        8 + // gas_amount
+        8 + // created_at
+        1 + // bump
+        2; // account_version
+}
+
+impl NotificationRegistration {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // wallet
+        32 + // commitment
+        8 + // registered_at
+        1 + // bump
+        2; // account_version
+}
+
+impl AttestationRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // recipient
+        4 + 100 + // issuer (max 100 bytes)
+        32 + // token_id
+        8 + // source_chain_id
+        4 + 200 + // metadata_uri (max 200 chars)
+        32 + // zeta_tx_hash
+        8 + // created_at
+        1 + // bump
+        2; // account_version
+}
+
+impl IdentityBinding {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // wallet
+        20 + // remote_address
+        8 + // chain_id
+        8 + // bound_at
+        1 + // bump
+        2; // account_version
+}
+
+impl RewardsConfig {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // points_per_round_trip
+        32 + // redemption_hook
+        8 + // updated_at
+        1 + // bump
+        2; // account_version
+}
+
+impl PointsLedger {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // wallet
+        8 + // balance
+        8 + // lifetime_earned
+        8 + // round_trips
+        8 + // last_credited_at
+        1 + // bump
+        2; // account_version
+}
+
+impl OwnershipRoot {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // chain_id
+        32 + // root
+        8 + // published_at
+        1 + // bump
+        2; // account_version
+}
+
+impl CollectionState {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // collection_id
+        32 + // authority
+        4 + 200 + // base_uri (max 200 chars)
+        8 + // max_supply
+        8 + // total_minted
+        16 + // allowed_chain_mask
+        8 + // created_at
+        8 + // updated_at
+        1 + // bump
+        2 + // account_version
+        1 + // asset_backend
+        32 + // merkle_tree
+        32 + // price_mint
+        8; // price_amount
+    
+    /// Whether NFTs of this collection may travel to or from the chain at `chain_index`
+    pub fn is_chain_allowed(&self, chain_index: u8) -> bool {
+        (chain_index as usize) < MAX_SUPPORTED_CHAINS && self.allowed_chain_mask & (1u128 << chain_index) != 0
+    }
+    
+    /// Fail unless `collection` is the NFT's own collection and allows the chain at `chain_index`
+    pub fn require_route(nft_metadata: &NFTMetadata, collection: Option<&Account<CollectionState>>, chain_index: u8) -> Result<()> {
+        if collection.map(|collection| collection.key()).unwrap_or_default() != nft_metadata.collection {
+            return err!(UniversalNFTError::CollectionMismatch);
+        }
+        if let Some(collection) = collection {
+            if !collection.is_chain_allowed(chain_index) {
+                return err!(UniversalNFTError::ChainNotAllowedForCollection);
+            }
+        }
+        Ok(())
+    }
+    
+    /// Prefix the collection's base URI to an NFT's metadata URI
+    pub fn resolve_uri(&self, metadata_uri: &str) -> Result<String> {
+        let uri = format!("{}{}", self.base_uri, metadata_uri);
+        if uri.len() > MAX_METADATA_URI_LENGTH_LIMIT {
+            return err!(UniversalNFTError::InvalidMetadataURILength);
+        }
+        Ok(uri)
+    }
+    
+    /// Count one more NFT issued into the collection, enforcing its supply cap
+    pub fn record_mint(&mut self) -> Result<()> {
+        if self.total_minted >= self.max_supply {
+            return err!(UniversalNFTError::MaxSupplyExceeded);
+        }
+        self.total_minted = self.total_minted.checked_add(1).ok_or(error!(UniversalNFTError::SupplyOverflow))?;
+        Ok(())
+    }
+}
+
+impl VerifiedCollection {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        8 + // created_at
+        1 + // bump
+        2; // account_version
+}
+
+impl Treasury {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // total_collected
+        8 + // total_withdrawn
+        1 + // bump
+        2; // account_version
+}
+
+impl OwnerVelocity {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        8 + // day
+        8 + // transfers
+        1 + // bump
+        2; // account_version
+    
+    /// Count one outbound transfer, failing with VelocityLimitExceeded past the daily cap
+    pub fn record(&mut self, now: i64, max_per_day: u64) -> Result<()> {
+        let day = now / SECONDS_PER_DAY;
+        if day != self.day {
+            self.day = day;
+            self.transfers = 0;
+        }
+        if max_per_day > 0 && self.transfers >= max_per_day {
+            return err!(UniversalNFTError::VelocityLimitExceeded);
+        }
+        self.transfers += 1;
+        Ok(())
+    }
+}
+
+/// Program accounts that carry an `account_version`
+pub trait Versioned {
+    fn account_version(&self) -> u16;
+    fn set_account_version(&mut self, version: u16);
+}
+
+macro_rules! impl_versioned {
+    ($($account:ty),* $(,)?) => {
+        $(
+            impl Versioned for $account {
+                fn account_version(&self) -> u16 {
+                    self.account_version
+                }
+                
+                fn set_account_version(&mut self, version: u16) {
+                    self.account_version = version;
+                }
+            }
+        )*
+    };
+}
+
+impl_versioned!(
+    ProgramState, ZetaChainGatewayState, NFTMetadata, NFTOrigin, CrossChainTransferState,
+    OwnershipVerificationState, Outbox, OutboxMessage, NotificationRegistration, AttestationRecord,
+    IdentityBinding, RewardsConfig, PointsLedger, ChainConfig, CollectionConfig, PauseConfig,
+    FrozenAsset, Denylist, AllowlistEntry, PendingDelivery, RelayerRecord, RateLimiter,
+    OwnerVelocity, OwnershipRoot, VerifiedCollection, CollectionState, Treasury,
+    AllowlistMintRecord, Airdrop, AirdropClaim, TokenApproval, OperatorApproval,
+    PermitNonce, WrappedNFT, TokenBalance, IncomingBatch, ClaimableNFT, Provenance, TransferHistory,
+);
+
+/// NFTMetadata layout with a u64 token ID, kept for migrating existing accounts
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyNFTMetadata {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub metadata_uri: String,
+    pub zeta_chain_id: u64,
+    pub cross_chain_data_hash: [u8; 32],
+    pub token_id: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+/// NFTOrigin layout with a u64 token ID, kept for migrating existing accounts
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyNFTOrigin {
+    pub token_id: u64,
+    pub original_mint: Pubkey,
+    pub original_metadata_uri: String,
+    pub source_chain_id: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+/// CrossChainTransferState layout with a u64 token ID, kept for migrating existing accounts
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyCrossChainTransferState {
+    pub nft_mint: Pubkey,
+    pub token_id: u64,
+    pub sender: Pubkey,
+    pub source_chain_id: u64,
+    pub target_chain_id: u64,
+    pub recipient: Vec<u8>,
+    pub status: TransferStatus,
+    pub zeta_tx_hash: [u8; 32],
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+/// ZetaChainGatewayState layout before the chain masks, kept for migrating existing accounts
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyZetaChainGatewayState {
+    pub gateway_address: [u8; 20],
+    pub gateway_authority: Pubkey,
+    pub current_valid_until: i64,
+    pub next_gateway_address: [u8; 20],
+    pub next_gateway_authority: Pubkey,
+    pub next_valid_from: i64,
+    pub version: u8,
+    pub transfer_timeout: i64,
+    pub message_expiry_window: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+/// ChainConfig layout before chain indexes, kept for migrating existing accounts
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyChainConfig {
+    pub chain_id: u64,
+    pub name: String,
+    pub address_format: AddressFormat,
+    pub required_confirmations: u32,
+    pub default_gas_limit: u64,
+    pub max_gas_limit: u64,
+    pub enabled: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn rate_limiter(max_transfers: u64, window: i64) -> RateLimiter {
+        RateLimiter {
+            max_transfers,
+            max_value: 0,
+            window,
+            outbound: RateWindow::default(),
+            inbound: RateWindow::default(),
+            max_owner_transfers_per_day: 0,
+            updated_at: 0,
+            bump: 0,
+            account_version: ACCOUNT_VERSION,
+        }
+    }
+    
+    #[test]
+    fn rate_limiter_caps_the_current_window() {
+        let mut limiter = rate_limiter(2, 100);
+        assert!(limiter.record(true, 1_000, 0).is_ok());
+        assert!(limiter.record(true, 1_010, 0).is_ok());
+        assert_eq!(limiter.record(true, 1_020, 0).unwrap_err(), error!(UniversalNFTError::RateLimitExceeded));
+        
+        // Each direction has its own window
+        assert!(limiter.record(false, 1_020, 0).is_ok());
+    }
+    
+    #[test]
+    fn rate_limiter_weights_the_previous_window_after_rollover() {
+        let mut limiter = rate_limiter(2, 100);
+        limiter.record(true, 1_000, 0).unwrap();
+        limiter.record(true, 1_010, 0).unwrap();
+        
+        // Right after the rollover the previous window still counts in full
+        assert!(limiter.record(true, 1_100, 0).is_err());
+        assert_eq!(limiter.inbound.window_start, 1_100);
+        assert_eq!(limiter.inbound.previous_count, 2);
+        
+        // Halfway through, only half of it overlaps the sliding window
+        assert!(limiter.record(true, 1_150, 0).is_ok());
+        assert!(limiter.record(true, 1_150, 0).is_err());
+    }
+    
+    #[test]
+    fn rate_limiter_forgets_windows_older_than_the_previous_one() {
+        let mut limiter = rate_limiter(2, 100);
+        limiter.record(true, 1_000, 0).unwrap();
+        limiter.record(true, 1_010, 0).unwrap();
+        
+        assert!(limiter.record(true, 1_200, 0).is_ok());
+        assert_eq!(limiter.inbound.window_start, 1_200);
+        assert_eq!(limiter.inbound.previous_count, 0);
+        assert_eq!(limiter.inbound.count, 1);
+    }
+    
+    #[test]
+    fn transfer_history_overwrites_the_oldest_record_when_full() {
+        let mut history = TransferHistory {
+            mint: Pubkey::new_unique(),
+            entries: Vec::new(),
+            head: 0,
+            total_transfers: 0,
+            bump: 0,
+            account_version: ACCOUNT_VERSION,
+        };
+        let record = |timestamp: i64| TransferRecord {
+            owner: Pubkey::default(),
+            timestamp,
+            kind: TransferKind::Local,
+        };
+        
+        for timestamp in 0..TRANSFER_HISTORY_LEN as i64 {
+            history.push(record(timestamp));
+        }
+        assert_eq!(history.entries.len(), TRANSFER_HISTORY_LEN);
+        assert_eq!(history.head, 0);
+        
+        history.push(record(TRANSFER_HISTORY_LEN as i64));
+        history.push(record(TRANSFER_HISTORY_LEN as i64 + 1));
+        assert_eq!(history.entries.len(), TRANSFER_HISTORY_LEN);
+        assert_eq!(history.head, 2);
+        assert_eq!(history.entries[0].timestamp, TRANSFER_HISTORY_LEN as i64);
+        assert_eq!(history.entries[1].timestamp, TRANSFER_HISTORY_LEN as i64 + 1);
+        assert_eq!(history.entries[history.head as usize].timestamp, 2);
+        assert_eq!(history.total_transfers, TRANSFER_HISTORY_LEN as u32 + 2);
+    }
+    
+    fn observer_key(byte: u8) -> libsecp256k1::SecretKey {
+        libsecp256k1::SecretKey::parse(&[byte; 32]).unwrap()
+    }
+    
+    fn observer_address(key: &libsecp256k1::SecretKey) -> [u8; 20] {
+        let public_key = libsecp256k1::PublicKey::from_secret_key(key).serialize();
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&keccak::hash(&public_key[1..]).to_bytes()[32 - EVM_ADDRESS_LENGTH..]);
+        address
+    }
+    
+    fn observer_signature(key: &libsecp256k1::SecretKey, message_hash: &[u8; 32]) -> ObserverSignature {
+        let (signature, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(message_hash), key);
+        ObserverSignature {
+            signature: signature.serialize(),
+            recovery_id: recovery_id.serialize(),
+        }
+    }
+    
+    fn gateway_state(observers: &[[u8; 20]], observer_threshold: u8) -> ZetaChainGatewayState {
+        let mut observer_set = [[0u8; 20]; MAX_OBSERVERS];
+        observer_set[..observers.len()].copy_from_slice(observers);
+        ZetaChainGatewayState {
+            gateway_address: [0u8; 20],
+            gateway_authority: Pubkey::default(),
+            current_valid_until: 0,
+            next_gateway_address: [0u8; 20],
+            next_gateway_authority: Pubkey::default(),
+            next_valid_from: 0,
+            registered_chain_mask: 0,
+            supported_chain_mask: 0,
+            observers: observer_set,
+            observer_count: observers.len() as u8,
+            observer_threshold,
+            version: 1,
+            transfer_timeout: 0,
+            message_expiry_window: 0,
+            challenge_period: 0,
+            updated_at: 0,
+            bump: 0,
+            account_version: ACCOUNT_VERSION,
+        }
+    }
+    
+    #[test]
+    fn observer_signatures_require_a_configured_set() {
+        let state = gateway_state(&[], 0);
+        assert_eq!(
+            state.verify_observer_signatures(&[7u8; 32], &[], &[]).unwrap_err(),
+            error!(UniversalNFTError::ObserverSetNotConfigured),
+        );
+    }
+    
+    #[test]
+    fn observer_signatures_meet_the_threshold() {
+        let (first, second, third) = (observer_key(1), observer_key(2), observer_key(3));
+        let state = gateway_state(&[observer_address(&first), observer_address(&second), observer_address(&third)], 2);
+        let message_hash = [7u8; 32];
+        
+        let signatures = [observer_signature(&first, &message_hash), observer_signature(&third, &message_hash)];
+        assert!(state.verify_observer_signatures(&message_hash, &signatures, &[]).is_ok());
+        
+        let signatures = [observer_signature(&first, &message_hash)];
+        assert_eq!(
+            state.verify_observer_signatures(&message_hash, &signatures, &[]).unwrap_err(),
+            error!(UniversalNFTError::InsufficientObserverSignatures),
+        );
+    }
+    
+    #[test]
+    fn observer_signatures_count_each_signer_once() {
+        let (first, second) = (observer_key(1), observer_key(2));
+        let state = gateway_state(&[observer_address(&first), observer_address(&second)], 2);
+        let message_hash = [7u8; 32];
+        
+        let signature = observer_signature(&first, &message_hash);
+        assert_eq!(
+            state.verify_observer_signatures(&message_hash, &[signature, signature], &[]).unwrap_err(),
+            error!(UniversalNFTError::InsufficientObserverSignatures),
+        );
+    }
+    
+    #[test]
+    fn observer_signatures_count_precompile_verified_addresses() {
+        let (first, second) = (observer_key(1), observer_key(2));
+        let state = gateway_state(&[observer_address(&first), observer_address(&second)], 2);
+        let message_hash = [7u8; 32];
+        let signature = observer_signature(&first, &message_hash);
+        
+        // A signature and a precompile-verified address from distinct observers meet the threshold
+        assert!(state.verify_observer_signatures(&message_hash, &[signature], &[observer_address(&second)]).is_ok());
+        
+        // The same observer through both paths still counts once
+        assert_eq!(
+            state.verify_observer_signatures(&message_hash, &[signature], &[observer_address(&first)]).unwrap_err(),
+            error!(UniversalNFTError::InsufficientObserverSignatures),
+        );
+    }
+    
+    #[test]
+    fn observer_signatures_ignore_keys_outside_the_set() {
+        let (first, outsider) = (observer_key(1), observer_key(9));
+        let state = gateway_state(&[observer_address(&first), observer_address(&observer_key(2))], 2);
+        let message_hash = [7u8; 32];
+        
+        let signatures = [observer_signature(&first, &message_hash), observer_signature(&outsider, &message_hash)];
+        assert_eq!(
+            state.verify_observer_signatures(&message_hash, &signatures, &[]).unwrap_err(),
+            error!(UniversalNFTError::InsufficientObserverSignatures),
+        );
+    }
 }
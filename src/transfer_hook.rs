@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        transfer_hook::{self, TransferHook, TransferHookAccount},
+        BaseStateWithExtensions,
+        StateWithExtensions,
+    },
+    state::{Account as Token2022Account, Mint as Token2022Mint},
+};
+use anchor_spl::token_interface::{self, TransferChecked};
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::{
+    errors::UniversalNFTError,
+    constants::*,
+    seeds::*,
+};
+
+// Transfer hook
+//
+// Token-2022 NFTs minted by this program name it as their transfer hook, so wallet and marketplace
+// transfers keep NFTMetadata.owner current. The runtime does not allow a program to be re-entered
+// through another program, so the program's own transfers switch the hook off around the CPI and
+// record the new owner themselves.
+
+/// Number of extra accounts the hook resolves: the NFT metadata PDA
+pub const EXTRA_ACCOUNT_METAS_LEN: usize = 1;
+
+/// Extra accounts passed to `transfer_hook`, after source, mint, destination, owner and this list
+pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    Ok(vec![
+        // NFT metadata PDA of the mint (account index 1), written by the hook
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: NFT_METADATA_SEED.to_vec() },
+                Seed::AccountKey { index: 1 },
+            ],
+            false,
+            true,
+        )?,
+    ])
+}
+
+/// Write the extra account metas into a freshly created validation account
+pub fn write_extra_account_metas(extra_account_meta_list: &AccountInfo) -> Result<()> {
+    let mut data = extra_account_meta_list.try_borrow_mut_data()?;
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &extra_account_metas()?)?;
+    Ok(())
+}
+
+/// Whether `mint` is a Token-2022 mint whose transfer hook is this program
+pub fn uses_own_hook(mint: &AccountInfo) -> Result<bool> {
+    if mint.owner != &spl_token_2022::ID {
+        return Ok(false);
+    }
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<Token2022Mint>::unpack(&data)?;
+    Ok(match state.get_extension::<TransferHook>() {
+        Ok(hook) => Option::<Pubkey>::from(hook.program_id) == Some(crate::ID),
+        Err(_) => false,
+    })
+}
+
+/// Whether Token-2022 is in the middle of moving tokens out of `token_account`
+pub fn is_transferring(token_account: &AccountInfo) -> Result<bool> {
+    let data = token_account.try_borrow_data()?;
+    let state = StateWithExtensions::<Token2022Account>::unpack(&data)?;
+    Ok(match state.get_extension::<TransferHookAccount>() {
+        Ok(extension) => bool::from(extension.transferring),
+        Err(_) => false,
+    })
+}
+
+/// Move one NFT with `transfer_checked`, pausing this program's hook when the mint uses it.
+/// `mint_authority` is the hook authority PDA and is only required for hooked mints
+pub fn transfer_nft<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    mint_authority: Option<AccountInfo<'info>>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    transfer_amount(token_program, from, mint, to, authority, mint_authority, signer_seeds, 1)
+}
+
+/// Move `amount` units of a semi-fungible token, like `transfer_nft`
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_amount<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    mint_authority: Option<AccountInfo<'info>>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    let hooked = uses_own_hook(mint)?;
+    if hooked {
+        set_hook_program(token_program, mint, mint_authority.as_ref(), None)?;
+    }
+    
+    let cpi_accounts = TransferChecked {
+        from: from.clone(),
+        mint: mint.clone(),
+        to: to.clone(),
+        authority: authority.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer_seeds);
+    token_interface::transfer_checked(cpi_ctx, amount, SOLANA_DECIMALS)?;
+    
+    if hooked {
+        set_hook_program(token_program, mint, mint_authority.as_ref(), Some(crate::ID))?;
+    }
+    Ok(())
+}
+
+fn set_hook_program<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    mint_authority: Option<&AccountInfo<'info>>,
+    program_id: Option<Pubkey>,
+) -> Result<()> {
+    let mint_authority = mint_authority.ok_or(error!(UniversalNFTError::MissingHookAuthority))?;
+    let (expected_authority, bump) = find_mint_authority();
+    if mint_authority.key() != expected_authority {
+        return err!(UniversalNFTError::MissingHookAuthority);
+    }
+    
+    let update_ix = transfer_hook::instruction::update(
+        &token_program.key(),
+        &mint.key(),
+        &expected_authority,
+        &[],
+        program_id,
+    )?;
+    solana_program::program::invoke_signed(
+        &update_ix,
+        &[mint.clone(), mint_authority.clone()],
+        &[&[MINT_AUTHORITY_SEED, &[bump]]],
+    )?;
+    Ok(())
+}
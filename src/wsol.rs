@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, spl_token, CloseAccount, SyncNative, TokenAccount};
+
+use crate::errors::UniversalNFTError;
+
+// Wrapped SOL helpers
+//
+// Token-denominated gateway deposits and fees are held in program-owned wSOL accounts. Lamports
+// sent to a wSOL account are only counted as tokens after `sync_native`, and closing the account
+// is the only way to recover both the wrapped balance and its rent, so every drain closes it.
+
+/// Ensure a token account holds wrapped SOL
+pub fn require_native(wsol_account: &Account<TokenAccount>) -> Result<()> {
+    if wsol_account.mint != spl_token::native_mint::ID {
+        return err!(UniversalNFTError::InvalidWrappedSolAccount);
+    }
+    Ok(())
+}
+
+/// Move `amount` lamports from a system account into a wSOL account and sync its token balance
+pub fn wrap_sol<'info>(
+    from: &AccountInfo<'info>,
+    wsol_account: &Account<'info, TokenAccount>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    require_native(wsol_account)?;
+    
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            system_program.clone(),
+            anchor_lang::system_program::Transfer {
+                from: from.clone(),
+                to: wsol_account.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    token::sync_native(CpiContext::new(
+        token_program.clone(),
+        SyncNative {
+            account: wsol_account.to_account_info(),
+        },
+    ))
+}
+
+/// Close a program-owned wSOL account, sending its wrapped balance and rent to `destination`
+pub fn unwrap_sol<'info>(
+    wsol_account: &Account<'info, TokenAccount>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    require_native(wsol_account)?;
+    
+    token::close_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        CloseAccount {
+            account: wsol_account.to_account_info(),
+            destination: destination.clone(),
+            authority: authority.clone(),
+        },
+        signer_seeds,
+    ))
+}
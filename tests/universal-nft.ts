@@ -7,6 +7,12 @@ import {
   PublicKey,
   SystemProgram,
   SYSVAR_RENT_PUBKEY,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Secp256k1Program,
+  Ed25519Program,
+  ComputeBudgetProgram,
+  Transaction,
+  TransactionInstruction,
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
@@ -16,7 +22,13 @@ import {
   createInitializeMintInstruction,
   createMintToInstruction,
   createAssociatedTokenAccountInstruction,
+  getAccount,
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
 } from "@solana/spl-token";
+import { secp256k1 } from "@noble/curves/secp256k1";
+import { keccak_256 } from "@noble/hashes/sha3";
 import { assert } from "chai";
 
 describe("zetachain-universal-nft", () => {
@@ -29,6 +41,7 @@ describe("zetachain-universal-nft", () => {
   const authority = Keypair.generate();
   const user1 = Keypair.generate();
   const user2 = Keypair.generate();
+  const observerKeys = [secp256k1.utils.randomPrivateKey(), secp256k1.utils.randomPrivateKey(), secp256k1.utils.randomPrivateKey()];
   const mint1 = Keypair.generate();
   const mint2 = Keypair.generate();
   
@@ -39,6 +52,13 @@ describe("zetachain-universal-nft", () => {
   let nftMetadata2Pda: PublicKey;
   let crossChainTransferPda: PublicKey;
   let ownershipVerificationPda: PublicKey;
+  let escrowAuthorityPda: PublicKey;
+  let outboxPda: PublicKey;
+  let collectionConfigPda: PublicKey;
+  let pauseConfigPda: PublicKey;
+  let rateLimiterPda: PublicKey;
+  let denylistPda: PublicKey;
+  let incomingPayloadBytes: Buffer;
   
   // Token accounts
   let user1TokenAccount: PublicKey;
@@ -48,10 +68,305 @@ describe("zetachain-universal-nft", () => {
   const testMetadataUri = "https://example.com/metadata.json";
   const testMaxSupply = 1000;
   const testZetaChainId = 2; // Ethereum
-  const testCrossChainData = new Uint8Array([1, 2, 3, 4, 5]);
+  const FEATURE_MARKETPLACE = 1 << 0; // Opens sell_nft, transfer_from and permit_transfer
+  const FEATURE_PUBLIC_MINT = 1 << 2; // Lets wallets other than the authority call mint_nft
+  const PAUSE_MINT = 1 << 0; // Halts mint_nft, batch_mint, claim_airdrop and wrap_external_nft
+  const testPayload = {
+    v1: {
+      tokenId: new Array(32).fill(0),
+      uri: testMetadataUri,
+      recipient: Buffer.alloc(0),
+      sender: Buffer.alloc(0),
+      royaltyBasisPoints: 0,
+      data: Buffer.from([1, 2, 3, 4, 5]),
+    },
+  };
   const testRecipient = new Uint8Array([0x74, 0x2d, 0x3b, 0x1a, 0x4c, 0x5e, 0x6f, 0x7a, 0x8b, 0x9c, 0xad, 0xbe, 0xcf, 0xd0, 0xe1, 0xf2, 0x34, 0x56, 0x78, 0x9a]);
   const testGatewayAddress = new Uint8Array([0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x12, 0x34, 0x56, 0x78]);
 
+  const chainConfigPda = (chainId: number): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("chain_config"), new anchor.BN(chainId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+  const metaplexMetadataPda = (mint: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      TOKEN_METADATA_PROGRAM_ID
+    )[0];
+
+  const masterEditionPda = (mint: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer(), Buffer.from("edition")],
+      TOKEN_METADATA_PROGRAM_ID
+    )[0];
+
+  // Metaplex update authority of program-minted NFTs
+  const mintAuthorityPda = PublicKey.findProgramAddressSync(
+    [Buffer.from("mint_authority")],
+    program.programId
+  )[0];
+
+  // Delegate of owners' token accounts, signing transfers made through approvals and permits
+  const transferAuthorityPda = PublicKey.findProgramAddressSync(
+    [Buffer.from("transfer_authority")],
+    program.programId
+  )[0];
+
+  const tokenApprovalPda = (mint: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("token_approval"), mint.toBuffer()],
+      program.programId
+    )[0];
+
+  const operatorApprovalPda = (owner: PublicKey, operator: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("operator_approval"), owner.toBuffer(), operator.toBuffer()],
+      program.programId
+    )[0];
+
+  const transferHistoryPda = (mint: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("transfer_history"), mint.toBuffer()],
+      program.programId
+    )[0];
+
+  const crossChainTransferStatePda = (mint: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("cross_chain_transfer"), mint.toBuffer()],
+      program.programId
+    )[0];
+
+  // Token ID of a Solana-minted NFT: keccak(mint || uint256(slot) || uint256(counter))
+  const derivedTokenId = (mint: PublicKey, slot: number, counter: anchor.BN): Buffer =>
+    Buffer.from(keccak_256(Buffer.concat([
+      mint.toBuffer(),
+      new anchor.BN(slot).toArrayLike(Buffer, "be", 32),
+      counter.toArrayLike(Buffer, "be", 32),
+    ])));
+
+  const nftOriginPda = (tokenId: Buffer | number[]): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_origin"), Buffer.from(tokenId)],
+      program.programId
+    )[0];
+
+  const observerAddress = (privateKey: Uint8Array): number[] =>
+    Array.from(keccak_256(secp256k1.getPublicKey(privateKey, false).slice(1)).slice(12));
+
+  // Digest checked by the program: keccak(domain || program_id || source_chain_id || zeta_tx_hash || payload_hash)
+  const incomingMessageDigest = (sourceChainId: number, zetaTxHash: Uint8Array, payloadBytes: Buffer): Uint8Array =>
+    keccak_256(Buffer.concat([
+      Buffer.from("universal-nft:incoming-message:v1"),
+      program.programId.toBuffer(),
+      new anchor.BN(sourceChainId).toArrayLike(Buffer, "le", 8),
+      Buffer.from(zetaTxHash),
+      Buffer.from(keccak_256(payloadBytes)),
+    ]));
+
+  const signDigest = (privateKey: Uint8Array, digest: Uint8Array) => {
+    const signature = secp256k1.sign(digest, privateKey);
+    return { signature: Array.from(signature.toCompactRawBytes()), recoveryId: signature.recovery };
+  };
+
+  // Mints a fresh NFT to `owner` through the public mint path; the first creator receives royalties
+  const mintTestNft = async (owner: Keypair, sellerFeeBasisPoints = 0, creators: { address: PublicKey; share: number }[] = []) => {
+    const mint = Keypair.generate();
+    const [nftMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_metadata"), mint.publicKey.toBuffer()],
+      program.programId
+    );
+    const tokenAccount = await getAssociatedTokenAddress(
+      mint.publicKey,
+      owner.publicKey,
+      false,
+      TOKEN_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    
+    await program.methods
+      .mintNft(
+        testMetadataUri,
+        new anchor.BN(testZetaChainId),
+        testPayload,
+        new anchor.BN(await provider.connection.getSlot()),
+        "", // Collection default name
+        "", // Collection default symbol
+        sellerFeeBasisPoints,
+        creators,
+        null, // No allowlist proof
+        null // No mint coupon
+      )
+      .accounts({
+        programState: programStatePda,
+        pauseConfig: pauseConfigPda,
+        gatewayState: gatewayStatePda,
+        chainConfig: chainConfigPda(testZetaChainId),
+        collectionConfig: collectionConfigPda,
+        mint: mint.publicKey,
+        mintAta: tokenAccount,
+        nftMetadata,
+        metadata: metaplexMetadataPda(mint.publicKey),
+        masterEdition: masterEditionPda(mint.publicKey),
+        mintAuthority: mintAuthorityPda,
+        payer: owner.publicKey,
+        minter: owner.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([owner, mint])
+      .rpc();
+    
+    return { mint: mint.publicKey, nftMetadata, tokenAccount };
+  };
+
+  // Creates a one-of-one Metaplex NFT outside the program, held by `owner`
+  const createExternalNft = async (owner: Keypair, uri: string) => {
+    const mint = await createMint(provider.connection, owner, owner.publicKey, null, 0);
+    const tokenAccount = await getOrCreateAssociatedTokenAccount(provider.connection, owner, mint, owner.publicKey);
+    await mintTo(provider.connection, owner, mint, tokenAccount.address, owner, 1);
+    
+    // CreateMetadataAccountV3: DataV2 without creators, collection or uses; mutable; no collection details
+    const borshString = (value: string) => {
+      const length = Buffer.alloc(4);
+      length.writeUInt32LE(Buffer.byteLength(value));
+      return Buffer.concat([length, Buffer.from(value)]);
+    };
+    const sellerFeeBasisPoints = Buffer.alloc(2);
+    sellerFeeBasisPoints.writeUInt16LE(250);
+    const createMetadataIx = new TransactionInstruction({
+      programId: TOKEN_METADATA_PROGRAM_ID,
+      keys: [
+        { pubkey: metaplexMetadataPda(mint), isSigner: false, isWritable: true },
+        { pubkey: mint, isSigner: false, isWritable: false },
+        { pubkey: owner.publicKey, isSigner: true, isWritable: false },
+        { pubkey: owner.publicKey, isSigner: true, isWritable: true },
+        { pubkey: owner.publicKey, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([
+        Buffer.from([33]),
+        borshString("External NFT"),
+        borshString("EXT"),
+        borshString(uri),
+        sellerFeeBasisPoints,
+        Buffer.from([0, 0, 0, 1, 0]),
+      ]),
+    });
+    await provider.sendAndConfirm(new Transaction().add(createMetadataIx), [owner]);
+    
+    return { mint, tokenAccount: tokenAccount.address };
+  };
+
+  // Creates an external NFT for `owner` and wraps it with a universal token ID
+  const wrapTestNft = async (owner: Keypair, uri: string) => {
+    const { mint, tokenAccount } = await createExternalNft(owner, uri);
+    const programState = await program.account.programState.fetch(programStatePda);
+    const tokenIdSlot = await provider.connection.getSlot();
+    const tokenId = derivedTokenId(mint, tokenIdSlot, programState.nextTokenId);
+    const [nftMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_metadata"), mint.toBuffer()],
+      program.programId
+    );
+    
+    const tx = await program.methods
+      .wrapExternalNft(new anchor.BN(tokenIdSlot))
+      .accounts({
+        programState: programStatePda,
+        pauseConfig: pauseConfigPda,
+        collectionConfig: collectionConfigPda,
+        nftMint: mint,
+        metadata: metaplexMetadataPda(mint),
+        holderTokenAccount: tokenAccount,
+        nftMetadata,
+        nftOrigin: nftOriginPda(tokenId),
+        wrappedNft: PublicKey.findProgramAddressSync(
+          [Buffer.from("wrapped_nft"), mint.toBuffer()],
+          program.programId
+        )[0],
+        payer: owner.publicKey,
+        holder: owner.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      })
+      .signers([owner])
+      .rpc();
+    
+    return { mint, nftMetadata, tokenAccount, tokenId, tx };
+  };
+
+  // Escrows one of `owner`'s NFTs and queues it for `targetChainId`
+  const sendCrossChain = async (
+    owner: Keypair,
+    { mint, nftMetadata, tokenAccount }: { mint: PublicKey; nftMetadata: PublicKey; tokenAccount: PublicKey },
+    targetChainId: number
+  ) => {
+    const { tokenId } = await program.account.nftMetadata.fetch(nftMetadata);
+    const outbox = await program.account.outbox.fetch(outboxPda);
+    return program.methods
+      .crossChainTransfer(
+        new anchor.BN(targetChainId),
+        testRecipient,
+        { standard: {} },
+        new anchor.BN(0),
+        new anchor.BN(0), // Chain default gas limit
+        null, // No memo
+        new anchor.BN(1)
+      )
+      .accounts({
+        programState: programStatePda,
+        pauseConfig: pauseConfigPda,
+        denylist: denylistPda,
+        rateLimiter: rateLimiterPda,
+        gatewayState: gatewayStatePda,
+        chainConfig: chainConfigPda(targetChainId),
+        nftMetadata,
+        nftOrigin: nftOriginPda(tokenId),
+        nftMint: mint,
+        ownerTokenAccount: tokenAccount,
+        frozenAsset: PublicKey.findProgramAddressSync(
+          [Buffer.from("frozen_asset"), mint.toBuffer()],
+          program.programId
+        )[0],
+        escrowAuthority: escrowAuthorityPda,
+        escrowTokenAccount: await getAssociatedTokenAddress(
+          mint,
+          escrowAuthorityPda,
+          true,
+          TOKEN_PROGRAM_ID,
+          ASSOCIATED_TOKEN_PROGRAM_ID
+        ),
+        transferState: crossChainTransferStatePda(mint),
+        outbox: outboxPda,
+        outboxMessage: PublicKey.findProgramAddressSync(
+          [Buffer.from("outbox_message"), outbox.nextSequence.toArrayLike(Buffer, "le", 8)],
+          program.programId
+        )[0],
+        ownerVelocity: PublicKey.findProgramAddressSync(
+          [Buffer.from("owner_velocity"), owner.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        transferHistory: transferHistoryPda(mint),
+        collectionState: null,
+        payer: owner.publicKey,
+        owner: owner.publicKey,
+        mintAuthority: null,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([owner])
+      .rpc();
+  };
+
   before(async () => {
     // Airdrop SOL to test accounts
     const signature1 = await provider.connection.requestAirdrop(authority.publicKey, 10 * LAMPORTS_PER_SOL);
@@ -90,7 +405,37 @@ describe("zetachain-universal-nft", () => {
     );
     
     [ownershipVerificationPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("ownership_verification"), mint1.publicKey.toBuffer()],
+      [Buffer.from("ownership_verification"), mint2.publicKey.toBuffer()],
+      program.programId
+    );
+    
+    [escrowAuthorityPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow_authority")],
+      program.programId
+    );
+    
+    [outboxPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("outbox")],
+      program.programId
+    );
+    
+    [collectionConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config")],
+      program.programId
+    );
+    
+    [pauseConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pause_config")],
+      program.programId
+    );
+    
+    [rateLimiterPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("rate_limiter")],
+      program.programId
+    );
+    
+    [denylistPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("denylist")],
       program.programId
     );
     
@@ -118,7 +463,12 @@ describe("zetachain-universal-nft", () => {
         .initialize(testMetadataUri, new anchor.BN(testMaxSupply))
         .accounts({
           programState: programStatePda,
+          pauseConfig: pauseConfigPda,
+          denylist: denylistPda,
+          rateLimiter: rateLimiterPda,
           gatewayState: gatewayStatePda,
+          outbox: outboxPda,
+          collectionConfig: collectionConfigPda,
           authority: authority.publicKey,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
@@ -137,7 +487,6 @@ describe("zetachain-universal-nft", () => {
       // Verify gateway state
       const gatewayState = await program.account.zetaChainGatewayState.fetch(gatewayStatePda);
       assert.equal(gatewayState.version, 1);
-      assert.isTrue(gatewayState.supportedChains.length > 0);
       
     } catch (error) {
       console.error("Error initializing program:", error);
@@ -145,25 +494,130 @@ describe("zetachain-universal-nft", () => {
     }
   });
 
+  it("Registers connected chains", async () => {
+    try {
+      const chains = [
+        { chainId: 1, chainIndex: 0, name: "Solana", addressFormat: { solana: {} } },
+        { chainId: 2, chainIndex: 1, name: "Ethereum", addressFormat: { evm: {} } },
+        { chainId: 3, chainIndex: 2, name: "BSC", addressFormat: { evm: {} } },
+      ];
+      
+      for (const chain of chains) {
+        await program.methods
+          .registerChain(
+            new anchor.BN(chain.chainId),
+            chain.chainIndex,
+            chain.name,
+            chain.addressFormat,
+            12,
+            new anchor.BN(500000),
+            new anchor.BN(5000000)
+          )
+          .accounts({
+            programState: programStatePda,
+            gatewayState: gatewayStatePda,
+            chainConfig: chainConfigPda(chain.chainId),
+            authority: authority.publicKey,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc();
+      }
+      
+      // Verify chain configuration
+      const chainConfig = await program.account.chainConfig.fetch(chainConfigPda(2));
+      assert.equal(chainConfig.name, "Ethereum");
+      assert.equal(chainConfig.requiredConfirmations, 12);
+      assert.isTrue(chainConfig.enabled);
+      assert.equal(chainConfig.chainIndex, 1);
+      
+      const gatewayState = await program.account.zetaChainGatewayState.fetch(gatewayStatePda);
+      assert.equal(gatewayState.supportedChainMask.toString(), "7"); // Bits 0, 1 and 2
+      
+    } catch (error) {
+      console.error("Error registering chains:", error);
+      throw error;
+    }
+  });
+
+  it("Configures the observer set", async () => {
+    try {
+      await program.methods
+        .setObservers(observerKeys.map(observerAddress), 2)
+        .accounts({
+          programState: programStatePda,
+          gatewayState: gatewayStatePda,
+          authority: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .signers([authority])
+        .rpc();
+      
+      const gatewayState = await program.account.zetaChainGatewayState.fetch(gatewayStatePda);
+      assert.equal(gatewayState.observerCount, 3);
+      assert.equal(gatewayState.observerThreshold, 2);
+      
+    } catch (error) {
+      console.error("Error configuring observers:", error);
+      throw error;
+    }
+  });
+
+  it("Enables public minting and the marketplace", async () => {
+    try {
+      await program.methods
+        .setFeatureFlags(new anchor.BN(FEATURE_PUBLIC_MINT | FEATURE_MARKETPLACE))
+        .accounts({
+          programState: programStatePda,
+          authority: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .signers([authority])
+        .rpc();
+      
+      const programState = await program.account.programState.fetch(programStatePda);
+      assert.equal(programState.featureFlags.toNumber(), FEATURE_PUBLIC_MINT | FEATURE_MARKETPLACE);
+      
+    } catch (error) {
+      console.error("Error enabling features:", error);
+      throw error;
+    }
+  });
+
   it("Mints a new NFT", async () => {
     try {
       const tx = await program.methods
         .mintNft(
           testMetadataUri,
           new anchor.BN(testZetaChainId),
-          testCrossChainData
+          testPayload,
+          new anchor.BN(await provider.connection.getSlot()),
+          "Universal NFT #1",
+          "UNFT",
+          500, // 5% seller fee
+          [{ address: user1.publicKey, share: 100 }],
+          null, // No allowlist proof
+          null // No mint coupon
         )
         .accounts({
           programState: programStatePda,
+          pauseConfig: pauseConfigPda,
           gatewayState: gatewayStatePda,
+          chainConfig: chainConfigPda(testZetaChainId),
+          collectionConfig: collectionConfigPda,
           mint: mint1.publicKey,
           mintAta: user1TokenAccount,
           nftMetadata: nftMetadata1Pda,
+          metadata: metaplexMetadataPda(mint1.publicKey),
+          masterEdition: masterEditionPda(mint1.publicKey),
+          mintAuthority: mintAuthorityPda,
           payer: user1.publicKey,
-          mintAuthority: user1.publicKey,
+          minter: user1.publicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
           rent: SYSVAR_RENT_PUBKEY,
         })
         .signers([user1, mint1])
@@ -193,12 +647,16 @@ describe("zetachain-universal-nft", () => {
       const newMetadataUri = "https://example.com/new-metadata.json";
       
       const tx = await program.methods
-        .updateMetadata(newMetadataUri)
+        .updateMetadata(newMetadataUri, Array(32).fill(0))
         .accounts({
+          collectionConfig: collectionConfigPda,
           nftMetadata: nftMetadata1Pda,
           nftMint: mint1.publicKey,
+          metadata: metaplexMetadataPda(mint1.publicKey),
+          updateAuthority: mintAuthorityPda,
           owner: user1.publicKey,
           systemProgram: SystemProgram.programId,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
         })
         .signers([user1])
         .rpc();
@@ -215,26 +673,94 @@ describe("zetachain-universal-nft", () => {
     }
   });
 
+  it("Updates gateway configuration", async () => {
+    try {
+      const newVersion = 2;
+      
+      const tx = await program.methods
+        .setupGateway(
+          testGatewayAddress,
+          authority.publicKey,
+          newVersion
+        )
+        .accounts({
+          programState: programStatePda,
+          gatewayState: gatewayStatePda,
+          authority: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      
+      console.log("Gateway configuration updated. Transaction signature:", tx);
+      
+      // Verify gateway state
+      const gatewayState = await program.account.zetaChainGatewayState.fetch(gatewayStatePda);
+      assert.deepEqual(gatewayState.gatewayAddress, Array.from(testGatewayAddress));
+      assert.equal(gatewayState.gatewayAuthority.toString(), authority.publicKey.toString());
+      assert.equal(gatewayState.version, newVersion);
+      
+    } catch (error) {
+      console.error("Error updating gateway configuration:", error);
+      throw error;
+    }
+  });
+
   it("Initiates cross-chain transfer", async () => {
     try {
       const targetChainId = 3; // BSC
+      const escrowTokenAccount = await getAssociatedTokenAddress(
+        mint1.publicKey,
+        escrowAuthorityPda,
+        true,
+        TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+      const outbox = await program.account.outbox.fetch(outboxPda);
+      const [outboxMessagePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("outbox_message"), outbox.nextSequence.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
       
       const tx = await program.methods
         .crossChainTransfer(
           new anchor.BN(targetChainId),
           testRecipient,
-          testCrossChainData
+          { standard: {} },
+          new anchor.BN(0),
+          new anchor.BN(0), // Chain default gas limit
+          null, // No memo
+          new anchor.BN(1)
         )
         .accounts({
           programState: programStatePda,
+          pauseConfig: pauseConfigPda,
+          denylist: denylistPda,
+          rateLimiter: rateLimiterPda,
           gatewayState: gatewayStatePda,
+          chainConfig: chainConfigPda(targetChainId),
           nftMetadata: nftMetadata1Pda,
           nftMint: mint1.publicKey,
           ownerTokenAccount: user1TokenAccount,
+          frozenAsset: PublicKey.findProgramAddressSync(
+            [Buffer.from("frozen_asset"), mint1.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          escrowAuthority: escrowAuthorityPda,
+          escrowTokenAccount: escrowTokenAccount,
           transferState: crossChainTransferPda,
+          outbox: outboxPda,
+          outboxMessage: outboxMessagePda,
+          ownerVelocity: PublicKey.findProgramAddressSync(
+            [Buffer.from("owner_velocity"), user1.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          payer: user1.publicKey,
           owner: user1.publicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           rent: SYSVAR_RENT_PUBKEY,
         })
         .signers([user1])
@@ -248,8 +774,31 @@ describe("zetachain-universal-nft", () => {
       assert.equal(transferState.sourceChainId.toNumber(), 1); // Solana
       assert.equal(transferState.targetChainId.toNumber(), targetChainId);
       assert.equal(transferState.status, 1); // InProgress
+      assert.equal(transferState.sender.toString(), user1.publicKey.toString());
+      assert.equal(transferState.gasLimit.toNumber(), 500000);
+      
+      // Verify outbox message was queued
+      const outboxMessage = await program.account.outboxMessage.fetch(outboxMessagePda);
+      assert.equal(outboxMessage.sequence.toNumber(), outbox.nextSequence.toNumber());
+      assert.equal(outboxMessage.targetChainId.toNumber(), targetChainId);
+      assert.deepEqual(outboxMessage.lane, { standard: {} });
+      
+      const outboxAfter = await program.account.outbox.fetch(outboxPda);
+      assert.equal(outboxAfter.standardLane.enqueued.toNumber(), 1);
+      
+      // Verify the transfer was counted by the rate limiter
+      const rateLimiter = await program.account.rateLimiter.fetch(rateLimiterPda);
+      assert.equal(rateLimiter.outbound.count.toNumber(), 1);
+      
+      const ownerVelocity = await program.account.ownerVelocity.fetch(
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("owner_velocity"), user1.publicKey.toBuffer()],
+          program.programId
+        )[0]
+      );
+      assert.equal(ownerVelocity.transfers.toNumber(), 1);
       
-      // Verify NFT is burned (owner cleared)
+      // Verify NFT is escrowed (owner cleared)
       const nftMetadata = await program.account.nftMetadata.fetch(nftMetadata1Pda);
       assert.equal(nftMetadata.owner.toString(), "11111111111111111111111111111111");
       
@@ -263,30 +812,66 @@ describe("zetachain-universal-nft", () => {
     try {
       const sourceChainId = 2; // Ethereum
       const incomingMetadataUri = "https://example.com/incoming-metadata.json";
-      const incomingCrossChainData = new Uint8Array([5, 4, 3, 2, 1]);
+      const pendingTransfer = await program.account.crossChainTransferState.fetch(crossChainTransferPda);
+      const incomingPayload = {
+        v2: {
+          tokenId: pendingTransfer.tokenId,
+          uri: incomingMetadataUri,
+          recipient: user2.publicKey.toBuffer(),
+          sender: Buffer.from(testRecipient),
+          royaltyBasisPoints: 0,
+          data: Buffer.from([5, 4, 3, 2, 1]),
+          timestamp: new anchor.BN(Math.floor(Date.now() / 1000)),
+        },
+      };
+      incomingPayloadBytes = program.coder.types.encode("CrossChainPayload", incomingPayload);
       const zetaTxHash = new Uint8Array(32).fill(1);
       
+      // Two of the three observers attest to the message: one inline, one through the secp256k1 precompile
+      const digest = incomingMessageDigest(sourceChainId, zetaTxHash, incomingPayloadBytes);
+      const observerSignatures = [signDigest(observerKeys[0], digest)];
+      const precompileIx = Secp256k1Program.createInstructionWithPrivateKey({
+        privateKey: Buffer.from(observerKeys[1]),
+        message: Buffer.from(digest),
+      });
+      
       const tx = await program.methods
         .processIncomingNft(
-          incomingMetadataUri,
           new anchor.BN(sourceChainId),
-          incomingCrossChainData,
-          zetaTxHash
+          incomingPayload,
+          zetaTxHash,
+          observerSignatures,
+          false
         )
         .accounts({
           programState: programStatePda,
+          pauseConfig: pauseConfigPda,
+          collectionConfig: collectionConfigPda,
+          denylist: denylistPda,
+          rateLimiter: rateLimiterPda,
           gatewayState: gatewayStatePda,
+          chainConfig: chainConfigPda(sourceChainId),
           transferState: crossChainTransferPda,
+          frozenAsset: PublicKey.findProgramAddressSync(
+            [Buffer.from("frozen_asset"), mint2.publicKey.toBuffer()],
+            program.programId
+          )[0],
           incomingNftMint: mint2.publicKey,
           recipientTokenAccount: user2TokenAccount,
           nftMetadata: nftMetadata2Pda,
+          metadata: metaplexMetadataPda(mint2.publicKey),
+          masterEdition: masterEditionPda(mint2.publicKey),
+          updateAuthority: mintAuthorityPda,
           payer: user2.publicKey,
           recipient: user2.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
           rent: SYSVAR_RENT_PUBKEY,
         })
+        .preInstructions([precompileIx])
         .signers([user2, mint2])
         .rpc();
       
@@ -315,15 +900,41 @@ describe("zetachain-universal-nft", () => {
 
   it("Verifies cross-chain ownership", async () => {
     try {
-      const proofData = new Uint8Array([1, 2, 3, 4, 5]);
+      const remoteChainId = 2; // Ethereum
+      const ownershipRootPda = PublicKey.findProgramAddressSync(
+        [Buffer.from("ownership_root"), new anchor.BN(remoteChainId).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
       
-      const tx = await program.methods
-        .verifyCrossChainOwnership(proofData)
+      // Two-leaf tree: the NFT's (token ID, owner) leaf and an unrelated sibling
+      const nftMetadata = await program.account.nftMetadata.fetch(nftMetadata2Pda);
+      const ownershipLeaf = (tokenId: number[], owner: Buffer): Buffer =>
+        Buffer.from(keccak_256(keccak_256(Buffer.concat([Buffer.from(tokenId), owner]))));
+      const leaf = ownershipLeaf(nftMetadata.tokenId, Buffer.from(testRecipient));
+      const sibling = ownershipLeaf(new Array(32).fill(7), Buffer.from(testRecipient));
+      const root = Buffer.from(keccak_256(Buffer.concat(Buffer.compare(leaf, sibling) <= 0 ? [leaf, sibling] : [sibling, leaf])));
+      
+      await program.methods
+        .publishOwnershipRoot(new anchor.BN(remoteChainId), Array.from(root))
         .accounts({
           gatewayState: gatewayStatePda,
+          chainConfig: chainConfigPda(remoteChainId),
+          ownershipRoot: ownershipRootPda,
+          gatewayAuthority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      
+      const tx = await program.methods
+        .verifyCrossChainOwnership(new anchor.BN(remoteChainId), Buffer.from(testRecipient), [Array.from(sibling)])
+        .accounts({
+          chainConfig: chainConfigPda(remoteChainId),
+          ownershipRoot: ownershipRootPda,
           nftMetadata: nftMetadata2Pda,
           verificationState: ownershipVerificationPda,
           nftMint: mint2.publicKey,
+          payer: user2.publicKey,
           verifier: user2.publicKey,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
@@ -337,43 +948,21 @@ describe("zetachain-universal-nft", () => {
       const verificationState = await program.account.ownershipVerificationState.fetch(ownershipVerificationPda);
       assert.equal(verificationState.nftMint.toString(), mint2.publicKey.toString());
       assert.isTrue(verificationState.verified);
+      assert.deepEqual(Buffer.from(verificationState.zetaOwner), Buffer.from(testRecipient));
       
-    } catch (error) {
-      console.error("Error verifying cross-chain ownership:", error);
-      throw error;
-    }
-  });
-
-  it("Updates gateway configuration", async () => {
-    try {
-      const newSupportedChains = [1, 2, 3, 4, 5]; // Solana, Ethereum, BSC, Polygon, Avalanche
-      const newVersion = 2;
-      
-      const tx = await program.methods
-        .setupGateway(
-          testGatewayAddress,
-          newSupportedChains,
-          newVersion
-        )
+      // dApps read the owner through the getter
+      const crossChainOwner = await program.methods
+        .getCrossChainOwner()
         .accounts({
-          programState: programStatePda,
-          gatewayState: gatewayStatePda,
-          authority: authority.publicKey,
-          systemProgram: SystemProgram.programId,
+          verificationState: ownershipVerificationPda,
+          nftMint: mint2.publicKey,
         })
-        .signers([authority])
-        .rpc();
-      
-      console.log("Gateway configuration updated. Transaction signature:", tx);
-      
-      // Verify gateway state
-      const gatewayState = await program.account.zetaChainGatewayState.fetch(gatewayStatePda);
-      assert.deepEqual(gatewayState.gatewayAddress, Array.from(testGatewayAddress));
-      assert.deepEqual(gatewayState.supportedChains, newSupportedChains);
-      assert.equal(gatewayState.version, newVersion);
+        .view();
+      assert.equal(crossChainOwner.chainId.toNumber(), remoteChainId);
+      assert.deepEqual(Buffer.from(crossChainOwner.owner), Buffer.from(testRecipient));
       
     } catch (error) {
-      console.error("Error updating gateway configuration:", error);
+      console.error("Error verifying cross-chain ownership:", error);
       throw error;
     }
   });
@@ -408,40 +997,67 @@ describe("zetachain-universal-nft", () => {
         .mintNft(
           testMetadataUri,
           new anchor.BN(testZetaChainId),
-          testCrossChainData
+          testPayload,
+          new anchor.BN(await provider.connection.getSlot()),
+          "", // Collection default name
+          "", // Collection default symbol
+          0,
+          [],
+          null, // No allowlist proof
+          null // No mint coupon
         )
         .accounts({
           programState: programStatePda,
+          pauseConfig: pauseConfigPda,
           gatewayState: gatewayStatePda,
+          chainConfig: chainConfigPda(testZetaChainId),
+          collectionConfig: collectionConfigPda,
           mint: mint3.publicKey,
           mintAta: user1TokenAccount3,
           nftMetadata: nftMetadata3Pda,
+          metadata: metaplexMetadataPda(mint3.publicKey),
+          masterEdition: masterEditionPda(mint3.publicKey),
+          mintAuthority: mintAuthorityPda,
           payer: user1.publicKey,
-          mintAuthority: user1.publicKey,
+          minter: user1.publicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
           rent: SYSVAR_RENT_PUBKEY,
         })
         .signers([user1, mint3])
         .rpc();
       
+      const transferHistory3Pda = PublicKey.findProgramAddressSync(
+        [Buffer.from("transfer_history"), mint3.publicKey.toBuffer()],
+        program.programId
+      )[0];
+      
       // Transfer NFT from user1 to user2
       const tx = await program.methods
-        .transferNft(user2.publicKey)
+        .transferNft(user2.publicKey, null, new anchor.BN(0), new anchor.BN(1))
         .accounts({
+          programState: programStatePda,
+          pauseConfig: pauseConfigPda,
+          denylist: denylistPda,
+          collectionConfig: collectionConfigPda,
           nftMetadata: nftMetadata3Pda,
           nftMint: mint3.publicKey,
           ownerTokenAccount: user1TokenAccount3,
           newOwnerTokenAccount: user2TokenAccount3,
+          transferHistory: transferHistory3Pda,
+          payer: user1.publicKey,
           owner: user1.publicKey,
           newOwner: user2.publicKey,
+          buyer: null,
+          royaltyRecipient: null,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           rent: SYSVAR_RENT_PUBKEY,
         })
-        .signers([user1, user2])
+        .signers([user1])
         .rpc();
       
       console.log("NFT transferred successfully. Transaction signature:", tx);
@@ -450,6 +1066,12 @@ describe("zetachain-universal-nft", () => {
       const nftMetadata = await program.account.nftMetadata.fetch(nftMetadata3Pda);
       assert.equal(nftMetadata.owner.toString(), user2.publicKey.toString());
       
+      // Verify the ownership change was recorded
+      const transferHistory = await program.account.transferHistory.fetch(transferHistory3Pda);
+      assert.equal(transferHistory.totalTransfers, 1);
+      assert.equal(transferHistory.entries[0].owner.toString(), user2.publicKey.toString());
+      assert.deepEqual(transferHistory.entries[0].kind, { local: {} });
+      
     } catch (error) {
       console.error("Error transferring NFT:", error);
       throw error;
@@ -478,32 +1100,56 @@ describe("zetachain-universal-nft", () => {
         .mintNft(
           testMetadataUri,
           new anchor.BN(testZetaChainId),
-          testCrossChainData
+          testPayload,
+          new anchor.BN(await provider.connection.getSlot()),
+          "", // Collection default name
+          "", // Collection default symbol
+          0,
+          [],
+          null, // No allowlist proof
+          null // No mint coupon
         )
         .accounts({
           programState: programStatePda,
+          pauseConfig: pauseConfigPda,
           gatewayState: gatewayStatePda,
+          chainConfig: chainConfigPda(testZetaChainId),
+          collectionConfig: collectionConfigPda,
           mint: mint4.publicKey,
           mintAta: user1TokenAccount4,
           nftMetadata: nftMetadata4Pda,
+          metadata: metaplexMetadataPda(mint4.publicKey),
+          masterEdition: masterEditionPda(mint4.publicKey),
+          mintAuthority: mintAuthorityPda,
           payer: user1.publicKey,
-          mintAuthority: user1.publicKey,
+          minter: user1.publicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
           rent: SYSVAR_RENT_PUBKEY,
         })
         .signers([user1, mint4])
         .rpc();
       
+      // The origin record is keyed by the token ID assigned at mint
+      const { tokenId } = await program.account.nftMetadata.fetch(nftMetadata4Pda);
+      const [nftOrigin4Pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("nft_origin"), Buffer.from(tokenId)],
+        program.programId
+      );
+      
       // Burn NFT
       const tx = await program.methods
         .burnNft()
         .accounts({
           programState: programStatePda,
+          pauseConfig: pauseConfigPda,
           nftMetadata: nftMetadata4Pda,
+          nftOrigin: nftOrigin4Pda,
           nftMint: mint4.publicKey,
           ownerTokenAccount: user1TokenAccount4,
+          tokenBalance: null,
           owner: user1.publicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -517,9 +1163,1142 @@ describe("zetachain-universal-nft", () => {
       const programState = await program.account.programState.fetch(programStatePda);
       assert.equal(programState.totalMinted.toNumber(), 2); // Should be 2 after minting and burning
       
+      // The metadata and origin records are closed
+      assert.isNull(await provider.connection.getAccountInfo(nftMetadata4Pda));
+      assert.isNull(await provider.connection.getAccountInfo(nftOrigin4Pda));
+      
     } catch (error) {
       console.error("Error burning NFT:", error);
       throw error;
     }
   });
+
+  it("Approves an address and an operator", async () => {
+    try {
+      const { mint, nftMetadata, tokenAccount } = await mintTestNft(user1);
+      const approveAccounts = {
+        nftMetadata,
+        nftMint: mint,
+        ownerTokenAccount: tokenAccount,
+        tokenApproval: tokenApprovalPda(mint),
+        transferAuthority: transferAuthorityPda,
+        payer: user1.publicKey,
+        owner: user1.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      };
+      
+      // A single-NFT approval also delegates the token account to the program
+      await program.methods
+        .approve(user2.publicKey)
+        .accounts(approveAccounts)
+        .signers([user1])
+        .rpc();
+      
+      let tokenApproval = await program.account.tokenApproval.fetch(tokenApprovalPda(mint));
+      assert.equal(tokenApproval.approved.toString(), user2.publicKey.toString());
+      assert.equal((await getAccount(provider.connection, tokenAccount)).delegate?.toString(), transferAuthorityPda.toString());
+      
+      // An operator for all of user1's NFTs
+      await program.methods
+        .setApprovalForAll(user2.publicKey, true)
+        .accounts({
+          operatorApproval: operatorApprovalPda(user1.publicKey, user2.publicKey),
+          payer: user1.publicKey,
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+      
+      const operatorApproval = await program.account.operatorApproval.fetch(operatorApprovalPda(user1.publicKey, user2.publicKey));
+      assert.isTrue(operatorApproval.approved);
+      
+      // Clearing the single-NFT approval keeps the delegation the operator relies on
+      await program.methods
+        .approve(PublicKey.default)
+        .accounts(approveAccounts)
+        .signers([user1])
+        .rpc();
+      
+      tokenApproval = await program.account.tokenApproval.fetch(tokenApprovalPda(mint));
+      assert.equal(tokenApproval.approved.toString(), PublicKey.default.toString());
+      assert.equal((await getAccount(provider.connection, tokenAccount)).delegate?.toString(), transferAuthorityPda.toString());
+      
+      // Revoking the program delegation is explicit
+      await program.methods
+        .setProgramDelegation(false)
+        .accounts({
+          nftMetadata,
+          nftMint: mint,
+          ownerTokenAccount: tokenAccount,
+          transferAuthority: transferAuthorityPda,
+          owner: user1.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+      
+      assert.isNull((await getAccount(provider.connection, tokenAccount)).delegate);
+      
+    } catch (error) {
+      console.error("Error setting approvals:", error);
+      throw error;
+    }
+  });
+
+  it("Moves an NFT as an operator with transfer_from", async () => {
+    try {
+      const { mint, nftMetadata, tokenAccount } = await mintTestNft(user1);
+      const user2NftAccount = await getAssociatedTokenAddress(
+        mint,
+        user2.publicKey,
+        false,
+        TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+      
+      // user2 is already user1's operator; the operator approval alone does not let the program move the token
+      const transferFromAccounts = {
+        programState: programStatePda,
+        pauseConfig: pauseConfigPda,
+        denylist: denylistPda,
+        collectionConfig: collectionConfigPda,
+        nftMetadata,
+        nftMint: mint,
+        ownerTokenAccount: tokenAccount,
+        newOwnerTokenAccount: user2NftAccount,
+        owner: user1.publicKey,
+        newOwner: user2.publicKey,
+        payer: user2.publicKey,
+        operator: user2.publicKey,
+        tokenApproval: null,
+        operatorApproval: operatorApprovalPda(user1.publicKey, user2.publicKey),
+        transferHistory: transferHistoryPda(mint),
+        transferAuthority: transferAuthorityPda,
+        mintAuthority: null,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      };
+      
+      let rejected = false;
+      try {
+        await program.methods
+          .transferFrom(user2.publicKey)
+          .accounts(transferFromAccounts)
+          .signers([user2])
+          .rpc();
+      } catch (error) {
+        rejected = true;
+        assert.include(error.toString(), "NotDelegatedToProgram");
+      }
+      assert.isTrue(rejected);
+      
+      // Once user1 delegates the token account to the program, the operator can move it
+      await program.methods
+        .setProgramDelegation(true)
+        .accounts({
+          nftMetadata,
+          nftMint: mint,
+          ownerTokenAccount: tokenAccount,
+          transferAuthority: transferAuthorityPda,
+          owner: user1.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+      
+      await program.methods
+        .transferFrom(user2.publicKey)
+        .accounts(transferFromAccounts)
+        .signers([user2])
+        .rpc();
+      
+      const metadata = await program.account.nftMetadata.fetch(nftMetadata);
+      assert.equal(metadata.owner.toString(), user2.publicKey.toString());
+      assert.equal((await getAccount(provider.connection, user2NftAccount)).amount.toString(), "1");
+      
+      const transferHistory = await program.account.transferHistory.fetch(transferHistoryPda(mint));
+      assert.equal(transferHistory.totalTransfers, 1);
+      assert.equal(transferHistory.entries[0].owner.toString(), user2.publicKey.toString());
+      
+    } catch (error) {
+      console.error("Error transferring NFT as operator:", error);
+      throw error;
+    }
+  });
+
+  it("Relays an owner-signed permit transfer", async () => {
+    try {
+      const { mint, nftMetadata, tokenAccount } = await mintTestNft(user1);
+      const user2NftAccount = await getAssociatedTokenAddress(
+        mint,
+        user2.publicKey,
+        false,
+        TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+      const [permitNoncePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("permit_nonce"), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      
+      // The one on-chain step the owner takes: delegate the token account to the program
+      await program.methods
+        .setProgramDelegation(true)
+        .accounts({
+          nftMetadata,
+          nftMint: mint,
+          ownerTokenAccount: tokenAccount,
+          transferAuthority: transferAuthorityPda,
+          owner: user1.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+      
+      // user1 signs the permit off-chain: domain || program_id || mint || recipient || nonce || expiry
+      const nonce = new anchor.BN(0);
+      const expiry = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const permitMessage = Buffer.concat([
+        Buffer.from("universal-nft:permit:v1"),
+        program.programId.toBuffer(),
+        mint.toBuffer(),
+        user2.publicKey.toBuffer(),
+        nonce.toArrayLike(Buffer, "le", 8),
+        expiry.toArrayLike(Buffer, "le", 8),
+      ]);
+      const permitSignatureIx = Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: user1.secretKey,
+        message: permitMessage,
+      });
+      
+      // The authority relays it, paying the fees and the recipient's token account
+      const relayerBalanceBefore = await provider.connection.getBalance(authority.publicKey);
+      const ownerBalanceBefore = await provider.connection.getBalance(user1.publicKey);
+      await program.methods
+        .permitTransfer(user2.publicKey, nonce, expiry)
+        .accounts({
+          programState: programStatePda,
+          pauseConfig: pauseConfigPda,
+          denylist: denylistPda,
+          collectionConfig: collectionConfigPda,
+          nftMetadata,
+          nftMint: mint,
+          ownerTokenAccount: tokenAccount,
+          newOwnerTokenAccount: user2NftAccount,
+          owner: user1.publicKey,
+          newOwner: user2.publicKey,
+          relayer: authority.publicKey,
+          permitNonce: permitNoncePda,
+          transferHistory: transferHistoryPda(mint),
+          transferAuthority: transferAuthorityPda,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          mintAuthority: null,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .preInstructions([permitSignatureIx])
+        .signers([authority])
+        .rpc();
+      
+      const metadata = await program.account.nftMetadata.fetch(nftMetadata);
+      assert.equal(metadata.owner.toString(), user2.publicKey.toString());
+      assert.equal((await getAccount(provider.connection, user2NftAccount)).amount.toString(), "1");
+      assert.isBelow(await provider.connection.getBalance(authority.publicKey), relayerBalanceBefore);
+      assert.equal(await provider.connection.getBalance(user1.publicKey), ownerBalanceBefore);
+      
+      // The nonce is spent, so the same permit cannot be replayed
+      const permitNonce = await program.account.permitNonce.fetch(permitNoncePda);
+      assert.equal(permitNonce.nonce.toNumber(), 1);
+      
+    } catch (error) {
+      console.error("Error relaying permit transfer:", error);
+      throw error;
+    }
+  });
+
+  it("Sells a mint into an SPL token priced collection to a public buyer", async () => {
+    try {
+      const collectionId = new anchor.BN(1);
+      const [collectionStatePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("collection_state"), collectionId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const [treasuryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("treasury")],
+        program.programId
+      );
+      const price = 25_000_000; // 25 tokens with 6 decimals
+      
+      // The collection belongs to the authority; user2 is an ordinary buyer
+      await program.methods
+        .registerCollection(collectionId, authority.publicKey, "https://example.com/collection/", new anchor.BN(10), new anchor.BN(7), { splToken: {} })
+        .accounts({
+          programState: programStatePda,
+          collectionState: collectionStatePda,
+          authority: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      
+      // Creates the treasury without charging lamports for public mints
+      await program.methods
+        .setMintPrice(new anchor.BN(0))
+        .accounts({
+          programState: programStatePda,
+          collectionConfig: collectionConfigPda,
+          treasury: treasuryPda,
+          authority: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      
+      const paymentMint = await createMint(provider.connection, authority, authority.publicKey, null, 6);
+      const buyerPaymentAccount = await getOrCreateAssociatedTokenAccount(provider.connection, authority, paymentMint, user2.publicKey);
+      const treasuryPaymentAccount = await getOrCreateAssociatedTokenAccount(provider.connection, authority, paymentMint, treasuryPda, true);
+      await mintTo(provider.connection, authority, paymentMint, buyerPaymentAccount.address, authority, 100_000_000);
+      
+      await program.methods
+        .setCollectionPrice(paymentMint, new anchor.BN(price))
+        .accounts({
+          collectionState: collectionStatePda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      
+      const mint = Keypair.generate();
+      const [nftMetadata] = PublicKey.findProgramAddressSync(
+        [Buffer.from("nft_metadata"), mint.publicKey.toBuffer()],
+        program.programId
+      );
+      const user2NftAccount = await getAssociatedTokenAddress(
+        mint.publicKey,
+        user2.publicKey,
+        false,
+        TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+      
+      await program.methods
+        .mintNft(
+          "1.json",
+          new anchor.BN(testZetaChainId),
+          testPayload,
+          new anchor.BN(await provider.connection.getSlot()),
+          "",
+          "",
+          0,
+          [],
+          null, // No allowlist proof
+          null // No mint coupon
+        )
+        .accounts({
+          programState: programStatePda,
+          pauseConfig: pauseConfigPda,
+          gatewayState: gatewayStatePda,
+          chainConfig: chainConfigPda(testZetaChainId),
+          collectionConfig: collectionConfigPda,
+          mint: mint.publicKey,
+          mintAta: user2NftAccount,
+          nftMetadata,
+          metadata: metaplexMetadataPda(mint.publicKey),
+          masterEdition: masterEditionPda(mint.publicKey),
+          mintAuthority: mintAuthorityPda,
+          payer: user2.publicKey,
+          minter: user2.publicKey,
+          treasury: treasuryPda,
+          paymentMint,
+          payerPaymentAccount: buyerPaymentAccount.address,
+          treasuryPaymentAccount: treasuryPaymentAccount.address,
+          paymentTokenProgram: TOKEN_PROGRAM_ID,
+          collectionState: collectionStatePda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user2, mint])
+        .rpc();
+      
+      // The buyer paid the collection's price into the treasury's token account
+      assert.equal((await getAccount(provider.connection, buyerPaymentAccount.address)).amount.toString(), (100_000_000 - price).toString());
+      assert.equal((await getAccount(provider.connection, treasuryPaymentAccount.address)).amount.toString(), price.toString());
+      
+      const metadata = await program.account.nftMetadata.fetch(nftMetadata);
+      assert.equal(metadata.owner.toString(), user2.publicKey.toString());
+      assert.equal(metadata.collection.toString(), collectionStatePda.toString());
+      
+      const collectionState = await program.account.collectionState.fetch(collectionStatePda);
+      assert.equal(collectionState.totalMinted.toNumber(), 1);
+      
+    } catch (error) {
+      console.error("Error buying an SPL token priced mint:", error);
+      throw error;
+    }
+  });
+
+  it("Pays the creator's royalty on sales", async () => {
+    try {
+      // 10% royalty to the authority as sole creator
+      const { mint, nftMetadata, tokenAccount } = await mintTestNft(user1, 1000, [{ address: authority.publicKey, share: 100 }]);
+      const user2NftAccount = await getAssociatedTokenAddress(
+        mint,
+        user2.publicKey,
+        false,
+        TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+      // The provider wallet pays fees and rent, so only sale payments move these balances
+      const balances = async () => ({
+        user1: await provider.connection.getBalance(user1.publicKey),
+        user2: await provider.connection.getBalance(user2.publicKey),
+        creator: await provider.connection.getBalance(authority.publicKey),
+      });
+      
+      // user1 sells to user2 for 1 SOL
+      const price = LAMPORTS_PER_SOL;
+      let before = await balances();
+      await program.methods
+        .sellNft(new anchor.BN(price))
+        .accounts({
+          programState: programStatePda,
+          pauseConfig: pauseConfigPda,
+          denylist: denylistPda,
+          nftMetadata,
+          nftMint: mint,
+          ownerTokenAccount: tokenAccount,
+          buyerTokenAccount: user2NftAccount,
+          transferHistory: transferHistoryPda(mint),
+          owner: user1.publicKey,
+          payer: provider.wallet.publicKey,
+          buyer: user2.publicKey,
+          royaltyRecipient: authority.publicKey,
+          mintAuthority: null,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1, user2])
+        .rpc();
+      
+      let after = await balances();
+      assert.equal(before.user2 - after.user2, price);
+      assert.equal(after.user1 - before.user1, price * 0.9);
+      assert.equal(after.creator - before.creator, price * 0.1);
+      assert.equal((await program.account.nftMetadata.fetch(nftMetadata)).owner.toString(), user2.publicKey.toString());
+      
+      // user2 sells it back through transfer_nft with a sale price attached, paid by user1 as buyer
+      const salePrice = LAMPORTS_PER_SOL / 2;
+      before = await balances();
+      await program.methods
+        .transferNft(user1.publicKey, null, new anchor.BN(salePrice), new anchor.BN(1))
+        .accounts({
+          programState: programStatePda,
+          pauseConfig: pauseConfigPda,
+          denylist: denylistPda,
+          collectionConfig: collectionConfigPda,
+          nftMetadata,
+          nftMint: mint,
+          ownerTokenAccount: user2NftAccount,
+          newOwnerTokenAccount: tokenAccount,
+          transferHistory: transferHistoryPda(mint),
+          payer: provider.wallet.publicKey,
+          owner: user2.publicKey,
+          newOwner: user1.publicKey,
+          buyer: user1.publicKey,
+          royaltyRecipient: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user2, user1])
+        .rpc();
+      
+      after = await balances();
+      assert.equal(before.user1 - after.user1, salePrice);
+      assert.equal(after.user2 - before.user2, salePrice * 0.9);
+      assert.equal(after.creator - before.creator, salePrice * 0.1);
+      assert.equal((await program.account.nftMetadata.fetch(nftMetadata)).owner.toString(), user1.publicKey.toString());
+      
+      // Both sales are in the activity feed
+      const transferHistory = await program.account.transferHistory.fetch(transferHistoryPda(mint));
+      assert.equal(transferHistory.totalTransfers, 2);
+      assert.equal(transferHistory.entries[0].owner.toString(), user2.publicKey.toString());
+      assert.equal(transferHistory.entries[1].owner.toString(), user1.publicKey.toString());
+      
+    } catch (error) {
+      console.error("Error paying royalties on sales:", error);
+      throw error;
+    }
+  });
+
+  it("Rejects gifts but allows sales under the enforced royalty policy", async () => {
+    const setRoyaltyPolicy = (royaltyPolicy: object) =>
+      program.methods
+        .setRoyaltyPolicy(royaltyPolicy)
+        .accounts({
+          programState: programStatePda,
+          collectionConfig: collectionConfigPda,
+          authority: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .signers([authority])
+        .rpc();
+    
+    try {
+      const { mint, nftMetadata, tokenAccount } = await mintTestNft(user1, 500, [{ address: authority.publicKey, share: 100 }]);
+      const transferAccounts = {
+        programState: programStatePda,
+        pauseConfig: pauseConfigPda,
+        denylist: denylistPda,
+        collectionConfig: collectionConfigPda,
+        nftMetadata,
+        nftMint: mint,
+        ownerTokenAccount: tokenAccount,
+        newOwnerTokenAccount: await getAssociatedTokenAddress(
+          mint,
+          user2.publicKey,
+          false,
+          TOKEN_PROGRAM_ID,
+          ASSOCIATED_TOKEN_PROGRAM_ID
+        ),
+        transferHistory: transferHistoryPda(mint),
+        payer: user1.publicKey,
+        owner: user1.publicKey,
+        newOwner: user2.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      };
+      
+      await setRoyaltyPolicy({ enforced: {} });
+      
+      // A gift pays no royalty, so it is rejected
+      let rejected = false;
+      try {
+        await program.methods
+          .transferNft(user2.publicKey, null, new anchor.BN(0), new anchor.BN(1))
+          .accounts({ ...transferAccounts, buyer: null, royaltyRecipient: null })
+          .signers([user1])
+          .rpc();
+      } catch (error) {
+        rejected = true;
+        assert.include(error.toString(), "RoyaltyEnforced");
+      }
+      assert.isTrue(rejected);
+      
+      // The same transfer as a sale pays the creator and goes through
+      await program.methods
+        .transferNft(user2.publicKey, null, new anchor.BN(LAMPORTS_PER_SOL / 10), new anchor.BN(1))
+        .accounts({ ...transferAccounts, buyer: user2.publicKey, royaltyRecipient: authority.publicKey })
+        .signers([user1, user2])
+        .rpc();
+      
+      const metadata = await program.account.nftMetadata.fetch(nftMetadata);
+      assert.equal(metadata.owner.toString(), user2.publicKey.toString());
+      
+    } catch (error) {
+      console.error("Error transferring under the enforced royalty policy:", error);
+      throw error;
+    } finally {
+      await setRoyaltyPolicy({ none: {} });
+    }
+  });
+
+  it("Halts minting while the program is paused", async () => {
+    const pauseAccounts = {
+      programState: programStatePda,
+      authority: authority.publicKey,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    };
+    
+    try {
+      await program.methods.pause().accounts(pauseAccounts).signers([authority]).rpc();
+      
+      const pausedState = await program.account.programState.fetch(programStatePda);
+      assert.isTrue(pausedState.paused);
+      
+      let rejected = false;
+      try {
+        await mintTestNft(user1);
+      } catch (error) {
+        rejected = true;
+        assert.include(error.toString(), "ProgramPaused");
+      }
+      assert.isTrue(rejected);
+      
+      // Minting resumes once the authority unpauses
+      await program.methods.unpause().accounts(pauseAccounts).signers([authority]).rpc();
+      const { nftMetadata } = await mintTestNft(user1);
+      
+      const metadata = await program.account.nftMetadata.fetch(nftMetadata);
+      assert.equal(metadata.owner.toString(), user1.publicKey.toString());
+      
+    } catch (error) {
+      console.error("Error pausing the program:", error);
+      throw error;
+    } finally {
+      const programState = await program.account.programState.fetch(programStatePda);
+      if (programState.paused) {
+        await program.methods.unpause().accounts(pauseAccounts).signers([authority]).rpc();
+      }
+    }
+  });
+
+  it("Pauses minting without halting transfers", async () => {
+    const setPausedOperations = (pausedOperations: number) =>
+      program.methods
+        .setPausedOperations(new anchor.BN(pausedOperations))
+        .accounts({
+          programState: programStatePda,
+          pauseConfig: pauseConfigPda,
+          authority: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .signers([authority])
+        .rpc();
+    
+    try {
+      const { mint, nftMetadata, tokenAccount } = await mintTestNft(user1);
+      await setPausedOperations(PAUSE_MINT);
+      
+      let rejected = false;
+      try {
+        await mintTestNft(user1);
+      } catch (error) {
+        rejected = true;
+        assert.include(error.toString(), "OperationPaused");
+      }
+      assert.isTrue(rejected);
+      
+      // Transfers have their own flag, so they keep working
+      await program.methods
+        .transferNft(user2.publicKey, null, new anchor.BN(0), new anchor.BN(1))
+        .accounts({
+          programState: programStatePda,
+          pauseConfig: pauseConfigPda,
+          denylist: denylistPda,
+          collectionConfig: collectionConfigPda,
+          nftMetadata,
+          nftMint: mint,
+          ownerTokenAccount: tokenAccount,
+          newOwnerTokenAccount: await getAssociatedTokenAddress(
+            mint,
+            user2.publicKey,
+            false,
+            TOKEN_PROGRAM_ID,
+            ASSOCIATED_TOKEN_PROGRAM_ID
+          ),
+          transferHistory: transferHistoryPda(mint),
+          payer: user1.publicKey,
+          owner: user1.publicKey,
+          newOwner: user2.publicKey,
+          buyer: null,
+          royaltyRecipient: null,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc();
+      
+      const metadata = await program.account.nftMetadata.fetch(nftMetadata);
+      assert.equal(metadata.owner.toString(), user2.publicKey.toString());
+      
+    } catch (error) {
+      console.error("Error pausing individual operations:", error);
+      throw error;
+    } finally {
+      await setPausedOperations(0);
+    }
+  });
+
+  it("Rate limits outbound cross-chain transfers", async () => {
+    const targetChainId = 3; // BSC
+    const original = await program.account.rateLimiter.fetch(rateLimiterPda);
+    const setRateLimit = (maxTransfers: anchor.BN, maxValue: anchor.BN, window: anchor.BN) =>
+      program.methods
+        .setRateLimit(maxTransfers, maxValue, window)
+        .accounts({
+          programState: programStatePda,
+          rateLimiter: rateLimiterPda,
+          authority: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .signers([authority])
+        .rpc();
+    
+    try {
+      const first = await mintTestNft(user1);
+      const second = await mintTestNft(user1);
+      
+      // A new window length starts counting from zero, so exactly one transfer fits
+      await setRateLimit(new anchor.BN(1), new anchor.BN(0), original.window.addn(60));
+      await sendCrossChain(user1, first, targetChainId);
+      
+      let rejected = false;
+      try {
+        await sendCrossChain(user1, second, targetChainId);
+      } catch (error) {
+        rejected = true;
+        assert.include(error.toString(), "RateLimitExceeded");
+      }
+      assert.isTrue(rejected);
+      
+      const rateLimiter = await program.account.rateLimiter.fetch(rateLimiterPda);
+      assert.equal(rateLimiter.outbound.count.toNumber(), 1);
+      
+      // The rejected NFT never left its owner
+      const metadata = await program.account.nftMetadata.fetch(second.nftMetadata);
+      assert.equal(metadata.owner.toString(), user1.publicKey.toString());
+      
+    } catch (error) {
+      console.error("Error rate limiting cross-chain transfers:", error);
+      throw error;
+    } finally {
+      await setRateLimit(original.maxTransfers, original.maxValue, original.window);
+    }
+  });
+
+  it("Batch mints NFTs to the authority", async () => {
+    try {
+      const mints = [Keypair.generate(), Keypair.generate()];
+      const items = [
+        { metadataUri: "https://example.com/batch-0.json", name: "Batch Zero" },
+        { metadataUri: "https://example.com/batch-1.json", name: "" }, // Deployment default name
+      ];
+      const programState = await program.account.programState.fetch(programStatePda);
+      const tokenIdSlot = await provider.connection.getSlot();
+      
+      // Six remaining accounts per item: mint, authority token account, NFT metadata, NFT origin, Metaplex metadata, master edition
+      const remainingAccounts = [];
+      for (const [i, mint] of mints.entries()) {
+        const tokenId = derivedTokenId(mint.publicKey, tokenIdSlot, programState.nextTokenId.addn(i));
+        remainingAccounts.push(
+          { pubkey: mint.publicKey, isSigner: true, isWritable: true },
+          {
+            pubkey: await getAssociatedTokenAddress(mint.publicKey, authority.publicKey, false, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID),
+            isSigner: false,
+            isWritable: true,
+          },
+          {
+            pubkey: PublicKey.findProgramAddressSync([Buffer.from("nft_metadata"), mint.publicKey.toBuffer()], program.programId)[0],
+            isSigner: false,
+            isWritable: true,
+          },
+          { pubkey: nftOriginPda(tokenId), isSigner: false, isWritable: true },
+          { pubkey: metaplexMetadataPda(mint.publicKey), isSigner: false, isWritable: true },
+          { pubkey: masterEditionPda(mint.publicKey), isSigner: false, isWritable: true },
+        );
+      }
+      
+      const tx = await program.methods
+        .batchMint(items, new anchor.BN(tokenIdSlot))
+        .accounts({
+          programState: programStatePda,
+          pauseConfig: pauseConfigPda,
+          collectionConfig: collectionConfigPda,
+          updateAuthority: mintAuthorityPda,
+          authority: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .remainingAccounts(remainingAccounts)
+        .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 })])
+        .signers([authority, ...mints])
+        .rpc();
+      
+      console.log("Batch minted. Transaction signature:", tx);
+      
+      // Every item is a regular NFT held by the authority, counted against the global supply
+      for (const [i, mint] of mints.entries()) {
+        const nftMetadataPda = PublicKey.findProgramAddressSync(
+          [Buffer.from("nft_metadata"), mint.publicKey.toBuffer()],
+          program.programId
+        )[0];
+        const metadata = await program.account.nftMetadata.fetch(nftMetadataPda);
+        assert.equal(metadata.owner.toString(), authority.publicKey.toString());
+        assert.equal(metadata.metadataUri, items[i].metadataUri);
+        
+        const tokenAccount = await getAssociatedTokenAddress(mint.publicKey, authority.publicKey, false, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID);
+        assert.equal((await getAccount(provider.connection, tokenAccount)).amount.toString(), "1");
+      }
+      
+      const programStateAfter = await program.account.programState.fetch(programStatePda);
+      assert.equal(programStateAfter.totalMinted.toNumber(), programState.totalMinted.toNumber() + mints.length);
+      assert.equal(programStateAfter.nextTokenId.toNumber(), programState.nextTokenId.toNumber() + mints.length);
+      
+    } catch (error) {
+      console.error("Error batch minting:", error);
+      throw error;
+    }
+  });
+
+  it("Claims an airdropped NFT with a Merkle proof", async () => {
+    try {
+      // Leaf: keccak(keccak(index as u32 LE || wallet || metadata URI))
+      const airdropLeaf = (index: number, wallet: PublicKey, metadataUri: string): Buffer => {
+        const indexBytes = Buffer.alloc(4);
+        indexBytes.writeUInt32LE(index);
+        return Buffer.from(keccak_256(keccak_256(Buffer.concat([indexBytes, wallet.toBuffer(), Buffer.from(metadataUri)]))));
+      };
+      const claimUri = "https://example.com/airdrop-1.json";
+      const leaves = [
+        airdropLeaf(0, user1.publicKey, "https://example.com/airdrop-0.json"),
+        airdropLeaf(1, user2.publicKey, claimUri),
+      ];
+      const root = Buffer.from(keccak_256(Buffer.concat(Buffer.compare(leaves[0], leaves[1]) <= 0 ? leaves : [leaves[1], leaves[0]])));
+      const [airdropPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("airdrop"), root],
+        program.programId
+      );
+      
+      await program.methods
+        .createAirdrop(Array.from(root), leaves.length)
+        .accounts({
+          programState: programStatePda,
+          airdrop: airdropPda,
+          authority: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      
+      // Claims leaf 1 as `claimer`, with a fresh mint each time
+      const claim = async (claimer: Keypair) => {
+        const mint = Keypair.generate();
+        const programState = await program.account.programState.fetch(programStatePda);
+        const tokenIdSlot = await provider.connection.getSlot();
+        const indexBytes = Buffer.alloc(4);
+        indexBytes.writeUInt32LE(1);
+        const [nftMetadata] = PublicKey.findProgramAddressSync(
+          [Buffer.from("nft_metadata"), mint.publicKey.toBuffer()],
+          program.programId
+        );
+        
+        await program.methods
+          .claimAirdrop(1, claimUri, [Array.from(leaves[0])], new anchor.BN(tokenIdSlot))
+          .accounts({
+            programState: programStatePda,
+            pauseConfig: pauseConfigPda,
+            collectionConfig: collectionConfigPda,
+            airdrop: airdropPda,
+            airdropClaim: PublicKey.findProgramAddressSync(
+              [Buffer.from("airdrop_claim"), airdropPda.toBuffer(), indexBytes],
+              program.programId
+            )[0],
+            mint: mint.publicKey,
+            claimerTokenAccount: await getAssociatedTokenAddress(mint.publicKey, claimer.publicKey, false, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID),
+            nftMetadata,
+            nftOrigin: nftOriginPda(derivedTokenId(mint.publicKey, tokenIdSlot, programState.nextTokenId)),
+            metadata: metaplexMetadataPda(mint.publicKey),
+            masterEdition: masterEditionPda(mint.publicKey),
+            updateAuthority: mintAuthorityPda,
+            payer: claimer.publicKey,
+            claimer: claimer.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+          })
+          .signers([claimer, mint])
+          .rpc();
+        
+        return nftMetadata;
+      };
+      
+      // The leaf names user2, so user1 can't claim it
+      let rejected = false;
+      try {
+        await claim(user1);
+      } catch (error) {
+        rejected = true;
+        assert.include(error.toString(), "InvalidMerkleProof");
+      }
+      assert.isTrue(rejected);
+      
+      const nftMetadata = await claim(user2);
+      const metadata = await program.account.nftMetadata.fetch(nftMetadata);
+      assert.equal(metadata.owner.toString(), user2.publicKey.toString());
+      assert.equal(metadata.metadataUri, claimUri);
+      
+      // Each leaf can only be claimed once
+      rejected = false;
+      try {
+        await claim(user2);
+      } catch (error) {
+        rejected = true;
+      }
+      assert.isTrue(rejected);
+      
+      const airdrop = await program.account.airdrop.fetch(airdropPda);
+      assert.equal(airdrop.claimedCount, 1);
+      
+    } catch (error) {
+      console.error("Error claiming airdrop:", error);
+      throw error;
+    }
+  });
+
+  it("Burns an escrowed NFT at its remote holder's request", async () => {
+    try {
+      const remoteChainId = 3; // BSC
+      const nft = await mintTestNft(user1);
+      const { tokenId } = await program.account.nftMetadata.fetch(nft.nftMetadata);
+      await sendCrossChain(user1, nft, remoteChainId);
+      
+      // The gateway confirms the NFT arrived on the remote chain
+      await program.methods
+        .confirmOutboundTransfer(Array.from(new Uint8Array(32).fill(8)))
+        .accounts({
+          gatewayState: gatewayStatePda,
+          transferState: crossChainTransferStatePda(nft.mint),
+          nftMint: nft.mint,
+          gatewayAuthority: authority.publicKey,
+          solanaBalance: null,
+          targetBalance: null,
+          provenance: PublicKey.findProgramAddressSync(
+            [Buffer.from("provenance"), Buffer.from(tokenId)],
+            program.programId
+          )[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      
+      // The remote holder burns it there; observers attest to the burn message
+      const burnPayload = {
+        tokenId,
+        owner: Buffer.from(testRecipient),
+        timestamp: new anchor.BN(Math.floor(Date.now() / 1000)),
+      };
+      const burnPayloadBytes = program.coder.types.encode("RemoteBurnPayload", burnPayload);
+      const zetaTxHash = new Uint8Array(32).fill(9);
+      const digest = incomingMessageDigest(remoteChainId, zetaTxHash, burnPayloadBytes);
+      const precompileIx = Secp256k1Program.createInstructionWithPrivateKey({
+        privateKey: Buffer.from(observerKeys[1]),
+        message: Buffer.from(digest),
+      });
+      const escrowTokenAccount = await getAssociatedTokenAddress(
+        nft.mint,
+        escrowAuthorityPda,
+        true,
+        TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+      
+      const tx = await program.methods
+        .processRemoteBurn(new anchor.BN(remoteChainId), burnPayload, Array.from(zetaTxHash), [signDigest(observerKeys[0], digest)])
+        .accounts({
+          programState: programStatePda,
+          pauseConfig: pauseConfigPda,
+          denylist: denylistPda,
+          rateLimiter: rateLimiterPda,
+          gatewayState: gatewayStatePda,
+          chainConfig: chainConfigPda(remoteChainId),
+          nftOrigin: nftOriginPda(tokenId),
+          nftMetadata: nft.nftMetadata,
+          nftMint: nft.mint,
+          transferState: crossChainTransferStatePda(nft.mint),
+          frozenAsset: PublicKey.findProgramAddressSync(
+            [Buffer.from("frozen_asset"), nft.mint.toBuffer()],
+            program.programId
+          )[0],
+          originalSender: user1.publicKey,
+          escrowAuthority: escrowAuthorityPda,
+          escrowTokenAccount,
+          gatewayAuthority: authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .preInstructions([precompileIx])
+        .signers([authority])
+        .rpc();
+      
+      console.log("Remote burn processed. Transaction signature:", tx);
+      
+      // The escrowed token is gone and every record of the NFT is closed
+      const mintInfo = await provider.connection.getParsedAccountInfo(nft.mint);
+      assert.equal((mintInfo.value.data as any).parsed.info.supply, "0");
+      assert.isNull(await provider.connection.getAccountInfo(escrowTokenAccount));
+      assert.isNull(await program.account.nftMetadata.fetchNullable(nft.nftMetadata));
+      assert.isNull(await program.account.nftOrigin.fetchNullable(nftOriginPda(tokenId)));
+      assert.isNull(await program.account.crossChainTransferState.fetchNullable(crossChainTransferStatePda(nft.mint)));
+      
+    } catch (error) {
+      console.error("Error processing remote burn:", error);
+      throw error;
+    }
+  });
+
+  it("Wraps an external Metaplex NFT", async () => {
+    try {
+      const externalUri = "https://example.com/external-0.json";
+      const programState = await program.account.programState.fetch(programStatePda);
+      const { mint, nftMetadata, tokenId, tx } = await wrapTestNft(user1, externalUri);
+      const [wrappedNft] = PublicKey.findProgramAddressSync(
+        [Buffer.from("wrapped_nft"), mint.toBuffer()],
+        program.programId
+      );
+      
+      console.log("External NFT wrapped. Transaction signature:", tx);
+      
+      // The NFT is tracked like a native one, taking its URI and royalty from the Metaplex metadata
+      const metadata = await program.account.nftMetadata.fetch(nftMetadata);
+      assert.equal(metadata.owner.toString(), user1.publicKey.toString());
+      assert.equal(metadata.metadataUri, externalUri);
+      assert.deepEqual(Buffer.from(metadata.tokenId), tokenId);
+      assert.equal(metadata.royaltyBasisPoints, 250);
+      
+      const wrapped = await program.account.wrappedNft.fetch(wrappedNft);
+      assert.equal(wrapped.wrapper.toString(), user1.publicKey.toString());
+      
+      const programStateAfter = await program.account.programState.fetch(programStatePda);
+      assert.equal(programStateAfter.totalMinted.toNumber(), programState.totalMinted.toNumber() + 1);
+      
+    } catch (error) {
+      console.error("Error wrapping external NFT:", error);
+      throw error;
+    }
+  });
+
+  it("Unwraps a returning external NFT to its recipient", async () => {
+    try {
+      const sourceChainId = 3; // BSC
+      const externalUri = "https://example.com/external-1.json";
+      const nft = await wrapTestNft(user1, externalUri);
+      await sendCrossChain(user1, nft, sourceChainId);
+      const provenancePda = PublicKey.findProgramAddressSync(
+        [Buffer.from("provenance"), nft.tokenId],
+        program.programId
+      )[0];
+      
+      await program.methods
+        .confirmOutboundTransfer(Array.from(new Uint8Array(32).fill(10)))
+        .accounts({
+          gatewayState: gatewayStatePda,
+          transferState: crossChainTransferStatePda(nft.mint),
+          nftMint: nft.mint,
+          gatewayAuthority: authority.publicKey,
+          solanaBalance: null,
+          targetBalance: null,
+          provenance: provenancePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      
+      // The NFT comes back addressed to user2; observers attest to the message
+      const returnPayload = {
+        v2: {
+          tokenId: Array.from(nft.tokenId),
+          uri: externalUri,
+          recipient: user2.publicKey.toBuffer(),
+          sender: Buffer.from(testRecipient),
+          royaltyBasisPoints: 0,
+          data: Buffer.alloc(0),
+          timestamp: new anchor.BN(Math.floor(Date.now() / 1000)),
+        },
+      };
+      const returnPayloadBytes = program.coder.types.encode("CrossChainPayload", returnPayload);
+      const zetaTxHash = new Uint8Array(32).fill(11);
+      const digest = incomingMessageDigest(sourceChainId, zetaTxHash, returnPayloadBytes);
+      const precompileIx = Secp256k1Program.createInstructionWithPrivateKey({
+        privateKey: Buffer.from(observerKeys[1]),
+        message: Buffer.from(digest),
+      });
+      const recipientTokenAccount = await getAssociatedTokenAddress(
+        nft.mint,
+        user2.publicKey,
+        false,
+        TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+      const wrappedNft = PublicKey.findProgramAddressSync(
+        [Buffer.from("wrapped_nft"), nft.mint.toBuffer()],
+        program.programId
+      )[0];
+      
+      const tx = await program.methods
+        .unwrapExternalNft(new anchor.BN(sourceChainId), returnPayload, Array.from(zetaTxHash), [signDigest(observerKeys[0], digest)])
+        .accounts({
+          programState: programStatePda,
+          pauseConfig: pauseConfigPda,
+          denylist: denylistPda,
+          rateLimiter: rateLimiterPda,
+          gatewayState: gatewayStatePda,
+          chainConfig: chainConfigPda(sourceChainId),
+          nftOrigin: nftOriginPda(nft.tokenId),
+          provenance: provenancePda,
+          transferHistory: transferHistoryPda(nft.mint),
+          nftMetadata: nft.nftMetadata,
+          wrappedNft,
+          nftMint: nft.mint,
+          transferState: crossChainTransferStatePda(nft.mint),
+          frozenAsset: PublicKey.findProgramAddressSync(
+            [Buffer.from("frozen_asset"), nft.mint.toBuffer()],
+            program.programId
+          )[0],
+          originalSender: user1.publicKey,
+          escrowAuthority: escrowAuthorityPda,
+          escrowTokenAccount: await getAssociatedTokenAddress(
+            nft.mint,
+            escrowAuthorityPda,
+            true,
+            TOKEN_PROGRAM_ID,
+            ASSOCIATED_TOKEN_PROGRAM_ID
+          ),
+          recipientTokenAccount,
+          recipient: user2.publicKey,
+          gatewayAuthority: authority.publicKey,
+          mintAuthority: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .preInstructions([precompileIx])
+        .signers([authority])
+        .rpc();
+      
+      console.log("External NFT unwrapped. Transaction signature:", tx);
+      
+      // user2 holds the original token, which leaves the program as a plain Metaplex NFT
+      assert.equal((await getAccount(provider.connection, recipientTokenAccount)).amount.toString(), "1");
+      assert.isNull(await program.account.nftMetadata.fetchNullable(nft.nftMetadata));
+      assert.isNull(await program.account.wrappedNft.fetchNullable(wrappedNft));
+      assert.isNull(await program.account.nftOrigin.fetchNullable(nftOriginPda(nft.tokenId)));
+      
+      const transferHistory = await program.account.transferHistory.fetch(transferHistoryPda(nft.mint));
+      const latest = transferHistory.entries[transferHistory.totalTransfers - 1];
+      assert.equal(latest.owner.toString(), user2.publicKey.toString());
+      assert.deepEqual(latest.kind, { crossChain: {} });
+      
+    } catch (error) {
+      console.error("Error unwrapping external NFT:", error);
+      throw error;
+    }
+  });
 });